@@ -0,0 +1,17 @@
+use super::ArgGroup;
+use proc_macro_util::{tokens::Identifier, Result};
+
+impl ArgGroup {
+    /// Checks that every member of this group is a flag, according to `is_flag`
+    pub fn check_members(&self, is_flag: impl Fn(&Identifier) -> bool) -> Result<()> {
+        for member in &self.members {
+            if !is_flag(member) {
+                return Err(member
+                    .span()
+                    .error(format!("\"{member}\" is not a flag in this struct")));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use super::ArgGroup;
+use proc_macro_util::tokens::Identifier;
+
+impl ArgGroup {
+    /// Is the flag variable named `variable_name` a member of this group?
+    pub fn contains(&self, variable_name: &Identifier) -> bool {
+        let variable_name = variable_name.to_string();
+        self.members
+            .iter()
+            .any(|member| member.to_string() == variable_name)
+    }
+}
@@ -0,0 +1,90 @@
+use super::ArgGroup;
+use proc_macro_util::{
+    ast::{AttrInput, OuterAttribute},
+    tokens::{Group, Identifier},
+    Result, Token,
+};
+use std::borrow::Cow;
+
+impl ArgGroup {
+    /// Extracts the [`ArgGroup`] from `attribute`
+    pub fn extract(attribute: OuterAttribute) -> Result<Self> {
+        let group = match attribute.attr.input {
+            Some(AttrInput::Group(group)) => group,
+            None => Cow::Owned(Group::new_parenthesis()),
+            Some(AttrInput::Expression(eq, _)) => {
+                return Err(eq.spans[0].error("expected a group, not an expression"))
+            }
+        };
+
+        let mut parser = group.parser();
+
+        let mut rule = None;
+        while !parser.empty() {
+            let tag = parser.parse::<Identifier>()?;
+            let tag_str = tag.to_string();
+
+            let (rule_name, required) = match tag_str.as_str() {
+                "exactly_one" => ("ExactlyOne", true),
+                "at_least_one" => ("AtLeastOne", true),
+                "at_most_one" => ("AtMostOne", false),
+                _ => {
+                    return Err(tag
+                        .span()
+                        .error(format!("unknown arg group tag \"{tag_str}\"")))
+                }
+            };
+
+            if rule.is_some() {
+                return Err(tag.span().error("an arg group can only have one rule"));
+            }
+
+            parser.parse::<Token![=]>()?;
+            let members_group = parser.parse::<&Group>()?;
+            let mut members_parser = members_group.parser();
+            let mut members = Vec::new();
+            while !members_parser.empty() {
+                members.push(members_parser.parse::<Identifier>()?);
+
+                match members_parser.step_parse::<Token![,]>() {
+                    Ok(_) => {}
+                    Err(_) => break,
+                }
+            }
+
+            if !members_parser.empty() {
+                return Err(members_parser.error("unexpected token"));
+            }
+
+            if members.len() < 2 {
+                return Err(tag.span().error("an arg group needs at least two flags"));
+            }
+
+            rule = Some((Identifier::new(rule_name), required, members));
+
+            match parser.step_parse::<Token![,]>() {
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+
+        if !parser.empty() {
+            return Err(parser.error("unexpected token"));
+        }
+
+        let (rule, required, members) = match rule {
+            Some(rule) => rule,
+            None => {
+                return Err(parser.error(
+                    "expected `exactly_one`, `at_least_one` or `at_most_one` listing the flags",
+                ))
+            }
+        };
+
+        Ok(ArgGroup {
+            rule,
+            required,
+            members,
+        })
+    }
+}
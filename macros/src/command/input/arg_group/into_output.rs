@@ -0,0 +1,38 @@
+use super::ArgGroup;
+use crate::command::output::{
+    ArgGroupCheck, ArgGroupDocumentOutput, ArgGroupHelpOutput, ArgGroupHelpUsageOutput,
+    ArgGroupInfo, FlagNames,
+};
+use proc_macro_util::tokens::{Identifier, Literal};
+
+impl ArgGroup {
+    /// Converts this group into the appropriate output types, using `flag` to get the name of the
+    /// info and the long name of each member
+    pub fn into_output(
+        self,
+        index: usize,
+        flag: impl Fn(&Identifier) -> (Identifier, Literal),
+    ) -> (
+        ArgGroupInfo,
+        ArgGroupCheck,
+        ArgGroupHelpUsageOutput,
+        ArgGroupHelpOutput,
+        ArgGroupDocumentOutput,
+    ) {
+        let info_name = Identifier::new(&format!("__ARG_GROUP_{}", index));
+
+        let (member_info_names, member_long_names) = self.members.iter().map(flag).unzip();
+
+        (
+            ArgGroupInfo::new(
+                info_name.clone(),
+                self.rule,
+                FlagNames::new(member_long_names),
+            ),
+            ArgGroupCheck::new(info_name.clone()),
+            ArgGroupHelpUsageOutput::new(self.required, member_info_names),
+            ArgGroupHelpOutput::new(info_name.clone()),
+            ArgGroupDocumentOutput::new(info_name),
+        )
+    }
+}
@@ -0,0 +1,18 @@
+use proc_macro_util::tokens::Identifier;
+
+mod check_members;
+mod contains;
+mod extract;
+mod into_output;
+
+/// A group of flags with a rule on how many of them can be passed together
+pub struct ArgGroup {
+    /// The name of the `ArgGroupRule` variant
+    rule: Identifier,
+
+    /// Must one of the members be passed?
+    required: bool,
+
+    /// The names of the flag variables in the group
+    members: Vec<Identifier>,
+}
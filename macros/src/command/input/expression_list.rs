@@ -0,0 +1,19 @@
+use proc_macro_util::{ast::Expression, tokens::Group, Result, Token};
+
+/// Parses the comma-separated expressions in `group`, like `description = ("Line one", "Line two")`
+///
+/// A trailing comma is allowed.
+pub fn parse_expression_list(group: &Group) -> Result<Vec<Expression<'static>>> {
+    let mut parser = group.parser();
+    let mut expressions = vec![parser.parse::<Expression>()?.into_static()];
+    while !parser.empty() {
+        parser.parse::<Token![,]>()?;
+        if parser.empty() {
+            break;
+        }
+
+        expressions.push(parser.parse::<Expression>()?.into_static());
+    }
+
+    Ok(expressions)
+}
@@ -1,4 +1,4 @@
-use super::{CommandInfo, Input, StructInput};
+use super::{ArgGroup, CommandInfo, Input, StructInput};
 use proc_macro_util::{
     ast::{AttrInput, DeriveItem, DeriveItemKind},
     Result,
@@ -8,6 +8,7 @@ impl<'a> Input<'a> {
     /// Extract the required details from `item`
     pub fn extract(item: DeriveItem<'a>) -> Result<Self> {
         let mut command_attribute = None;
+        let mut arg_groups = Vec::new();
         let mut docs = Vec::new();
         for attribute in item.attributes {
             if attribute.attr.path.remaining.len() > 0 || attribute.attr.path.leading.is_some() {
@@ -16,6 +17,7 @@ impl<'a> Input<'a> {
 
             match attribute.attr.path.first.to_string().as_str() {
                 "command" => command_attribute = Some(attribute),
+                "arg_group" => arg_groups.push(ArgGroup::extract(attribute)?),
                 "doc" => match attribute.attr.input {
                     Some(AttrInput::Expression(_, expression)) => docs.push(expression),
                     _ => {}
@@ -31,9 +33,9 @@ impl<'a> Input<'a> {
         };
 
         match item.kind {
-            DeriveItemKind::Struct(r#struct) => {
-                Ok(Input::Struct(StructInput::extract(r#struct, info)?))
-            }
+            DeriveItemKind::Struct(r#struct) => Ok(Input::Struct(StructInput::extract(
+                r#struct, info, arg_groups,
+            )?)),
             DeriveItemKind::Enum(_) => todo!(),
         }
     }
@@ -0,0 +1,33 @@
+use proc_macro_util::{tokens::Literal, Result};
+
+/// Replaces `names`, the long names given to `conflicts_with` or `requires`, by the long names
+/// with the leading dashes
+///
+/// `long_names` are the long names as written and with the leading dashes of the flags in the
+/// struct. The flags of nested flag groups can't be seen here, so if the struct has flag groups,
+/// other names are taken as the long names of their flags.
+pub fn resolve_flag_names<'b>(
+    names: impl Iterator<Item = &'b mut Literal>,
+    long_names: &[(String, Literal)],
+    has_flag_groups: bool,
+) -> Result<()> {
+    for name in names {
+        let name_str = name.to_string();
+        match long_names
+            .iter()
+            .find(|(long_name, _)| *long_name == name_str)
+        {
+            Some((_, info_long_name)) => *name = info_long_name.clone(),
+            None if has_flag_groups => {
+                *name = Literal::new(format!("--{}", name_str.trim_matches('"')).as_str())
+            }
+            None => {
+                return Err(name
+                    .span()
+                    .error(format!("{name_str} is not a flag in this struct")))
+            }
+        }
+    }
+
+    Ok(())
+}
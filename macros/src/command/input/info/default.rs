@@ -5,8 +5,14 @@ impl<'a> Default for CommandInfo<'a> {
         CommandInfo {
             name: None,
             description: None,
+            long_description: None,
+            after_help: None,
+            examples: Vec::new(),
             version: None,
+            long_version: Vec::new(),
+            version_command: false,
             help: false,
+            dynamic_completion: false,
             usage_header: None,
             positional_header: None,
             flag_header: None,
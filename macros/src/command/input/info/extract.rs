@@ -1,4 +1,5 @@
 use super::CommandInfo;
+use crate::command::parse_expression_list;
 use proc_macro_util::{
     ast::{AttrInput, Expression, OuterAttribute},
     tokens::{Group, Identifier, Literal},
@@ -21,8 +22,14 @@ impl<'a> CommandInfo<'a> {
 
         let mut name = None;
         let mut description = None;
+        let mut long_description = None;
+        let mut after_help = None;
+        let mut examples = Vec::new();
         let mut version = None;
+        let mut long_version = Vec::new();
+        let mut version_command = false;
         let mut help = false;
+        let mut dynamic_completion = false;
         let mut usage_header = None;
         let mut positional_header = None;
         let mut flag_header = None;
@@ -38,17 +45,7 @@ impl<'a> CommandInfo<'a> {
                 "description" => {
                     description = Some(if parser.step_parse::<Token![=]>().is_ok() {
                         Some(if let Ok(group) = parser.step_parse::<&Group>() {
-                            let mut parser = group.parser();
-                            let mut description = vec![parser.parse::<Expression>()?.into_static()];
-                            while !parser.empty() {
-                                parser.parse::<Token![,]>()?;
-                                if parser.empty() {
-                                    break;
-                                }
-
-                                description.push(parser.parse::<Expression>()?.into_static());
-                            }
-                            description
+                            parse_expression_list(group)?
                         } else {
                             vec![parser.parse::<Expression>()?.into_static()]
                         })
@@ -56,6 +53,30 @@ impl<'a> CommandInfo<'a> {
                         None
                     })
                 }
+                "long_description" => {
+                    parser.parse::<Token![=]>()?;
+                    long_description = Some(if let Ok(group) = parser.step_parse::<&Group>() {
+                        parse_expression_list(group)?
+                    } else {
+                        vec![parser.parse::<Expression>()?.into_static()]
+                    });
+                }
+                "after_help" => {
+                    parser.parse::<Token![=]>()?;
+                    after_help = Some(if let Ok(group) = parser.step_parse::<&Group>() {
+                        parse_expression_list(group)?
+                    } else {
+                        vec![parser.parse::<Expression>()?.into_static()]
+                    });
+                }
+                "examples" => {
+                    parser.parse::<Token![=]>()?;
+                    examples = if let Ok(group) = parser.step_parse::<&Group>() {
+                        parse_expression_list(group)?
+                    } else {
+                        vec![parser.parse::<Expression>()?.into_static()]
+                    };
+                }
                 "version" => {
                     version = Some(if let Ok(_) = parser.step_parse::<Token![=]>() {
                         Some(parser.parse::<Expression>()?.into_static())
@@ -63,9 +84,34 @@ impl<'a> CommandInfo<'a> {
                         None
                     });
                 }
+                "long_version" => {
+                    parser.parse::<Token![=]>()?;
+                    let group = parser.parse::<&Group>()?;
+                    let mut parser = group.parser();
+                    while !parser.empty() {
+                        let name = parser.parse::<Identifier>()?;
+                        parser.parse::<Token![=]>()?;
+                        long_version.push((name, parser.parse::<Expression>()?.into_static()));
+
+                        match parser.step_parse::<Token![,]>() {
+                            Ok(_) => {}
+                            Err(_) => break,
+                        }
+                    }
+
+                    if !parser.empty() {
+                        return Err(parser.error("unexpected token"));
+                    }
+                }
+                "version_command" => {
+                    version_command = true;
+                }
                 "help" => {
                     help = true;
                 }
+                "dynamic_completion" => {
+                    dynamic_completion = true;
+                }
                 "usage" => {
                     parser.parse::<Token![=]>()?;
                     usage_header = Some(parser.parse::<Expression>()?.into_static());
@@ -101,11 +147,21 @@ impl<'a> CommandInfo<'a> {
             None => None,
         };
 
+        if (long_version.len() > 0 || version_command) && version.is_none() {
+            version = Some(None);
+        }
+
         Ok(CommandInfo {
             name,
             description,
+            long_description,
+            after_help,
+            examples,
             version,
+            long_version,
+            version_command,
             help,
+            dynamic_completion,
             usage_header,
             positional_header,
             flag_header,
@@ -0,0 +1,24 @@
+use super::CommandInfo;
+use proc_macro_util::tokens::Literal;
+
+impl<'a> CommandInfo<'a> {
+    /// Gets the long names of the flags built into the command, including the leading dashes and
+    /// "--color" if `color` is built in
+    pub fn builtin_long_names(&self, color: bool) -> Vec<Literal> {
+        let mut names = Vec::new();
+        if self.help {
+            names.push(Literal::new("--help"));
+            names.push(Literal::new("--help-all"));
+        }
+
+        if self.version.is_some() {
+            names.push(Literal::new("--version"));
+        }
+
+        if color {
+            names.push(Literal::new("--color"));
+        }
+
+        names
+    }
+}
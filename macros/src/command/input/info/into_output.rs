@@ -1,12 +1,23 @@
 use super::CommandInfo;
 use crate::command::output::{
-    Description, FlagGroupHelpOutput, FlagGroupHelpUsageOutput, FlagHelpOutput,
-    FlagHelpUsageOutput, HelpHeader, HelpOutput, HelpOutputDescription, HelpOutputName,
-    HelpUsageOutput, PositionalHelpOutput, PositionalHelpUsageOutput, VersionOutput,
+    ArgGroupDocumentOutput, ArgGroupHelpOutput, ArgGroupHelpUsageOutput, BuiltinFlagDocument,
+    Description, DocumentOutput, DocumentSubCommandsOutput, FlagDocumentOutput,
+    FlagGroupDocumentOutput, FlagGroupHelpOutput, FlagGroupHelpUsageOutput, FlagHelpOutput,
+    FlagHelpUsageOutput, HelpEpilogue, HelpExamples, HelpHeader, HelpLongDescription, HelpOutput,
+    HelpOutputDescription, HelpOutputName, HelpUsageOutput, LongVersionOutput,
+    PositionalDocumentOutput, PositionalHelpOutput, PositionalHelpUsageOutput, ProgramName,
+    SectionTitle, VersionCommandDocument, VersionOutput,
 };
 
 impl<'a> CommandInfo<'a> {
-    /// Converts this input into a [`VersionOutput`]
+    /// Converts this input into a [`ProgramName`], [`VersionOutput`], [`LongVersionOutput`], whether
+    /// there is a "version" sub-command, [`HelpOutput`], [`DocumentOutput`] and whether dynamic
+    /// completion is enabled
+    ///
+    /// The [`DocumentOutput`] is always generated, as documentation and completions are built from
+    /// it even for commands without a help flag. `short_help` and `short_version` tell whether the
+    /// built-in "-h" and "-V" flags are free to be described, and `color` whether the built-in
+    /// "--color" flag is.
     pub fn into_output(
         self,
         positional_usages: Vec<PositionalHelpUsageOutput>,
@@ -15,39 +26,107 @@ impl<'a> CommandInfo<'a> {
         flag_help: Vec<FlagHelpOutput>,
         flag_group_usages: Vec<FlagGroupHelpUsageOutput<'a>>,
         flag_group_helps: Vec<FlagGroupHelpOutput<'a>>,
-    ) -> (Option<VersionOutput<'a>>, Option<HelpOutput<'a>>) {
+        arg_group_usages: Vec<ArgGroupHelpUsageOutput>,
+        arg_group_helps: Vec<ArgGroupHelpOutput>,
+        positional_document: Vec<PositionalDocumentOutput>,
+        flag_document: Vec<FlagDocumentOutput>,
+        arg_group_documents: Vec<ArgGroupDocumentOutput>,
+        flag_group_documents: Vec<FlagGroupDocumentOutput<'a>>,
+        document_sub_commands: Vec<DocumentSubCommandsOutput>,
+        short_help: bool,
+        short_version: bool,
+        color: bool,
+    ) -> (
+        Option<ProgramName>,
+        Option<VersionOutput<'a>>,
+        Option<LongVersionOutput<'a>>,
+        bool,
+        Option<HelpOutput<'a>>,
+        DocumentOutput<'a>,
+        bool,
+    ) {
+        let mut builtin_flags = Vec::new();
+        if self.help {
+            builtin_flags.push(BuiltinFlagDocument::Help(short_help));
+            builtin_flags.push(BuiltinFlagDocument::HelpAll);
+        }
+
+        if self.version.is_some() {
+            builtin_flags.push(BuiltinFlagDocument::Version(short_version));
+        }
+
+        if color {
+            builtin_flags.push(BuiltinFlagDocument::Color);
+        }
+
+        let document = DocumentOutput::new(
+            match self.name.clone() {
+                Some(name) => HelpOutputName::Provided(name),
+                None => HelpOutputName::Default,
+            },
+            match self.description {
+                Some(description) => HelpOutputDescription::Provided(Description::new(description)),
+                None => HelpOutputDescription::Default,
+            },
+            match self.long_description.clone() {
+                Some(long_description) => {
+                    HelpOutputDescription::Provided(Description::new(long_description))
+                }
+                None => HelpOutputDescription::None,
+            },
+            self.name.clone().map(ProgramName::new),
+            HelpUsageOutput::new(
+                positional_usages,
+                flag_usages,
+                flag_group_usages,
+                arg_group_usages,
+            ),
+            match self.positional_header.clone() {
+                Some(expression) => SectionTitle::UserProvided(expression),
+                None => SectionTitle::Default("arguments_header"),
+            },
+            positional_document,
+            match self.flag_header.clone() {
+                Some(expression) => SectionTitle::UserProvided(expression),
+                None => SectionTitle::Default("options_header"),
+            },
+            flag_document,
+            builtin_flags,
+            arg_group_documents,
+            flag_group_documents,
+            document_sub_commands,
+            self.version_command.then_some(VersionCommandDocument),
+        );
+
         (
+            self.name.clone().map(ProgramName::new),
             self.version
                 .map(|version| match (self.name.clone(), version) {
                     (_, Some(version)) => VersionOutput::UserDefined(version),
                     (Some(name), None) => VersionOutput::AlternateName(name),
                     (None, None) => VersionOutput::Default,
                 }),
+            if self.long_version.len() > 0 {
+                Some(LongVersionOutput::new(self.long_version))
+            } else {
+                None
+            },
+            self.version_command,
             if self.help {
                 Some(HelpOutput::new(
-                    match self.name {
+                    match self.name.clone() {
                         Some(name) => HelpOutputName::Provided(name),
                         None => HelpOutputName::Default,
                     },
-                    match self.description {
-                        Some(description) => {
-                            HelpOutputDescription::Provided(Description::new(description))
-                        }
-                        None => HelpOutputDescription::Default,
+                    self.long_description.map(|_| HelpLongDescription),
+                    match self.usage_header {
+                        Some(expression) => HelpHeader::UserProvided(expression),
+                        None => HelpHeader::Default("usage_header"),
                     },
-                    HelpUsageOutput::new(
-                        match self.usage_header {
-                            Some(expression) => HelpHeader::UserProvided(expression),
-                            None => HelpHeader::Default("USAGE:"),
-                        },
-                        positional_usages,
-                        flag_usages,
-                        flag_group_usages,
-                    ),
                     if positional_help.len() > 0 {
                         Some(match self.positional_header {
                             Some(expression) => HelpHeader::UserProvided(expression),
-                            None => HelpHeader::Default("ARGUMENTS:"),
+                            None => HelpHeader::Default("arguments_header"),
                         })
                     } else {
                         None
@@ -56,17 +135,33 @@ impl<'a> CommandInfo<'a> {
                     if flag_help.len() > 0 {
                         Some(match self.flag_header {
                             Some(expression) => HelpHeader::UserProvided(expression),
-                            None => HelpHeader::Default("OPTIONS:"),
+                            None => HelpHeader::Default("options_header"),
                         })
                     } else {
                         None
                     },
                     flag_help,
+                    arg_group_helps,
                     flag_group_helps,
+                    if self.examples.len() > 0 {
+                        Some(HelpExamples::new(
+                            HelpHeader::Default("examples_header"),
+                            self.examples,
+                        ))
+                    } else {
+                        None
+                    },
+                    self.after_help.map(|after_help| {
+                        HelpEpilogue::new(HelpOutputDescription::Provided(Description::new(
+                            after_help,
+                        )))
+                    }),
                 ))
             } else {
                 None
             },
+            document,
+            self.dynamic_completion,
         )
     }
 }
@@ -1,7 +1,11 @@
-use proc_macro_util::{ast::Expression, tokens::Literal};
+use proc_macro_util::{
+    ast::Expression,
+    tokens::{Identifier, Literal},
+};
 
 mod default;
 mod extract;
+mod get;
 mod into_output;
 
 /// User provided information about a command
@@ -12,12 +16,30 @@ pub struct CommandInfo<'a> {
     /// The description of the program
     description: Option<Vec<Expression<'a>>>,
 
+    /// The extended description of the program, only included in long help
+    long_description: Option<Vec<Expression<'a>>>,
+
+    /// The epilogue written at the end of long help
+    after_help: Option<Vec<Expression<'a>>>,
+
+    /// The example command lines written in long help
+    examples: Vec<Expression<'a>>,
+
     /// If a version flag should be included, and what content it should have
     version: Option<Option<Expression<'a>>>,
 
+    /// The names and values of the build details following the version in the long version
+    long_version: Vec<(Identifier, Expression<'a>)>,
+
+    /// Should a "version" sub-command display the long version
+    version_command: bool,
+
     /// Should a help flag be included
     help: bool,
 
+    /// Should requests from dynamic completion scripts be answered when parsing the environment
+    dynamic_completion: bool,
+
     /// The user provided header for usage
     usage_header: Option<Expression<'a>>,
 
@@ -1,12 +1,19 @@
 use info::CommandInfo;
 use r#struct::StructInput;
 
+mod arg_group;
+mod expression_list;
+mod flag_names;
 mod info;
 mod r#struct;
 
 mod extract;
 mod into_output;
 
+pub use arg_group::ArgGroup;
+pub use expression_list::parse_expression_list;
+pub use flag_names::resolve_flag_names;
+
 /// The details extracted from the input item to the Command derive macro
 pub enum Input<'a> {
     /// The input item is a struct
@@ -1,5 +1,5 @@
 use super::{flag_group::FlagGroup, Flag, Positional, StructInput};
-use crate::command::input::CommandInfo;
+use crate::command::input::{resolve_flag_names, ArgGroup, CommandInfo};
 use proc_macro_util::{
     ast::items::{Struct, StructBody},
     Result,
@@ -7,7 +7,11 @@ use proc_macro_util::{
 
 impl<'a> StructInput<'a> {
     /// Extract the required details from `r#struct`
-    pub fn extract(r#struct: Struct<'a>, info: CommandInfo<'a>) -> Result<Self> {
+    pub fn extract(
+        r#struct: Struct<'a>,
+        info: CommandInfo<'a>,
+        arg_groups: Vec<ArgGroup>,
+    ) -> Result<Self> {
         let name = r#struct.name;
 
         let fields = match r#struct.body {
@@ -54,12 +58,30 @@ impl<'a> StructInput<'a> {
             };
         }
 
+        let long_names: Vec<_> = flags.iter().map(|flag| flag.long_names()).collect();
+        for flag in &mut flags {
+            resolve_flag_names(
+                flag.relationship_names(),
+                &long_names,
+                !flag_groups.is_empty(),
+            )?;
+        }
+
+        for arg_group in &arg_groups {
+            arg_group.check_members(|member| {
+                flags
+                    .iter()
+                    .any(|flag| flag.variable_name().to_string() == member.to_string())
+            })?;
+        }
+
         Ok(StructInput {
             name,
             generic_params: r#struct.generic_params,
             positionals,
             flags,
             flag_groups,
+            arg_groups,
             info,
         })
     }
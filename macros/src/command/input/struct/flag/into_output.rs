@@ -1,7 +1,7 @@
 use super::Flag;
 use crate::command::output::{
-    DefaultValue, Description, FlagHelpOutput, FlagHelpUsageOutput, FlagInfo, FlagLongName,
-    FlagShortName, FlagUnwrap, VariableDeclaration,
+    DefaultValue, Description, FlagDocumentOutput, FlagHelpOutput, FlagHelpUsageOutput, FlagInfo,
+    FlagLongName, FlagNames, FlagShortName, FlagUnwrap, Validator, VariableDeclaration,
 };
 
 impl<'a> Flag<'a> {
@@ -17,6 +17,7 @@ impl<'a> Flag<'a> {
         FlagUnwrap<'a>,
         FlagHelpUsageOutput,
         FlagHelpOutput,
+        FlagDocumentOutput,
     ) {
         (
             FlagInfo::new(
@@ -28,7 +29,13 @@ impl<'a> Flag<'a> {
                 self.min,
                 self.max,
                 self.default.map(DefaultValue::new).into(),
+                self.validate.map(Validator::new).into(),
+                self.complete.into(),
+                self.hidden,
                 self.description.map(Description::new).into(),
+                self.long_description.map(Description::new).into(),
+                FlagNames::new(self.conflicts_with),
+                FlagNames::new(self.requires),
             ),
             VariableDeclaration::new(self.variable_name.clone()),
             FlagLongName::new(
@@ -45,7 +52,8 @@ impl<'a> Flag<'a> {
             }),
             FlagUnwrap::new(self.variable_name, self.info_name.clone()),
             FlagHelpUsageOutput::new(self.info_name.clone()),
-            FlagHelpOutput::new(self.info_name, description_offset, short_names),
+            FlagHelpOutput::new(self.info_name.clone(), description_offset, short_names),
+            FlagDocumentOutput::new(self.info_name),
         )
     }
 }
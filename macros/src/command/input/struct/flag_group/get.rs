@@ -0,0 +1,9 @@
+use super::FlagGroup;
+use proc_macro_util::ast::Type;
+
+impl<'a> FlagGroup<'a> {
+    /// Gets the type of this flag group
+    pub fn r#type(&self) -> &Type<'a> {
+        &self.r#type
+    }
+}
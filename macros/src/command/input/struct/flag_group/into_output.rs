@@ -1,7 +1,7 @@
 use super::FlagGroup;
 use crate::command::output::{
-    FlagGroupDeclaration, FlagGroupHelpOutput, FlagGroupHelpUsageOutput, FlagGroupLongName,
-    FlagGroupShortName, FlagGroupUnwrap,
+    FlagGroupDeclaration, FlagGroupDocumentOutput, FlagGroupHelpOutput, FlagGroupHelpUsageOutput,
+    FlagGroupLongName, FlagGroupShortName, FlagGroupUnwrap,
 };
 
 impl<'a> FlagGroup<'a> {
@@ -14,14 +14,16 @@ impl<'a> FlagGroup<'a> {
         FlagGroupUnwrap<'a>,
         FlagGroupHelpUsageOutput<'a>,
         FlagGroupHelpOutput<'a>,
+        FlagGroupDocumentOutput<'a>,
     ) {
         (
             FlagGroupDeclaration::new(self.variable_name.clone(), self.r#type.clone()),
             FlagGroupLongName::new(self.variable_name.clone(), self.r#type.clone()),
             FlagGroupShortName::new(self.variable_name.clone(), self.r#type.clone()),
-            FlagGroupUnwrap::new(self.variable_name),
+            FlagGroupUnwrap::new(self.variable_name, self.r#type.clone()),
             FlagGroupHelpUsageOutput::new(self.r#type.clone()),
-            FlagGroupHelpOutput::new(self.header_name, self.r#type),
+            FlagGroupHelpOutput::new(self.header_name.clone(), self.r#type.clone()),
+            FlagGroupDocumentOutput::new(self.header_name, self.r#type),
         )
     }
 }
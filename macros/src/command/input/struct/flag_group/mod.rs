@@ -5,6 +5,7 @@ use proc_macro_util::{
 use std::borrow::Cow;
 
 mod extract;
+mod get;
 mod into_output;
 
 /// The information extracted for a flag group
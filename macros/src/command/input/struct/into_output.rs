@@ -1,15 +1,47 @@
 use crate::command::{
     input::StructInput,
-    output::{Output, StructOutput},
+    output::{LongNamesOutput, Output, StructOutput},
 };
 use proc_macro_util::ast::GenericParams;
 
 impl<'a> StructInput<'a> {
     /// Converts this input into an [`Output`]
     pub fn into_output(self) -> Output<'a> {
+        // The built-in color flag is left out if the command has its own
+        let color = !self
+            .flags
+            .iter()
+            .any(|flag| flag.info_names().1.to_string() == "\"--color\"");
+
+        let long_names = LongNamesOutput::new(
+            self.flags
+                .iter()
+                .filter_map(|flag| flag.visible_long_name().cloned())
+                .chain(self.info.builtin_long_names(color))
+                .collect(),
+            self.flag_groups
+                .iter()
+                .map(|flag_group| flag_group.r#type().clone())
+                .collect(),
+        );
+
+        // The built-in short flags are only checked after the flags of the command
+        let uses_short_name = |name: &str| {
+            self.flags.iter().any(|flag| {
+                flag.short_name()
+                    .is_some_and(|short_name| short_name.to_string().get(1..2) == Some(name))
+            })
+        };
+        let short_help = !uses_short_name("h");
+        let short_version = !uses_short_name("V");
+
         let mut flag_description_offset = 0;
         let mut short_names = false;
-        for flag in &self.flags {
+        for flag in self
+            .flags
+            .iter()
+            .filter(|flag| flag.visible_long_name().is_some())
+        {
             flag_description_offset = flag_description_offset.max(flag.help_length());
             short_names |= flag.short_name().is_some();
         }
@@ -20,6 +52,37 @@ impl<'a> StructInput<'a> {
 
         flag_description_offset += 2;
 
+        let in_arg_group: Vec<bool> = self
+            .flags
+            .iter()
+            .map(|flag| {
+                self.arg_groups
+                    .iter()
+                    .any(|arg_group| arg_group.contains(flag.variable_name()))
+            })
+            .collect();
+
+        let mut arg_group_info = Vec::with_capacity(self.arg_groups.len());
+        let mut arg_group_checks = Vec::with_capacity(self.arg_groups.len());
+        let mut arg_group_usages = Vec::with_capacity(self.arg_groups.len());
+        let mut arg_group_helps = Vec::with_capacity(self.arg_groups.len());
+        let mut arg_group_documents = Vec::with_capacity(self.arg_groups.len());
+        for (index, arg_group) in self.arg_groups.into_iter().enumerate() {
+            let (info, check, usage, help, document) = arg_group.into_output(index, |member| {
+                let member = member.to_string();
+                self.flags
+                    .iter()
+                    .find(|flag| flag.variable_name().to_string() == member)
+                    .unwrap()
+                    .info_names()
+            });
+            arg_group_info.push(info);
+            arg_group_checks.push(check);
+            arg_group_usages.push(usage);
+            arg_group_helps.push(help);
+            arg_group_documents.push(document);
+        }
+
         let mut flag_info = Vec::with_capacity(self.flags.len());
         let mut flag_declarations = Vec::with_capacity(self.flags.len());
         let mut flag_long_names = Vec::with_capacity(self.flags.len());
@@ -27,15 +90,21 @@ impl<'a> StructInput<'a> {
         let mut flag_unwraps = Vec::with_capacity(self.flags.len());
         let mut flag_usages = Vec::with_capacity(self.flags.len());
         let mut flag_help = Vec::with_capacity(self.flags.len());
-        for flag in self.flags {
-            let (info, declaration, long_name, short_name, unwrap, usage, help) =
+        let mut flag_document = Vec::with_capacity(self.flags.len());
+        for (flag, in_arg_group) in self.flags.into_iter().zip(in_arg_group) {
+            let (info, declaration, long_name, short_name, unwrap, usage, help, document) =
                 flag.into_output(flag_description_offset, short_names);
             flag_info.push(info);
             flag_declarations.push(declaration);
             flag_long_names.push(long_name);
             flag_unwraps.push(unwrap);
-            flag_usages.push(usage);
             flag_help.push(help);
+            flag_document.push(document);
+
+            // Members of an arg group are written in the usage of their group
+            if !in_arg_group {
+                flag_usages.push(usage);
+            }
 
             if let Some(short_name) = short_name {
                 flag_short_names.push(short_name);
@@ -57,9 +126,20 @@ impl<'a> StructInput<'a> {
         let mut positional_unwraps = Vec::with_capacity(self.positionals.len());
         let mut positional_usages = Vec::with_capacity(self.positionals.len());
         let mut positional_help = Vec::with_capacity(self.positionals.len());
+        let mut positional_document = Vec::with_capacity(self.positionals.len());
+        let mut document_sub_commands = Vec::with_capacity(self.positionals.len());
         for (index, positional) in self.positionals.into_iter().enumerate() {
-            let (info, declaration, r#match, sub_command, unwrap, usage, help) =
-                positional.into_output(index, positional_description_offset);
+            let (
+                info,
+                declaration,
+                r#match,
+                sub_command,
+                unwrap,
+                usage,
+                help,
+                document,
+                document_sub_command,
+            ) = positional.into_output(index, positional_description_offset);
             positional_info.push(info);
             positional_declarations.push(declaration);
             positional_matches.push(r#match);
@@ -67,6 +147,8 @@ impl<'a> StructInput<'a> {
             positional_unwraps.push(unwrap);
             positional_usages.push(usage);
             positional_help.push(help);
+            positional_document.push(document);
+            document_sub_commands.push(document_sub_command);
         }
 
         let mut flag_group_declarations = Vec::with_capacity(self.flag_groups.len());
@@ -75,8 +157,9 @@ impl<'a> StructInput<'a> {
         let mut flag_group_unwraps = Vec::with_capacity(self.flag_groups.len());
         let mut flag_group_usages = Vec::with_capacity(self.flag_groups.len());
         let mut flag_group_helps = Vec::with_capacity(self.flag_groups.len());
+        let mut flag_group_documents = Vec::with_capacity(self.flag_groups.len());
         for flag_group in self.flag_groups {
-            let (declaration, long_name, short_name, unwrap, usage, help) =
+            let (declaration, long_name, short_name, unwrap, usage, help, document) =
                 flag_group.into_output();
 
             flag_group_declarations.push(declaration);
@@ -85,15 +168,34 @@ impl<'a> StructInput<'a> {
             flag_group_unwraps.push(unwrap);
             flag_group_usages.push(usage);
             flag_group_helps.push(help);
+            flag_group_documents.push(document);
         }
 
-        let (version, help) = self.info.into_output(
+        let (
+            program_name,
+            version,
+            long_version,
+            version_command,
+            help,
+            document,
+            dynamic_completion,
+        ) = self.info.into_output(
             positional_usages,
             positional_help,
             flag_usages,
             flag_help,
             flag_group_usages,
             flag_group_helps,
+            arg_group_usages,
+            arg_group_helps,
+            positional_document,
+            flag_document,
+            arg_group_documents,
+            flag_group_documents,
+            document_sub_commands,
+            short_help,
+            short_version,
+            color,
         );
 
         let generic_args = self
@@ -105,6 +207,7 @@ impl<'a> StructInput<'a> {
             self.name,
             self.generic_params,
             generic_args,
+            program_name,
             positional_info,
             positional_declarations,
             positional_matches,
@@ -119,8 +222,16 @@ impl<'a> StructInput<'a> {
             flag_group_long_names,
             flag_group_short_names,
             flag_group_unwraps,
+            arg_group_info,
+            arg_group_checks,
+            long_names,
             version,
+            long_version,
+            version_command,
             help,
+            color,
+            document,
+            dynamic_completion,
         ))
     }
 }
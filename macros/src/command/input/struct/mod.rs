@@ -1,4 +1,4 @@
-use super::CommandInfo;
+use super::{ArgGroup, CommandInfo};
 use flag::Flag;
 use flag_group::FlagGroup;
 use positional::Positional;
@@ -29,6 +29,9 @@ pub struct StructInput<'a> {
     /// The flag groups in this struct
     flag_groups: Vec<FlagGroup<'a>>,
 
+    /// The groups of flags with a rule on how many can be passed together
+    arg_groups: Vec<ArgGroup>,
+
     /// The information describing the command
     info: CommandInfo<'a>,
 }
@@ -1,4 +1,5 @@
 use super::Positional;
+use crate::command::parse_expression_list;
 use proc_macro_util::{
     ast::{items::StructField, AttrInput, Expression, SimplePathSegment},
     tokens::{Group, Identifier, Literal},
@@ -55,7 +56,11 @@ impl<'a> Positional<'a> {
         let mut min = None;
         let mut max = None;
         let mut default = None;
+        let mut validate = None;
+        let mut complete = None;
+        let mut hidden = false;
         let mut description = None;
+        let mut long_description = None;
         if let Some(attribute) = arg_attribute {
             let mut parser = attribute.parser();
             while !parser.empty() {
@@ -79,21 +84,29 @@ impl<'a> Positional<'a> {
                         parser.parse::<Token![=]>()?;
                         default = Some(parser.parse::<Expression>()?.into_static());
                     }
+                    "validate" => {
+                        parser.parse::<Token![=]>()?;
+                        validate = Some(parser.parse::<Expression>()?.into_static());
+                    }
+                    "hidden" => hidden = true,
+                    "complete" => {
+                        parser.parse::<Token![=]>()?;
+                        complete = Some(parser.parse::<Expression>()?.into_static());
+                    }
                     "description" => {
                         parser.parse::<Token![=]>()?;
 
                         description = Some(if let Ok(group) = parser.step_parse::<&Group>() {
-                            let mut parser = group.parser();
-                            let mut description = vec![parser.parse::<Expression>()?.into_static()];
-                            while !parser.empty() {
-                                parser.parse::<Token![,]>()?;
-                                if parser.empty() {
-                                    break;
-                                }
+                            parse_expression_list(group)?
+                        } else {
+                            vec![parser.parse::<Expression>()?.into_static()]
+                        })
+                    }
+                    "long_description" => {
+                        parser.parse::<Token![=]>()?;
 
-                                description.push(parser.parse::<Expression>()?.into_static());
-                            }
-                            description
+                        long_description = Some(if let Ok(group) = parser.step_parse::<&Group>() {
+                            parse_expression_list(group)?
                         } else {
                             vec![parser.parse::<Expression>()?.into_static()]
                         })
@@ -124,7 +137,11 @@ impl<'a> Positional<'a> {
             min,
             max,
             default,
+            validate,
+            complete,
+            hidden,
             description,
+            long_description,
         })
     }
 }
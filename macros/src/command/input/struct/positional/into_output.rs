@@ -1,7 +1,8 @@
 use super::Positional;
 use crate::command::output::{
-    DefaultValue, Description, PositionalHelpOutput, PositionalHelpUsageOutput, PositionalInfo,
-    PositionalMatch, PositionalSubCommand, PositionalUnwrap, VariableDeclaration,
+    DefaultValue, Description, DocumentSubCommandsOutput, PositionalDocumentOutput,
+    PositionalHelpOutput, PositionalHelpUsageOutput, PositionalInfo, PositionalMatch,
+    PositionalSubCommand, PositionalUnwrap, Validator, VariableDeclaration,
 };
 
 impl<'a> Positional<'a> {
@@ -17,6 +18,8 @@ impl<'a> Positional<'a> {
         PositionalUnwrap<'a>,
         PositionalHelpUsageOutput,
         PositionalHelpOutput,
+        PositionalDocumentOutput,
+        DocumentSubCommandsOutput,
     ) {
         (
             PositionalInfo::new(
@@ -26,14 +29,20 @@ impl<'a> Positional<'a> {
                 self.min,
                 self.max,
                 self.default.map(DefaultValue::new).into(),
+                self.validate.map(Validator::new).into(),
+                self.complete.into(),
+                self.hidden,
                 self.description.map(Description::new).into(),
+                self.long_description.map(Description::new).into(),
             ),
             VariableDeclaration::new(self.variable_name.clone()),
             PositionalMatch::new(index, self.variable_name.clone(), self.info_name.clone()),
             PositionalSubCommand::new(index, self.variable_name.clone()),
             PositionalUnwrap::new(self.variable_name, self.info_name.clone()),
             PositionalHelpUsageOutput::new(self.info_name.clone()),
-            PositionalHelpOutput::new(self.info_name, description_offset),
+            PositionalHelpOutput::new(self.info_name.clone(), description_offset),
+            PositionalDocumentOutput::new(self.info_name.clone()),
+            DocumentSubCommandsOutput::new(self.info_name),
         )
     }
 }
@@ -32,6 +32,18 @@ pub struct Positional<'a> {
     /// The default value
     default: Option<Expression<'a>>,
 
+    /// The function checking each value
+    validate: Option<Expression<'a>>,
+
+    /// The function listing completion candidates for the value
+    complete: Option<Expression<'a>>,
+
+    /// Is this left out of usage, help and completions?
+    hidden: bool,
+
     /// The description of this positional
     description: Option<Vec<Expression<'static>>>,
+
+    /// The extended description, only included in long help
+    long_description: Option<Vec<Expression<'static>>>,
 }
@@ -5,9 +5,12 @@ use proc_macro_util::{ast::DeriveItem, Result};
 mod input;
 mod output;
 
+pub use input::{parse_expression_list, resolve_flag_names, ArgGroup};
 pub use output::{
-    DefaultValue, Description, FlagGroupHelpOutput, FlagGroupHelpUsageOutput, FlagHelpOutput,
-    FlagHelpUsageOutput, FlagInfo,
+    ArgGroupCheck, ArgGroupDocumentOutput, ArgGroupHelpOutput, ArgGroupHelpUsageOutput,
+    ArgGroupInfo, DefaultValue, Description, FlagDocumentOutput, FlagGroupDocumentOutput,
+    FlagGroupHelpOutput, FlagGroupHelpUsageOutput, FlagHelpOutput, FlagHelpUsageOutput, FlagInfo,
+    FlagNames, LongNamesOutput, OptionalOutput, UnwrappedField, Validator,
 };
 
 pub fn generate(item: DeriveItem) -> Result<Output> {
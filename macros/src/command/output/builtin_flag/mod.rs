@@ -0,0 +1,19 @@
+mod to_tokens;
+
+/// Generates the match arm for a flag built into a command
+pub enum BuiltinFlag {
+    /// The "--help" and "--help-all" flags
+    Help,
+
+    /// The "-h" flag, if no other flag uses it
+    ShortHelp,
+
+    /// The "--version" flag
+    Version,
+
+    /// The "-V" flag, if no other flag uses it
+    ShortVersion,
+
+    /// The "--color" flag
+    Color,
+}
@@ -0,0 +1,45 @@
+use super::BuiltinFlag;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for BuiltinFlag {
+    fn to_tokens(self, generator: &mut Generator) {
+        match self {
+            BuiltinFlag::Help => {
+                to_tokens! { generator
+                    "help" => {
+                        return Ok(::argparse::ParseOutcome::Help(<Self as ::argparse::Command>::help(&__command_list, ::argparse::HelpMode::Long)));
+                    }
+                    "help-all" => {
+                        return Ok(::argparse::ParseOutcome::Help(<Self as ::argparse::Command>::help(&__command_list, ::argparse::HelpMode::All)));
+                    }
+                }
+            }
+            BuiltinFlag::ShortHelp => {
+                to_tokens! { generator
+                    if __flag_name == 'h' {
+                        return Ok(::argparse::ParseOutcome::Help(<Self as ::argparse::Command>::help(&__command_list, ::argparse::HelpMode::Short)));
+                    }
+                }
+            }
+            BuiltinFlag::Version => {
+                to_tokens! { generator
+                    "version" => {
+                        return Ok(::argparse::ParseOutcome::Version(<Self as ::argparse::Command>::long_version()));
+                    }
+                }
+            }
+            BuiltinFlag::ShortVersion => {
+                to_tokens! { generator
+                    if __flag_name == 'V' {
+                        return Ok(::argparse::ParseOutcome::Version(<Self as ::argparse::Command>::version()));
+                    }
+                }
+            }
+            BuiltinFlag::Color => {
+                to_tokens! { generator
+                    "color" => __command_list.set_color(::argparse::ColorChoice::parse_flag(__source)?),
+                }
+            }
+        }
+    }
+}
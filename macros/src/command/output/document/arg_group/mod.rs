@@ -0,0 +1,10 @@
+use proc_macro_util::tokens::Identifier;
+
+mod new;
+mod to_tokens;
+
+/// Generates the tokens to write the documentation of an arg group after its flags
+pub struct ArgGroupDocumentOutput {
+    /// The name of the info variable describing this arg group
+    info_name: Identifier,
+}
@@ -0,0 +1,9 @@
+use super::ArgGroupDocumentOutput;
+use proc_macro_util::tokens::Identifier;
+
+impl ArgGroupDocumentOutput {
+    /// Creates a new [`ArgGroupDocumentOutput`]
+    pub fn new(info_name: Identifier) -> Self {
+        ArgGroupDocumentOutput { info_name }
+    }
+}
@@ -0,0 +1,12 @@
+use super::ArgGroupDocumentOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for ArgGroupDocumentOutput {
+    fn to_tokens(self, generator: &mut Generator) {
+        let ArgGroupDocumentOutput { info_name } = self;
+
+        to_tokens! { generator
+            __writer.arg_group(Self::#info_name)?;
+        }
+    }
+}
@@ -0,0 +1,16 @@
+mod to_tokens;
+
+/// Generates the tokens to write the documentation entry for a flag built into a command
+pub enum BuiltinFlagDocument {
+    /// The "--help" flag, with whether it has the short name "-h"
+    Help(bool),
+
+    /// The "--help-all" flag
+    HelpAll,
+
+    /// The "--version" flag, with whether it has the short name "-V"
+    Version(bool),
+
+    /// The "--color" flag
+    Color,
+}
@@ -0,0 +1,39 @@
+use super::BuiltinFlagDocument;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for BuiltinFlagDocument {
+    fn to_tokens(self, generator: &mut Generator) {
+        match self {
+            BuiltinFlagDocument::Help(true) => {
+                to_tokens! { generator
+                    __writer.flag(&::argparse::FlagInfo::help(true))?;
+                }
+            }
+            BuiltinFlagDocument::Help(false) => {
+                to_tokens! { generator
+                    __writer.flag(&::argparse::FlagInfo::help(false))?;
+                }
+            }
+            BuiltinFlagDocument::HelpAll => {
+                to_tokens! { generator
+                    __writer.flag(&::argparse::FlagInfo::help_all())?;
+                }
+            }
+            BuiltinFlagDocument::Version(true) => {
+                to_tokens! { generator
+                    __writer.flag(&::argparse::FlagInfo::version(true))?;
+                }
+            }
+            BuiltinFlagDocument::Version(false) => {
+                to_tokens! { generator
+                    __writer.flag(&::argparse::FlagInfo::version(false))?;
+                }
+            }
+            BuiltinFlagDocument::Color => {
+                to_tokens! { generator
+                    __writer.flag(&::argparse::FlagInfo::color())?;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use super::FlagDocumentOutput;
+use proc_macro_util::tokens::Identifier;
+
+impl FlagDocumentOutput {
+    /// Creates a new [`FlagDocumentOutput`]
+    pub fn new(info_name: Identifier) -> Self {
+        FlagDocumentOutput { info_name }
+    }
+}
@@ -0,0 +1,12 @@
+use super::FlagDocumentOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for FlagDocumentOutput {
+    fn to_tokens(self, generator: &mut Generator) {
+        let FlagDocumentOutput { info_name } = self;
+
+        to_tokens! { generator
+            __writer.flag(Self::#info_name)?;
+        }
+    }
+}
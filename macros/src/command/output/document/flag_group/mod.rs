@@ -0,0 +1,13 @@
+use proc_macro_util::{ast::Type, tokens::Literal};
+
+mod new;
+mod to_tokens;
+
+/// Generates the tokens to write the documentation entries for a flag group
+pub struct FlagGroupDocumentOutput<'a> {
+    /// The header for the group
+    header: Literal,
+
+    /// The type of the flag group
+    r#type: Type<'a>,
+}
@@ -0,0 +1,9 @@
+use super::FlagGroupDocumentOutput;
+use proc_macro_util::{ast::Type, tokens::Literal};
+
+impl<'a> FlagGroupDocumentOutput<'a> {
+    /// Creates a new [`FlagGroupDocumentOutput`]
+    pub fn new(header: Literal, r#type: Type<'a>) -> Self {
+        FlagGroupDocumentOutput { header, r#type }
+    }
+}
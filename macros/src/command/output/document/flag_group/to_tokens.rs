@@ -0,0 +1,14 @@
+use super::FlagGroupDocumentOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for FlagGroupDocumentOutput<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let FlagGroupDocumentOutput { header, r#type } = self;
+
+        to_tokens! { generator
+            __writer.flag_group::<#r#type>(#header)?;
+            <#r#type as ::argparse::FlagGroup>::write_document(__writer)?;
+            __writer.end_flag_group()?;
+        }
+    }
+}
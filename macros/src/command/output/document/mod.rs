@@ -0,0 +1,71 @@
+use super::{HelpOutputDescription, HelpOutputName, HelpUsageOutput, ProgramName};
+
+mod arg_group;
+mod builtin_flag;
+mod flag;
+mod flag_group;
+mod positional;
+mod section_title;
+mod sub_commands;
+mod version_command;
+
+mod new;
+mod to_tokens;
+
+pub use arg_group::ArgGroupDocumentOutput;
+pub use builtin_flag::BuiltinFlagDocument;
+pub use flag::FlagDocumentOutput;
+pub use flag_group::FlagGroupDocumentOutput;
+pub use positional::PositionalDocumentOutput;
+pub use section_title::SectionTitle;
+pub use sub_commands::DocumentSubCommandsOutput;
+pub use version_command::VersionCommandDocument;
+
+/// Generates the code to write the descriptions, usage and documentation sections for a command
+///
+/// These are generated whether or not the command has a help flag, as documentation and
+/// completions are built from them.
+pub struct DocumentOutput<'a> {
+    /// The name of the program to use
+    name: HelpOutputName,
+
+    /// The description to use
+    description: HelpOutputDescription<'a>,
+
+    /// The extended description to use
+    long_description: HelpOutputDescription<'a>,
+
+    /// Overrides the program name in usage, as the command list passed may come from the
+    /// arguments rather than parsing
+    program_name: Option<ProgramName>,
+
+    /// The usage of the command
+    usage: HelpUsageOutput<'a>,
+
+    /// The title of the positionals section
+    positional_title: SectionTitle<'a>,
+
+    /// The positionals to write entries for
+    positionals: Vec<PositionalDocumentOutput>,
+
+    /// The title of the flags section
+    flag_title: SectionTitle<'a>,
+
+    /// The flags to write entries for
+    flags: Vec<FlagDocumentOutput>,
+
+    /// The enabled flags built into the command, written after its own flags
+    builtin_flags: Vec<BuiltinFlagDocument>,
+
+    /// The arg groups of the flags to write after them
+    arg_groups: Vec<ArgGroupDocumentOutput>,
+
+    /// The flag groups to write entries for
+    flag_groups: Vec<FlagGroupDocumentOutput<'a>>,
+
+    /// The positionals which may write sections for sub-commands
+    sub_commands: Vec<DocumentSubCommandsOutput>,
+
+    /// The "version" sub-command, if enabled
+    version_command: Option<VersionCommandDocument>,
+}
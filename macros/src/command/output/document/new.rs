@@ -0,0 +1,43 @@
+use super::{
+    ArgGroupDocumentOutput, BuiltinFlagDocument, DocumentOutput, DocumentSubCommandsOutput,
+    FlagDocumentOutput, FlagGroupDocumentOutput, PositionalDocumentOutput, SectionTitle,
+    VersionCommandDocument,
+};
+use crate::command::output::{HelpOutputDescription, HelpOutputName, HelpUsageOutput, ProgramName};
+
+impl<'a> DocumentOutput<'a> {
+    /// Creates a new [`DocumentOutput`]
+    pub fn new(
+        name: HelpOutputName,
+        description: HelpOutputDescription<'a>,
+        long_description: HelpOutputDescription<'a>,
+        program_name: Option<ProgramName>,
+        usage: HelpUsageOutput<'a>,
+        positional_title: SectionTitle<'a>,
+        positionals: Vec<PositionalDocumentOutput>,
+        flag_title: SectionTitle<'a>,
+        flags: Vec<FlagDocumentOutput>,
+        builtin_flags: Vec<BuiltinFlagDocument>,
+        arg_groups: Vec<ArgGroupDocumentOutput>,
+        flag_groups: Vec<FlagGroupDocumentOutput<'a>>,
+        sub_commands: Vec<DocumentSubCommandsOutput>,
+        version_command: Option<VersionCommandDocument>,
+    ) -> Self {
+        DocumentOutput {
+            name,
+            description,
+            long_description,
+            program_name,
+            usage,
+            positional_title,
+            positionals,
+            flag_title,
+            flags,
+            builtin_flags,
+            arg_groups,
+            flag_groups,
+            sub_commands,
+            version_command,
+        }
+    }
+}
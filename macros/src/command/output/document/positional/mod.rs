@@ -0,0 +1,10 @@
+use proc_macro_util::tokens::Identifier;
+
+mod new;
+mod to_tokens;
+
+/// Generates the tokens to write the documentation entry for a positional
+pub struct PositionalDocumentOutput {
+    /// The name of the info variable describing this positional
+    info_name: Identifier,
+}
@@ -0,0 +1,9 @@
+use super::PositionalDocumentOutput;
+use proc_macro_util::tokens::Identifier;
+
+impl PositionalDocumentOutput {
+    /// Creates a new [`PositionalDocumentOutput`]
+    pub fn new(info_name: Identifier) -> Self {
+        PositionalDocumentOutput { info_name }
+    }
+}
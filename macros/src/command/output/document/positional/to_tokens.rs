@@ -0,0 +1,12 @@
+use super::PositionalDocumentOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for PositionalDocumentOutput {
+    fn to_tokens(self, generator: &mut Generator) {
+        let PositionalDocumentOutput { info_name } = self;
+
+        to_tokens! { generator
+            __writer.positional(Self::#info_name)?;
+        }
+    }
+}
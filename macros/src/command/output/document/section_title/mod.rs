@@ -0,0 +1,12 @@
+use proc_macro_util::ast::Expression;
+
+mod to_tokens;
+
+/// The title of a documentation section, matching the header of the section in help
+pub enum SectionTitle<'a> {
+    /// The user provided a header
+    UserProvided(Expression<'a>),
+
+    /// Use the default header, the name of the `Messages` method providing it
+    Default(&'static str),
+}
@@ -0,0 +1,16 @@
+use super::SectionTitle;
+use proc_macro_util::{to_tokens, tokens::Identifier, Generator, ToTokens};
+
+impl<'a> ToTokens for SectionTitle<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        match self {
+            SectionTitle::UserProvided(expression) => expression.to_tokens(generator),
+            SectionTitle::Default(default) => {
+                let default = Identifier::new(default);
+                to_tokens! { generator
+                    ::argparse::messages().#default()
+                }
+            }
+        }
+    }
+}
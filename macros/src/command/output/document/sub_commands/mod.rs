@@ -0,0 +1,10 @@
+use proc_macro_util::tokens::Identifier;
+
+mod new;
+mod to_tokens;
+
+/// Generates the tokens to write the documentation sections for the sub-commands of a positional
+pub struct DocumentSubCommandsOutput {
+    /// The name of the info variable describing the positional
+    info_name: Identifier,
+}
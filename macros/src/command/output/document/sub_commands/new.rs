@@ -0,0 +1,9 @@
+use super::DocumentSubCommandsOutput;
+use proc_macro_util::tokens::Identifier;
+
+impl DocumentSubCommandsOutput {
+    /// Creates a new [`DocumentSubCommandsOutput`]
+    pub fn new(info_name: Identifier) -> Self {
+        DocumentSubCommandsOutput { info_name }
+    }
+}
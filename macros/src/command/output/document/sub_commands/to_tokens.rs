@@ -0,0 +1,12 @@
+use super::DocumentSubCommandsOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for DocumentSubCommandsOutput {
+    fn to_tokens(self, generator: &mut Generator) {
+        let DocumentSubCommandsOutput { info_name } = self;
+
+        to_tokens! { generator
+            Self::#info_name.write_document_sub_commands(__writer, &__command_list)?;
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use super::DocumentOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for DocumentOutput<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let DocumentOutput {
+            name,
+            description,
+            long_description,
+            program_name,
+            usage,
+            positional_title,
+            positionals,
+            flag_title,
+            flags,
+            builtin_flags,
+            arg_groups,
+            flag_groups,
+            sub_commands,
+            version_command,
+        } = self;
+
+        to_tokens! { generator
+            fn write_description(
+                __output: &mut dyn ::std::io::Write,
+                __margin: usize,
+            ) -> ::std::io::Result<()> {
+                (#description)(__output, __margin)
+            }
+
+            fn write_long_description(
+                __output: &mut dyn ::std::io::Write,
+                __margin: usize,
+            ) -> ::std::io::Result<()> {
+                (#long_description)(__output, __margin)
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn write_usage(
+                __output: &mut dyn ::std::io::Write,
+                __command_list: &::argparse::CommandList,
+                __style: ::argparse::Style,
+            ) -> ::std::io::Result<()>
+        }
+
+        {
+            let generator = &mut generator.group_brace();
+            if let Some(program_name) = program_name {
+                to_tokens! { generator
+                    let mut __command_list = __command_list.clone();
+                    #program_name
+                }
+            }
+
+            to_tokens! { generator
+                #usage
+
+                Ok(())
+            }
+        }
+
+        to_tokens! { generator
+            fn write_document<__W: ::argparse::DocumentWriter>(
+                __writer: &mut __W,
+                __command_list: &::argparse::CommandList,
+            ) -> ::std::io::Result<()>
+        }
+
+        let generator = &mut generator.group_brace();
+
+        if sub_commands.is_empty() && version_command.is_none() {
+            to_tokens! { generator
+                __writer.command::<Self>(#name, __command_list)?;
+            }
+        } else {
+            to_tokens! { generator
+                let __command_list = __writer.command::<Self>(#name, __command_list)?;
+            }
+        }
+
+        if !positionals.is_empty() {
+            to_tokens! { generator
+                __writer.section(#positional_title)?;
+                #positionals
+            }
+        }
+
+        if !flags.is_empty() || !builtin_flags.is_empty() {
+            to_tokens! { generator
+                __writer.section(#flag_title)?;
+                #flags
+                #builtin_flags
+                #arg_groups
+            }
+        }
+
+        to_tokens! { generator
+            #flag_groups
+            #sub_commands
+            #version_command
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod to_tokens;
+
+/// Generates the tokens to write the documentation section for the "version" sub-command
+pub struct VersionCommandDocument;
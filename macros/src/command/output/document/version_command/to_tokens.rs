@@ -0,0 +1,13 @@
+use super::VersionCommandDocument;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for VersionCommandDocument {
+    fn to_tokens(self, generator: &mut Generator) {
+        to_tokens! { generator
+            <::argparse::VersionSubCommand as ::argparse::Command>::write_document(
+                __writer,
+                &__command_list.sub_command("version"),
+            )?;
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod to_tokens;
+
+/// Generates the function enabling answers to dynamic completion scripts
+pub struct DynamicCompletion;
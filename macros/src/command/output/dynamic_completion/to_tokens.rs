@@ -0,0 +1,12 @@
+use super::DynamicCompletion;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for DynamicCompletion {
+    fn to_tokens(self, generator: &mut Generator) {
+        to_tokens! { generator
+            fn dynamic_completion_enabled() -> bool {
+                true
+            }
+        }
+    }
+}
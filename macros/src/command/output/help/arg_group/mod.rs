@@ -0,0 +1,10 @@
+use proc_macro_util::tokens::Identifier;
+
+mod new;
+mod to_tokens;
+
+/// Generates the tokens to display the rule of an arg group after its flags in help
+pub struct ArgGroupHelpOutput {
+    /// The name of the info variable describing this arg group
+    info_name: Identifier,
+}
@@ -0,0 +1,9 @@
+use super::ArgGroupHelpOutput;
+use proc_macro_util::tokens::Identifier;
+
+impl ArgGroupHelpOutput {
+    /// Creates a new [`ArgGroupHelpOutput`]
+    pub fn new(info_name: Identifier) -> Self {
+        ArgGroupHelpOutput { info_name }
+    }
+}
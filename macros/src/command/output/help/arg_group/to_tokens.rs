@@ -0,0 +1,12 @@
+use super::ArgGroupHelpOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for ArgGroupHelpOutput {
+    fn to_tokens(self, generator: &mut Generator) {
+        let ArgGroupHelpOutput { info_name } = self;
+
+        to_tokens! { generator
+            Self::#info_name.write_help(__output, __wrap)?;
+        }
+    }
+}
@@ -9,4 +9,7 @@ pub enum HelpOutputDescription<'a> {
 
     /// Use the provided description
     Provided(Description<'a>),
+
+    /// Write nothing
+    None,
 }
@@ -6,14 +6,23 @@ impl<'a> ToTokens for HelpOutputDescription<'a> {
         match self {
             HelpOutputDescription::Default => {
                 to_tokens! { generator
-                   |_| { ::std::println!("{}", ::std::env!("CARGO_PKG_DESCRIPTION")); }
+                    |__output: &mut dyn ::std::io::Write, _: usize| -> ::std::io::Result<()> {
+                        ::std::writeln!(__output, "{}", ::std::env!("CARGO_PKG_DESCRIPTION"))
+                    }
                 }
             }
             HelpOutputDescription::Provided(description) => {
                 to_tokens! { generator
-                    |_| {
-                        (#description)(0);
-                        ::std::println!();
+                    |__output: &mut dyn ::std::io::Write, __margin: usize| -> ::std::io::Result<()> {
+                        (#description)(__output, __margin)?;
+                        ::std::writeln!(__output)
+                    }
+                }
+            }
+            HelpOutputDescription::None => {
+                to_tokens! { generator
+                    |_: &mut dyn ::std::io::Write, _: usize| -> ::std::io::Result<()> {
+                        Ok(())
                     }
                 }
             }
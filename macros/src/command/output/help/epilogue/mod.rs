@@ -0,0 +1,10 @@
+use super::HelpOutputDescription;
+
+mod new;
+mod to_tokens;
+
+/// Generates the code to write the epilogue at the end of long help
+pub struct HelpEpilogue<'a> {
+    /// The text of the epilogue
+    description: HelpOutputDescription<'a>,
+}
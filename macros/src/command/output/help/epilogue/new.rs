@@ -0,0 +1,9 @@
+use super::HelpEpilogue;
+use crate::command::output::HelpOutputDescription;
+
+impl<'a> HelpEpilogue<'a> {
+    /// Creates a new [`HelpEpilogue`]
+    pub fn new(description: HelpOutputDescription<'a>) -> Self {
+        HelpEpilogue { description }
+    }
+}
@@ -0,0 +1,13 @@
+use super::HelpEpilogue;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for HelpEpilogue<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let HelpEpilogue { description } = self;
+
+        to_tokens! { generator
+            ::std::writeln!(__output)?;
+            __wrap.write_description(__output, #description, 0)?;
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use super::HelpHeader;
+use proc_macro_util::ast::Expression;
+
+mod new;
+mod to_tokens;
+
+/// Generates the code to write the examples section in long help
+pub struct HelpExamples<'a> {
+    /// The header for the section
+    header: HelpHeader<'a>,
+
+    /// The example command lines
+    examples: Vec<Expression<'a>>,
+}
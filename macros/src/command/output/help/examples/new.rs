@@ -0,0 +1,10 @@
+use super::HelpExamples;
+use crate::command::output::HelpHeader;
+use proc_macro_util::ast::Expression;
+
+impl<'a> HelpExamples<'a> {
+    /// Creates a new [`HelpExamples`]
+    pub fn new(header: HelpHeader<'a>, examples: Vec<Expression<'a>>) -> Self {
+        HelpExamples { header, examples }
+    }
+}
@@ -0,0 +1,18 @@
+use super::HelpExamples;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for HelpExamples<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let HelpExamples { header, examples } = self;
+
+        to_tokens! { generator
+            #header
+        }
+
+        for example in examples {
+            to_tokens! { generator
+                ::std::writeln!(__output, "    {}", #example)?;
+            }
+        }
+    }
+}
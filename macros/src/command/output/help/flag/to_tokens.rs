@@ -10,7 +10,7 @@ impl ToTokens for FlagHelpOutput {
         } = self;
 
         to_tokens! { generator
-            Self::#info_name.display_help(#short_names, #description_offset);
+            Self::#info_name.write_help(__output, #short_names, #description_offset, __wrap, __style, __mode)?;
         }
     }
 }
@@ -6,9 +6,9 @@ impl<'a> ToTokens for FlagGroupHelpOutput<'a> {
         let FlagGroupHelpOutput { header, r#type } = self;
 
         to_tokens! { generator
-            ::std::println!();
-            ::std::println!("{}:", #header);
-            <#r#type as ::argparse::FlagGroup>::print_help();
+            ::std::writeln!(__output)?;
+            ::std::writeln!(__output, "{}", __style.header(::std::format_args!("{}:", #header)))?;
+            <#r#type as ::argparse::FlagGroup>::write_help(__output, __wrap, __style, __mode)?;
         }
     }
 }
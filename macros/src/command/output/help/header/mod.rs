@@ -7,6 +7,6 @@ pub enum HelpHeader<'a> {
     /// The user provided a header
     UserProvided(Expression<'a>),
 
-    /// Use the default header
+    /// Use the default header, the name of the `Messages` method providing it
     Default(&'static str),
 }
@@ -1,21 +1,22 @@
 use super::HelpHeader;
-use proc_macro_util::{to_tokens, Generator, ToTokens};
+use proc_macro_util::{to_tokens, tokens::Identifier, Generator, ToTokens};
 
 impl<'a> ToTokens for HelpHeader<'a> {
     fn to_tokens(self, generator: &mut Generator) {
         to_tokens! { generator
-            ::std::println!();
+            ::std::writeln!(__output)?;
         }
 
         match self {
             HelpHeader::UserProvided(expression) => {
-                to_tokens! {    generator
-                    ::std::println!("{}", #expression);
+                to_tokens! { generator
+                    ::std::writeln!(__output, "{}", __style.header(#expression))?;
                 }
             }
             HelpHeader::Default(default) => {
+                let default = Identifier::new(default);
                 to_tokens! { generator
-                    ::std::println!(#default);
+                    ::std::writeln!(__output, "{}", __style.header(::argparse::messages().#default()))?;
                 }
             }
         }
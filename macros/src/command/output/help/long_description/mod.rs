@@ -0,0 +1,4 @@
+mod to_tokens;
+
+/// Generates the code to write the extended description of a command in long help
+pub struct HelpLongDescription;
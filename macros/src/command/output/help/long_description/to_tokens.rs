@@ -0,0 +1,11 @@
+use super::HelpLongDescription;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for HelpLongDescription {
+    fn to_tokens(self, generator: &mut Generator) {
+        to_tokens! { generator
+            ::std::writeln!(__output)?;
+            __wrap.write_description(__output, <Self as ::argparse::Command>::write_long_description, 0)?;
+        }
+    }
+}
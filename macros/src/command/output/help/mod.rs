@@ -1,7 +1,11 @@
+mod arg_group;
 mod description;
+mod epilogue;
+mod examples;
 mod flag;
 mod flag_group;
 mod header;
+mod long_description;
 mod name;
 mod positional;
 mod usage;
@@ -9,14 +13,19 @@ mod usage;
 mod new;
 mod to_tokens;
 
+pub use arg_group::ArgGroupHelpOutput;
 pub use description::HelpOutputDescription;
+pub use epilogue::HelpEpilogue;
+pub use examples::HelpExamples;
 pub use flag::FlagHelpOutput;
 pub use flag_group::FlagGroupHelpOutput;
 pub use header::HelpHeader;
+pub use long_description::HelpLongDescription;
 pub use name::HelpOutputName;
 pub use positional::PositionalHelpOutput;
 pub use usage::{
-    FlagGroupHelpUsageOutput, FlagHelpUsageOutput, HelpUsageOutput, PositionalHelpUsageOutput,
+    ArgGroupHelpUsageOutput, FlagGroupHelpUsageOutput, FlagHelpUsageOutput, HelpUsageOutput,
+    PositionalHelpUsageOutput,
 };
 
 /// Generates the code to display a help message
@@ -24,11 +33,11 @@ pub struct HelpOutput<'a> {
     /// The name of the program to use
     name: HelpOutputName,
 
-    /// The description to use
-    description: HelpOutputDescription<'a>,
+    /// Writes the extended description in long help, if there is one
+    long_description_help: Option<HelpLongDescription>,
 
-    /// The usage to output with this help
-    usage: HelpUsageOutput<'a>,
+    /// The header for the usage
+    usage_header: HelpHeader<'a>,
 
     /// The header for the positional section
     positional_header: Option<HelpHeader<'a>>,
@@ -42,6 +51,15 @@ pub struct HelpOutput<'a> {
     /// The flags to display help for
     flags: Vec<FlagHelpOutput>,
 
+    /// The rules of the arg groups to display after the flags
+    arg_groups: Vec<ArgGroupHelpOutput>,
+
     /// The flag groups to display help for
     flag_groups: Vec<FlagGroupHelpOutput<'a>>,
+
+    /// The examples to display in long help
+    examples: Option<HelpExamples<'a>>,
+
+    /// The epilogue to display at the end of long help
+    epilogue: Option<HelpEpilogue<'a>>,
 }
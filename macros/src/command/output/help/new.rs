@@ -1,29 +1,36 @@
 use super::{
-    flag_group::FlagGroupHelpOutput, FlagHelpOutput, HelpHeader, HelpOutput, HelpOutputDescription,
-    HelpOutputName, HelpUsageOutput, PositionalHelpOutput,
+    flag_group::FlagGroupHelpOutput, ArgGroupHelpOutput, FlagHelpOutput, HelpEpilogue,
+    HelpExamples, HelpHeader, HelpLongDescription, HelpOutput, HelpOutputName,
+    PositionalHelpOutput,
 };
 
 impl<'a> HelpOutput<'a> {
     /// Creates a new [`HelpOutput`]
     pub fn new(
         name: HelpOutputName,
-        description: HelpOutputDescription<'a>,
-        usage: HelpUsageOutput<'a>,
+        long_description_help: Option<HelpLongDescription>,
+        usage_header: HelpHeader<'a>,
         positional_header: Option<HelpHeader<'a>>,
         positionals: Vec<PositionalHelpOutput>,
         flag_header: Option<HelpHeader<'a>>,
         flags: Vec<FlagHelpOutput>,
+        arg_groups: Vec<ArgGroupHelpOutput>,
         flag_groups: Vec<FlagGroupHelpOutput<'a>>,
+        examples: Option<HelpExamples<'a>>,
+        epilogue: Option<HelpEpilogue<'a>>,
     ) -> Self {
         HelpOutput {
             name,
-            description,
-            usage,
+            long_description_help,
+            usage_header,
             positional_header,
             positionals,
             flag_header,
             flags,
+            arg_groups,
             flag_groups,
+            examples,
+            epilogue,
         }
     }
 }
@@ -9,7 +9,7 @@ impl ToTokens for PositionalHelpOutput {
         } = self;
 
         to_tokens! { generator
-            Self::#info_name.display_help(#description_offset);
+            Self::#info_name.write_help(__output, #description_offset, __wrap, __mode)?;
         }
     }
 }
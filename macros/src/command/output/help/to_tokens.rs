@@ -5,31 +5,54 @@ impl<'a> ToTokens for HelpOutput<'a> {
     fn to_tokens(self, generator: &mut Generator) {
         let HelpOutput {
             name,
-            description,
-            usage,
+            long_description_help,
+            usage_header,
             positional_header,
             positionals,
             flag_header,
             flags,
+            arg_groups,
             flag_groups,
+            examples,
+            epilogue,
         } = self;
 
         to_tokens! { generator
-            "help" => {
-                ::std::println!("{}", #name);
-                (#description)(0);
+            fn write_help(
+                __output: &mut dyn ::std::io::Write,
+                __command_list: &::argparse::CommandList,
+                __wrap: ::argparse::TextWrap,
+                __style: ::argparse::Style,
+                __mode: ::argparse::HelpMode,
+            ) -> ::std::io::Result<()> {
+                ::std::writeln!(__output, "{}", #name)?;
+                if __mode.shows_long() {
+                    __wrap.write_description(__output, <Self as ::argparse::Command>::write_description, 0)?;
+                    #long_description_help
+                } else {
+                    __wrap.write_summary(__output, <Self as ::argparse::Command>::write_description, 0)?;
+                }
 
-                #usage
+                #usage_header
+                ::std::write!(__output, "    ")?;
+                <Self as ::argparse::Command>::write_usage(__output, __command_list, __style)?;
+                ::std::writeln!(__output)?;
 
                 #positional_header
                 #positionals
 
                 #flag_header
                 #flags
+                #arg_groups
 
                 #flag_groups
 
-                return Ok(None);
+                if __mode.shows_long() {
+                    #examples
+                    #epilogue
+                }
+
+                Ok(())
             }
         }
     }
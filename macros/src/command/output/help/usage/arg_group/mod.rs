@@ -0,0 +1,13 @@
+use proc_macro_util::tokens::Identifier;
+
+mod new;
+mod to_tokens;
+
+/// Generates the usage string for an arg group
+pub struct ArgGroupHelpUsageOutput {
+    /// Must one of the flags be passed?
+    required: bool,
+
+    /// The names for the information describing the flags in the group
+    info_names: Vec<Identifier>,
+}
@@ -0,0 +1,12 @@
+use super::ArgGroupHelpUsageOutput;
+use proc_macro_util::tokens::Identifier;
+
+impl ArgGroupHelpUsageOutput {
+    /// Creates a new [`ArgGroupHelpUsageOutput`]
+    pub fn new(required: bool, info_names: Vec<Identifier>) -> Self {
+        ArgGroupHelpUsageOutput {
+            required,
+            info_names,
+        }
+    }
+}
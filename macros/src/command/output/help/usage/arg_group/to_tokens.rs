@@ -0,0 +1,41 @@
+use super::ArgGroupHelpUsageOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for ArgGroupHelpUsageOutput {
+    fn to_tokens(self, generator: &mut Generator) {
+        let ArgGroupHelpUsageOutput {
+            required,
+            info_names,
+        } = self;
+
+        if !required {
+            to_tokens! { generator
+                __optional_flags = true;
+            }
+            return;
+        }
+
+        to_tokens! { generator
+            ::std::write!(__output, "<")?;
+        }
+
+        let mut first = true;
+        for info_name in info_names {
+            if first {
+                first = false;
+            } else {
+                to_tokens! { generator
+                    ::std::write!(__output, "|")?;
+                }
+            }
+
+            to_tokens! { generator
+                Self::#info_name.write_usage_name(__output, __style)?;
+            }
+        }
+
+        to_tokens! { generator
+            ::std::write!(__output, "> ")?;
+        }
+    }
+}
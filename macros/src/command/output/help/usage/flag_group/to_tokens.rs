@@ -6,7 +6,7 @@ impl<'a> ToTokens for FlagGroupHelpUsageOutput<'a> {
         let FlagGroupHelpUsageOutput { r#type } = self;
 
         to_tokens! { generator
-            __optional_flags |= <#r#type as ::argparse::FlagGroup>::print_help_usage();
+            __optional_flags |= <#r#type as ::argparse::FlagGroup>::write_help_usage(__output, __style)?;
         }
     }
 }
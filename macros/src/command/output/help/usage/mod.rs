@@ -1,5 +1,4 @@
-use super::HelpHeader;
-
+mod arg_group;
 mod flag;
 mod flag_group;
 mod positional;
@@ -7,15 +6,13 @@ mod positional;
 mod new;
 mod to_tokens;
 
+pub use arg_group::ArgGroupHelpUsageOutput;
 pub use flag::FlagHelpUsageOutput;
 pub use flag_group::FlagGroupHelpUsageOutput;
 pub use positional::PositionalHelpUsageOutput;
 
 /// Produces the usage for a help message
 pub struct HelpUsageOutput<'a> {
-    /// The header for the usage
-    header: HelpHeader<'a>,
-
     /// The positional usages
     positionals: Vec<PositionalHelpUsageOutput>,
 
@@ -24,4 +21,7 @@ pub struct HelpUsageOutput<'a> {
 
     /// The flag group usages
     flag_groups: Vec<FlagGroupHelpUsageOutput<'a>>,
+
+    /// The arg group usages
+    arg_groups: Vec<ArgGroupHelpUsageOutput>,
 }
@@ -4,29 +4,29 @@ use proc_macro_util::{to_tokens, Generator, ToTokens};
 impl<'a> ToTokens for HelpUsageOutput<'a> {
     fn to_tokens(self, generator: &mut Generator) {
         let HelpUsageOutput {
-            header,
             positionals,
             flags,
             flag_groups,
+            arg_groups,
         } = self;
 
         to_tokens! { generator
-            #header
-
-            ::std::print!("    {}", __command_list);
+            ::std::write!(__output, "{}", __command_list)?;
+            if !__command_list.is_empty() {
+                ::std::write!(__output, " ")?;
+            }
 
             let mut __optional_flags = false;
 
             #flags
             #flag_groups
+            #arg_groups
 
             if __optional_flags {
-                ::std::print!("[OPTIONS..] ");
+                ::std::write!(__output, "{} ", ::argparse::messages().options_usage())?;
             }
 
             #positionals
-
-            ::std::println!();
         }
     }
 }
@@ -0,0 +1,14 @@
+use proc_macro_util::{ast::Type, tokens::Literal};
+
+mod new;
+mod to_tokens;
+
+/// Generates the code to add the long names of flags which aren't hidden to `__long_names`, used
+/// for suggesting a flag when an unknown one is passed
+pub struct LongNamesOutput<'a> {
+    /// The long names of the flags, including the leading dashes
+    names: Vec<Literal>,
+
+    /// The types of the flag groups whose long names are included
+    flag_groups: Vec<Type<'a>>,
+}
@@ -0,0 +1,9 @@
+use super::LongNamesOutput;
+use proc_macro_util::{ast::Type, tokens::Literal};
+
+impl<'a> LongNamesOutput<'a> {
+    /// Creates a new [`LongNamesOutput`]
+    pub fn new(names: Vec<Literal>, flag_groups: Vec<Type<'a>>) -> Self {
+        LongNamesOutput { names, flag_groups }
+    }
+}
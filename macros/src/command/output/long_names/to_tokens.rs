@@ -0,0 +1,20 @@
+use super::LongNamesOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for LongNamesOutput<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let LongNamesOutput { names, flag_groups } = self;
+
+        for name in names {
+            to_tokens! { generator
+                __long_names.push(#name);
+            }
+        }
+
+        for flag_group in flag_groups {
+            to_tokens! { generator
+                <#flag_group as ::argparse::FlagGroup>::long_names(__long_names);
+            }
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use proc_macro_util::{ast::Expression, tokens::Literal};
+
+mod new;
+mod to_tokens;
+
+/// Generates the code to display the long version, following the version with build details
+pub struct LongVersionOutput<'a> {
+    /// The labels and values of the build details
+    details: Vec<(Literal, Expression<'a>)>,
+}
@@ -0,0 +1,23 @@
+use super::LongVersionOutput;
+use proc_macro_util::{
+    ast::Expression,
+    tokens::{Identifier, Literal},
+};
+
+impl<'a> LongVersionOutput<'a> {
+    /// Creates a new [`LongVersionOutput`], labelling each detail with its name with underscores
+    /// replaced by spaces
+    pub fn new(details: Vec<(Identifier, Expression<'a>)>) -> Self {
+        LongVersionOutput {
+            details: details
+                .into_iter()
+                .map(|(name, value)| {
+                    (
+                        Literal::new(name.to_string().replace('_', " ").as_str()),
+                        value,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use super::LongVersionOutput;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for LongVersionOutput<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        to_tokens! { generator
+            fn write_long_version(__output: &mut dyn ::std::io::Write) -> ::std::io::Result<()>
+        };
+
+        let generator = &mut generator.group_brace();
+        to_tokens! { generator
+            <Self as ::argparse::Command>::write_version(__output)?;
+        }
+
+        for (label, value) in self.details {
+            to_tokens! { generator
+                ::std::writeln!(__output, "{}: {}", #label, #value)?;
+            }
+        }
+
+        to_tokens! { generator
+            Ok(())
+        }
+    }
+}
@@ -1,25 +1,43 @@
 use as_f64::AsF64;
 
 mod as_f64;
+mod builtin_flag;
+mod document;
+mod dynamic_completion;
 mod help;
+mod long_names;
+mod long_version;
 mod r#struct;
 mod version;
+mod version_command;
 
 mod new;
 mod to_tokens;
 
+pub use builtin_flag::BuiltinFlag;
+pub use document::{
+    ArgGroupDocumentOutput, BuiltinFlagDocument, DocumentOutput, DocumentSubCommandsOutput,
+    FlagDocumentOutput, FlagGroupDocumentOutput, PositionalDocumentOutput, SectionTitle,
+    VersionCommandDocument,
+};
+pub use dynamic_completion::DynamicCompletion;
 pub use help::{
-    FlagGroupHelpOutput, FlagGroupHelpUsageOutput, FlagHelpOutput, FlagHelpUsageOutput, HelpHeader,
-    HelpOutput, HelpOutputDescription, HelpOutputName, HelpUsageOutput, PositionalHelpOutput,
-    PositionalHelpUsageOutput,
+    ArgGroupHelpOutput, ArgGroupHelpUsageOutput, FlagGroupHelpOutput, FlagGroupHelpUsageOutput,
+    FlagHelpOutput, FlagHelpUsageOutput, HelpEpilogue, HelpExamples, HelpHeader,
+    HelpLongDescription, HelpOutput, HelpOutputDescription, HelpOutputName, HelpUsageOutput,
+    PositionalHelpOutput, PositionalHelpUsageOutput,
 };
+pub use long_names::LongNamesOutput;
+pub use long_version::LongVersionOutput;
 pub use r#struct::{
-    DefaultValue, Description, FlagGroupDeclaration, FlagGroupLongName, FlagGroupShortName,
-    FlagGroupUnwrap, FlagInfo, FlagLongName, FlagShortName, FlagUnwrap, OptionalOutput,
-    PositionalInfo, PositionalMatch, PositionalSubCommand, PositionalUnwrap, StructOutput,
-    VariableDeclaration,
+    ArgGroupCheck, ArgGroupInfo, DefaultValue, Description, FlagGroupDeclaration,
+    FlagGroupLongName, FlagGroupPassed, FlagGroupShortName, FlagGroupUnwrap, FlagInfo,
+    FlagLongName, FlagNames, FlagPassed, FlagShortName, FlagUnwrap, OptionalOutput, PositionalInfo,
+    PositionalMatch, PositionalSubCommand, PositionalUnwrap, ProgramName, ProgramNameFunction,
+    StructOutput, UnwrappedField, Validator, VariableDeclaration,
 };
 pub use version::VersionOutput;
+pub use version_command::VersionCommand;
 
 /// The output code from the Command derive macro
 pub enum Output<'a> {
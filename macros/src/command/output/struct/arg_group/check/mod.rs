@@ -0,0 +1,10 @@
+use proc_macro_util::tokens::Identifier;
+
+mod new;
+mod to_tokens;
+
+/// Checks the passed flags against an arg group
+pub struct ArgGroupCheck {
+    /// The name of the information describing the group
+    info_name: Identifier,
+}
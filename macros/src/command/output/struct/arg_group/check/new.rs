@@ -0,0 +1,9 @@
+use super::ArgGroupCheck;
+use proc_macro_util::tokens::Identifier;
+
+impl ArgGroupCheck {
+    /// Creates a new [`ArgGroupCheck`]
+    pub fn new(info_name: Identifier) -> Self {
+        ArgGroupCheck { info_name }
+    }
+}
@@ -0,0 +1,12 @@
+use super::ArgGroupCheck;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for ArgGroupCheck {
+    fn to_tokens(self, generator: &mut Generator) {
+        let ArgGroupCheck { info_name } = self;
+
+        to_tokens! { generator
+            __errors.take(Self::#info_name.check(&__passed_flags));
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::command::output::FlagNames;
+use proc_macro_util::tokens::Identifier;
+
+mod new;
+mod to_tokens;
+
+/// The description of an arg group
+pub struct ArgGroupInfo {
+    /// The name for the constant
+    info_name: Identifier,
+
+    /// The name of the `ArgGroupRule` variant
+    rule: Identifier,
+
+    /// The long names of the flags in the group
+    members: FlagNames,
+}
@@ -0,0 +1,14 @@
+use super::ArgGroupInfo;
+use crate::command::output::FlagNames;
+use proc_macro_util::tokens::Identifier;
+
+impl ArgGroupInfo {
+    /// Creates a new [`ArgGroupInfo`]
+    pub fn new(info_name: Identifier, rule: Identifier, members: FlagNames) -> Self {
+        ArgGroupInfo {
+            info_name,
+            rule,
+            members,
+        }
+    }
+}
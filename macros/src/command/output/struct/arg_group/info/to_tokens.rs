@@ -0,0 +1,19 @@
+use super::ArgGroupInfo;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for ArgGroupInfo {
+    fn to_tokens(self, generator: &mut Generator) {
+        let ArgGroupInfo {
+            info_name,
+            rule,
+            members,
+        } = self;
+
+        to_tokens! { generator
+            const #info_name: &::argparse::ArgGroup = &::argparse::ArgGroup {
+                rule: ::argparse::ArgGroupRule::#rule,
+                members: &[#members],
+            };
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod check;
+mod info;
+
+pub use check::ArgGroupCheck;
+pub use info::ArgGroupInfo;
@@ -6,7 +6,7 @@ impl<'a> ToTokens for Description<'a> {
         let Description { expressions } = self;
 
         to_tokens! { generator
-            |__count|
+            |__output: &mut dyn ::std::io::Write, __count: usize| -> ::std::io::Result<()>
         }
 
         let generator = &mut generator.group_brace();
@@ -16,16 +16,20 @@ impl<'a> ToTokens for Description<'a> {
                 first = false;
             } else {
                 to_tokens! { generator
-                    ::std::println!();
+                    ::std::writeln!(__output)?;
                     for _ in 0..__count {
-                        ::std::print!(" ");
+                        ::std::write!(__output, " ")?;
                     }
                 }
             }
 
             to_tokens! { generator
-                ::std::print!("{}", #expression);
+                ::std::write!(__output, "{}", #expression)?;
             }
         }
+
+        to_tokens! { generator
+            Ok(())
+        }
     }
 }
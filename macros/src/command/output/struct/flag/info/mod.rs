@@ -1,4 +1,6 @@
-use crate::command::output::{AsF64, DefaultValue, Description, OptionalOutput};
+use crate::command::output::{
+    AsF64, DefaultValue, Description, FlagNames, OptionalOutput, Validator,
+};
 use proc_macro_util::{
     ast::{Expression, Type},
     tokens::{Identifier, Literal},
@@ -33,6 +35,24 @@ pub struct FlagInfo<'a> {
     /// The default value
     default: OptionalOutput<DefaultValue<'a>>,
 
+    /// The function checking each value
+    validate: OptionalOutput<Validator<'a>>,
+
+    /// The function listing completion candidates for the value
+    complete: OptionalOutput<Expression<'a>>,
+
+    /// Is this left out of usage, help and completions?
+    hidden: bool,
+
     /// The description
     description: OptionalOutput<Description<'a>>,
+
+    /// The extended description, only included in long help
+    long_description: OptionalOutput<Description<'a>>,
+
+    /// The long names of the flags which can't be passed with this one
+    conflicts_with: FlagNames,
+
+    /// The long names of the flags which must be passed with this one
+    requires: FlagNames,
 }
@@ -1,5 +1,7 @@
 use super::FlagInfo;
-use crate::command::output::{as_f64::AsF64, DefaultValue, Description, OptionalOutput};
+use crate::command::output::{
+    as_f64::AsF64, DefaultValue, Description, FlagNames, OptionalOutput, Validator,
+};
 use proc_macro_util::{
     ast::{Expression, Type},
     tokens::{Identifier, Literal},
@@ -16,7 +18,13 @@ impl<'a> FlagInfo<'a> {
         min: Option<Expression<'a>>,
         max: Option<Expression<'a>>,
         default: OptionalOutput<DefaultValue<'a>>,
+        validate: OptionalOutput<Validator<'a>>,
+        complete: OptionalOutput<Expression<'a>>,
+        hidden: bool,
         description: OptionalOutput<Description<'a>>,
+        long_description: OptionalOutput<Description<'a>>,
+        conflicts_with: FlagNames,
+        requires: FlagNames,
     ) -> Self {
         FlagInfo {
             info_name,
@@ -27,7 +35,13 @@ impl<'a> FlagInfo<'a> {
             min: min.map(AsF64::new).into(),
             max: max.map(AsF64::new).into(),
             default,
+            validate,
+            complete,
+            hidden,
             description,
+            long_description,
+            conflicts_with,
+            requires,
         }
     }
 }
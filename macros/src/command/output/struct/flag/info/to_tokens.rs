@@ -12,7 +12,13 @@ impl<'a> ToTokens for FlagInfo<'a> {
             min,
             max,
             default,
+            validate,
+            complete,
+            hidden,
             description,
+            long_description,
+            conflicts_with,
+            requires,
         } = self;
 
         to_tokens! { generator
@@ -23,7 +29,13 @@ impl<'a> ToTokens for FlagInfo<'a> {
                 min: #min,
                 max: #max,
                 default: #default,
+                validate: #validate,
+                complete: #complete,
+                hidden: #hidden,
                 description: #description,
+                long_description: #long_description,
+                conflicts_with: &[#conflicts_with],
+                requires: &[#requires],
             };
         }
     }
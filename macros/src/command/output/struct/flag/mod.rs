@@ -1,9 +1,13 @@
 mod info;
 mod long_name;
+mod names;
+mod passed;
 mod short_name;
 mod unwrap;
 
 pub use info::FlagInfo;
 pub use long_name::FlagLongName;
+pub use names::FlagNames;
+pub use passed::FlagPassed;
 pub use short_name::FlagShortName;
 pub use unwrap::FlagUnwrap;
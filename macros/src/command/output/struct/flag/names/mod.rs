@@ -0,0 +1,10 @@
+use proc_macro_util::tokens::Literal;
+
+mod new;
+mod to_tokens;
+
+/// Generates the elements of the array listing the long names of related flags
+pub struct FlagNames {
+    /// The long names, with "--" prepended
+    names: Vec<Literal>,
+}
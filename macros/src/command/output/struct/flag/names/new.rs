@@ -0,0 +1,9 @@
+use super::FlagNames;
+use proc_macro_util::tokens::Literal;
+
+impl FlagNames {
+    /// Creates a new [`FlagNames`]
+    pub fn new(names: Vec<Literal>) -> Self {
+        FlagNames { names }
+    }
+}
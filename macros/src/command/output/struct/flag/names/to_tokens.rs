@@ -0,0 +1,14 @@
+use super::FlagNames;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for FlagNames {
+    fn to_tokens(self, generator: &mut Generator) {
+        let FlagNames { names } = self;
+
+        for name in names {
+            to_tokens! { generator
+                #name,
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use proc_macro_util::tokens::Identifier;
+use std::borrow::Cow;
+
+mod new;
+mod to_tokens;
+
+/// Records a flag variable as passed if it was set
+pub struct FlagPassed<'a> {
+    /// The name of the variable to check
+    variable_name: Cow<'a, Identifier>,
+
+    /// The name of the information describing the flag
+    info_name: Identifier,
+}
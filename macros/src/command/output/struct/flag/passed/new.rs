@@ -0,0 +1,13 @@
+use super::FlagPassed;
+use proc_macro_util::tokens::Identifier;
+use std::borrow::Cow;
+
+impl<'a> FlagPassed<'a> {
+    /// Creates a new [`FlagPassed`]
+    pub fn new(variable_name: Cow<'a, Identifier>, info_name: Identifier) -> Self {
+        FlagPassed {
+            variable_name,
+            info_name,
+        }
+    }
+}
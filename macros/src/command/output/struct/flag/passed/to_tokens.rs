@@ -0,0 +1,17 @@
+use super::FlagPassed;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for FlagPassed<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let FlagPassed {
+            variable_name,
+            info_name,
+        } = self;
+
+        to_tokens! { generator
+            if #variable_name.is_some() {
+                __passed_flags.push(Self::#info_name.passed());
+            }
+        }
+    }
+}
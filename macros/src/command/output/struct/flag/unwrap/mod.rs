@@ -1,7 +1,9 @@
 use proc_macro_util::tokens::Identifier;
 use std::borrow::Cow;
 
+mod field;
 mod new;
+mod passed;
 mod to_tokens;
 
 /// Unwraps a flag variable
@@ -0,0 +1,9 @@
+use super::FlagUnwrap;
+use crate::command::output::FlagPassed;
+
+impl<'a> FlagUnwrap<'a> {
+    /// Gets the check recording whether the flag was passed
+    pub fn passed(&self) -> FlagPassed<'a> {
+        FlagPassed::new(self.variable_name.clone(), self.info_name.clone())
+    }
+}
@@ -1,9 +1,11 @@
 mod declaration;
 mod long_name;
+mod passed;
 mod short_name;
 mod unwrap;
 
 pub use declaration::FlagGroupDeclaration;
 pub use long_name::FlagGroupLongName;
+pub use passed::FlagGroupPassed;
 pub use short_name::FlagGroupShortName;
 pub use unwrap::FlagGroupUnwrap;
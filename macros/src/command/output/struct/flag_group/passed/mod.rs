@@ -0,0 +1,14 @@
+use proc_macro_util::{ast::Type, tokens::Identifier};
+use std::borrow::Cow;
+
+mod new;
+mod to_tokens;
+
+/// Records the flags passed in a flag group variable
+pub struct FlagGroupPassed<'a> {
+    /// The name of the variable to check
+    variable_name: Cow<'a, Identifier>,
+
+    /// The type of the flag group
+    r#type: Type<'a>,
+}
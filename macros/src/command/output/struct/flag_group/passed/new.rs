@@ -0,0 +1,13 @@
+use super::FlagGroupPassed;
+use proc_macro_util::{ast::Type, tokens::Identifier};
+use std::borrow::Cow;
+
+impl<'a> FlagGroupPassed<'a> {
+    /// Creates a new [`FlagGroupPassed`]
+    pub fn new(variable_name: Cow<'a, Identifier>, r#type: Type<'a>) -> Self {
+        FlagGroupPassed {
+            variable_name,
+            r#type,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use super::FlagGroupPassed;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for FlagGroupPassed<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let FlagGroupPassed {
+            variable_name,
+            r#type,
+        } = self;
+
+        to_tokens! { generator
+            <#r#type as ::argparse::FlagGroup>::passed_flags(&#variable_name, &mut __passed_flags);
+        }
+    }
+}
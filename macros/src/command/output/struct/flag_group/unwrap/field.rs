@@ -0,0 +1,9 @@
+use super::FlagGroupUnwrap;
+use crate::command::output::UnwrappedField;
+
+impl<'a> FlagGroupUnwrap<'a> {
+    /// Gets the initialization of the field from the unwrapped variable
+    pub fn field(&self) -> UnwrappedField<'a> {
+        UnwrappedField::new(self.variable_name.clone())
+    }
+}
@@ -1,11 +1,16 @@
-use proc_macro_util::tokens::Identifier;
+use proc_macro_util::{ast::Type, tokens::Identifier};
 use std::borrow::Cow;
 
+mod field;
 mod new;
+mod passed;
 mod to_tokens;
 
 /// Unwraps a flag variable
 pub struct FlagGroupUnwrap<'a> {
     /// The name of the variable to unwrap
     variable_name: Cow<'a, Identifier>,
+
+    /// The type of the flag group
+    r#type: Type<'a>,
 }
@@ -1,10 +1,13 @@
 use super::FlagGroupUnwrap;
-use proc_macro_util::tokens::Identifier;
+use proc_macro_util::{ast::Type, tokens::Identifier};
 use std::borrow::Cow;
 
 impl<'a> FlagGroupUnwrap<'a> {
     /// Creates a new [`FlagGroupUnwrap`]
-    pub fn new(variable_name: Cow<'a, Identifier>) -> Self {
-        FlagGroupUnwrap { variable_name }
+    pub fn new(variable_name: Cow<'a, Identifier>, r#type: Type<'a>) -> Self {
+        FlagGroupUnwrap {
+            variable_name,
+            r#type,
+        }
     }
 }
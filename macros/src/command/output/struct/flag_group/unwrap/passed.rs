@@ -0,0 +1,9 @@
+use super::FlagGroupUnwrap;
+use crate::command::output::FlagGroupPassed;
+
+impl<'a> FlagGroupUnwrap<'a> {
+    /// Gets the collection of the flags passed in the flag group
+    pub fn passed(&self) -> FlagGroupPassed<'a> {
+        FlagGroupPassed::new(self.variable_name.clone(), self.r#type.clone())
+    }
+}
@@ -1,29 +1,42 @@
-use super::{HelpOutput, VersionOutput};
+use super::{
+    BuiltinFlag, DocumentOutput, DynamicCompletion, HelpOutput, LongVersionOutput, VersionCommand,
+    VersionOutput,
+};
 use proc_macro_util::{
     ast::{GenericArgs, GenericParams},
     tokens::Identifier,
 };
 use std::borrow::Cow;
 
+mod arg_group;
 mod default_value;
 mod description;
 mod flag;
 mod flag_group;
 mod optional_output;
 mod positional;
+mod program_name;
+mod program_name_function;
+mod unwrapped_field;
+mod validator;
 mod variable_declaration;
 
 mod new;
 mod to_tokens;
 
+pub use arg_group::{ArgGroupCheck, ArgGroupInfo};
 pub use default_value::DefaultValue;
 pub use description::Description;
-pub use flag::{FlagInfo, FlagLongName, FlagShortName, FlagUnwrap};
+pub use flag::{FlagInfo, FlagLongName, FlagNames, FlagPassed, FlagShortName, FlagUnwrap};
 pub use flag_group::{
-    FlagGroupDeclaration, FlagGroupLongName, FlagGroupShortName, FlagGroupUnwrap,
+    FlagGroupDeclaration, FlagGroupLongName, FlagGroupPassed, FlagGroupShortName, FlagGroupUnwrap,
 };
 pub use optional_output::OptionalOutput;
 pub use positional::{PositionalInfo, PositionalMatch, PositionalSubCommand, PositionalUnwrap};
+pub use program_name::ProgramName;
+pub use program_name_function::ProgramNameFunction;
+pub use unwrapped_field::UnwrappedField;
+pub use validator::Validator;
 pub use variable_declaration::VariableDeclaration;
 
 /// The output code for a struct
@@ -40,6 +53,12 @@ pub struct StructOutput<'a> {
     /// The name of the module to produce the implementation in
     module_name: Identifier,
 
+    /// Overrides the program name used in usage
+    program_name: Option<ProgramName>,
+
+    /// The function giving the user provided program name, if there is one
+    program_name_function: Option<ProgramNameFunction>,
+
     /// The info describing the positionals
     positional_info: Vec<PositionalInfo<'a>>,
 
@@ -82,9 +101,57 @@ pub struct StructOutput<'a> {
     /// Unwraps the flag group variables
     flag_group_unwraps: Vec<FlagGroupUnwrap<'a>>,
 
-    /// The version flag to output
+    /// The info describing the arg groups
+    arg_group_info: Vec<ArgGroupInfo>,
+
+    /// Checks the passed flags against the arg groups
+    arg_group_checks: Vec<ArgGroupCheck>,
+
+    /// Records the flags which were passed, for checking their relationships
+    flag_passed: Vec<FlagPassed<'a>>,
+
+    /// Records the flags which were passed in the flag groups
+    flag_group_passed: Vec<FlagGroupPassed<'a>>,
+
+    /// Initializes the fields from the unwrapped variables
+    unwrapped_fields: Vec<UnwrappedField<'a>>,
+
+    /// The long names of the flags, for suggesting a flag when an unknown one is passed
+    long_names: LongNamesOutput<'a>,
+
+    /// The match arm for the version flag
+    version_flag: Option<BuiltinFlag>,
+
+    /// The match arm for the help flag
+    help_flag: Option<BuiltinFlag>,
+
+    /// The check for the short help flag
+    short_help_flag: Option<BuiltinFlag>,
+
+    /// The check for the short version flag
+    short_version_flag: Option<BuiltinFlag>,
+
+    /// The variable tracking if the "version" sub-command can still be given
+    version_command_started: Option<VersionCommand>,
+
+    /// The check for the "version" sub-command
+    version_command: Option<VersionCommand>,
+
+    /// The match arm for the color flag
+    color_flag: Option<BuiltinFlag>,
+
+    /// The version to output
     version: Option<VersionOutput<'a>>,
 
-    /// The help flag to output
+    /// The long version to output, if there are build details
+    long_version: Option<LongVersionOutput<'a>>,
+
+    /// The help to output
     help: Option<HelpOutput<'a>>,
+
+    /// The descriptions, usage and documentation sections
+    document: DocumentOutput<'a>,
+
+    /// Enables answers to dynamic completion scripts
+    dynamic_completion: Option<DynamicCompletion>,
 }
@@ -1,9 +1,13 @@
 use super::{
-    FlagGroupDeclaration, FlagGroupLongName, FlagGroupShortName, FlagGroupUnwrap, FlagInfo,
-    FlagLongName, FlagShortName, FlagUnwrap, PositionalInfo, PositionalMatch, PositionalSubCommand,
-    PositionalUnwrap, StructOutput, VariableDeclaration,
+    ArgGroupCheck, ArgGroupInfo, FlagGroupDeclaration, FlagGroupLongName, FlagGroupShortName,
+    FlagGroupUnwrap, FlagInfo, FlagLongName, FlagShortName, FlagUnwrap, PositionalInfo,
+    PositionalMatch, PositionalSubCommand, PositionalUnwrap, ProgramName, StructOutput,
+    UnwrappedField, VariableDeclaration,
+};
+use crate::command::output::{
+    BuiltinFlag, DocumentOutput, DynamicCompletion, HelpOutput, LongNamesOutput, LongVersionOutput,
+    VersionCommand, VersionOutput,
 };
-use crate::command::output::{HelpOutput, VersionOutput};
 use proc_macro_util::{
     ast::{GenericArgs, GenericParams},
     tokens::Identifier,
@@ -16,6 +20,7 @@ impl<'a> StructOutput<'a> {
         name: Cow<'a, Identifier>,
         generic_params: Option<GenericParams<'a>>,
         generic_args: Option<GenericArgs<'a>>,
+        program_name: Option<ProgramName>,
         positional_info: Vec<PositionalInfo<'a>>,
         positional_declarations: Vec<VariableDeclaration<'a>>,
         positional_matches: Vec<PositionalMatch<'a>>,
@@ -30,16 +35,40 @@ impl<'a> StructOutput<'a> {
         flag_group_long_names: Vec<FlagGroupLongName<'a>>,
         flag_group_short_names: Vec<FlagGroupShortName<'a>>,
         flag_group_unwraps: Vec<FlagGroupUnwrap<'a>>,
+        arg_group_info: Vec<ArgGroupInfo>,
+        arg_group_checks: Vec<ArgGroupCheck>,
+        long_names: LongNamesOutput<'a>,
         version: Option<VersionOutput<'a>>,
+        long_version: Option<LongVersionOutput<'a>>,
+        version_command: bool,
         help: Option<HelpOutput<'a>>,
+        color: bool,
+        document: DocumentOutput<'a>,
+        dynamic_completion: bool,
     ) -> Self {
         let module_name = Identifier::new(&format!("__command_{}", name));
+        let program_name_function = program_name.as_ref().map(ProgramName::function);
+
+        let flag_passed = flag_unwraps.iter().map(FlagUnwrap::passed).collect();
+        let flag_group_passed = flag_group_unwraps
+            .iter()
+            .map(FlagGroupUnwrap::passed)
+            .collect();
+
+        let unwrapped_fields = positional_unwraps
+            .iter()
+            .map(PositionalUnwrap::field)
+            .chain(flag_unwraps.iter().map(FlagUnwrap::field))
+            .chain(flag_group_unwraps.iter().map(FlagGroupUnwrap::field))
+            .collect();
 
         StructOutput {
             name,
             generic_params,
             generic_args,
             module_name,
+            program_name,
+            program_name_function,
             positional_info,
             positional_declarations,
             positional_matches,
@@ -54,8 +83,24 @@ impl<'a> StructOutput<'a> {
             flag_group_long_names,
             flag_group_short_names,
             flag_group_unwraps,
+            arg_group_info,
+            arg_group_checks,
+            flag_passed,
+            flag_group_passed,
+            unwrapped_fields,
+            long_names,
+            version_flag: version.as_ref().map(|_| BuiltinFlag::Version),
+            help_flag: help.as_ref().map(|_| BuiltinFlag::Help),
+            short_help_flag: help.as_ref().map(|_| BuiltinFlag::ShortHelp),
+            short_version_flag: version.as_ref().map(|_| BuiltinFlag::ShortVersion),
+            version_command_started: version_command.then_some(VersionCommand::Started),
+            version_command: version_command.then_some(VersionCommand::Check),
+            color_flag: color.then_some(BuiltinFlag::Color),
             version,
+            long_version,
             help,
+            document,
+            dynamic_completion: dynamic_completion.then_some(DynamicCompletion),
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::command::output::{
     as_f64::AsF64,
-    r#struct::{DefaultValue, Description, OptionalOutput},
+    r#struct::{DefaultValue, Description, OptionalOutput, Validator},
 };
 use proc_macro_util::{
     ast::{Expression, Type},
@@ -30,6 +30,18 @@ pub struct PositionalInfo<'a> {
     /// The default value
     default: OptionalOutput<DefaultValue<'a>>,
 
+    /// The function checking each value
+    validate: OptionalOutput<Validator<'a>>,
+
+    /// The function listing completion candidates for the value
+    complete: OptionalOutput<Expression<'a>>,
+
+    /// Is this left out of usage, help and completions?
+    hidden: bool,
+
     /// The description of this positional
     description: OptionalOutput<Description<'a>>,
+
+    /// The extended description, only included in long help
+    long_description: OptionalOutput<Description<'a>>,
 }
@@ -1,7 +1,7 @@
 use super::PositionalInfo;
 use crate::command::output::{
     as_f64::AsF64,
-    r#struct::{DefaultValue, Description, OptionalOutput},
+    r#struct::{DefaultValue, Description, OptionalOutput, Validator},
 };
 use proc_macro_util::{
     ast::{Expression, Type},
@@ -17,7 +17,11 @@ impl<'a> PositionalInfo<'a> {
         min: Option<Expression<'a>>,
         max: Option<Expression<'a>>,
         default: OptionalOutput<DefaultValue<'a>>,
+        validate: OptionalOutput<Validator<'a>>,
+        complete: OptionalOutput<Expression<'a>>,
+        hidden: bool,
         description: OptionalOutput<Description<'a>>,
+        long_description: OptionalOutput<Description<'a>>,
     ) -> Self {
         PositionalInfo {
             info_name,
@@ -26,7 +30,11 @@ impl<'a> PositionalInfo<'a> {
             min: min.map(AsF64::new).into(),
             max: max.map(AsF64::new).into(),
             default,
+            validate,
+            complete,
+            hidden,
             description,
+            long_description,
         }
     }
 }
@@ -10,7 +10,11 @@ impl<'a> ToTokens for PositionalInfo<'a> {
             min,
             max,
             default,
+            validate,
+            complete,
+            hidden,
             description,
+            long_description,
         } = self;
 
         to_tokens! { generator
@@ -19,7 +23,11 @@ impl<'a> ToTokens for PositionalInfo<'a> {
                 min: #min,
                 max: #max,
                 default: #default,
+                validate: #validate,
+                complete: #complete,
+                hidden: #hidden,
                 description: #description,
+                long_description: #long_description,
             };
         }
     }
@@ -13,10 +13,11 @@ impl<'a> ToTokens for PositionalSubCommand<'a> {
                 &mut #variable_name,
                 __command,
                 __source,
-                ::std::format!("{}{} ", __command_list, __argument)
+                __command_list.sub_command(__argument.to_string())
             )? {
-                true => break,
-                false => return Ok(None),
+                ::argparse::ParseOutcome::Parsed(()) => break,
+                ::argparse::ParseOutcome::Help(__help) => return Ok(::argparse::ParseOutcome::Help(__help)),
+                ::argparse::ParseOutcome::Version(__version) => return Ok(::argparse::ParseOutcome::Version(__version)),
             },
         }
     }
@@ -1,6 +1,7 @@
 use proc_macro_util::tokens::Identifier;
 use std::borrow::Cow;
 
+mod field;
 mod new;
 mod to_tokens;
 
@@ -0,0 +1,9 @@
+use super::ProgramName;
+use crate::command::output::ProgramNameFunction;
+
+impl ProgramName {
+    /// Gets the function giving the program name to `Command::parse_or_exit`
+    pub fn function(&self) -> ProgramNameFunction {
+        ProgramNameFunction::new(self.name.clone())
+    }
+}
@@ -0,0 +1,11 @@
+use proc_macro_util::tokens::Literal;
+
+mod function;
+mod new;
+mod to_tokens;
+
+/// Overrides the program name in the command list with a user provided name
+pub struct ProgramName {
+    /// The name of the program
+    name: Literal,
+}
@@ -0,0 +1,9 @@
+use super::ProgramName;
+use proc_macro_util::tokens::Literal;
+
+impl ProgramName {
+    /// Creates a new [`ProgramName`]
+    pub fn new(name: Literal) -> Self {
+        ProgramName { name }
+    }
+}
@@ -0,0 +1,12 @@
+use super::ProgramName;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for ProgramName {
+    fn to_tokens(self, generator: &mut Generator) {
+        let ProgramName { name } = self;
+
+        to_tokens! { generator
+            __command_list.set_program_name(#name);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use proc_macro_util::tokens::Literal;
+
+mod new;
+mod to_tokens;
+
+/// Generates the function giving the program name set by the command
+pub struct ProgramNameFunction {
+    /// The name of the program
+    name: Literal,
+}
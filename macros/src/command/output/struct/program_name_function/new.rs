@@ -0,0 +1,9 @@
+use super::ProgramNameFunction;
+use proc_macro_util::tokens::Literal;
+
+impl ProgramNameFunction {
+    /// Creates a new [`ProgramNameFunction`]
+    pub fn new(name: Literal) -> Self {
+        ProgramNameFunction { name }
+    }
+}
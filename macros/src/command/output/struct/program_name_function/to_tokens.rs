@@ -0,0 +1,14 @@
+use super::ProgramNameFunction;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for ProgramNameFunction {
+    fn to_tokens(self, generator: &mut Generator) {
+        let ProgramNameFunction { name } = self;
+
+        to_tokens! { generator
+            fn program_name() -> ::std::option::Option<&'static str> {
+                ::std::option::Option::Some(#name)
+            }
+        }
+    }
+}
@@ -8,6 +8,8 @@ impl<'a> ToTokens for StructOutput<'a> {
             generic_params,
             generic_args,
             module_name,
+            program_name,
+            program_name_function,
             positional_info,
             positional_declarations,
             positional_matches,
@@ -22,8 +24,24 @@ impl<'a> ToTokens for StructOutput<'a> {
             flag_group_long_names,
             flag_group_short_names,
             flag_group_unwraps,
+            arg_group_info,
+            arg_group_checks,
+            flag_passed,
+            flag_group_passed,
+            unwrapped_fields,
+            long_names,
+            version_flag,
+            help_flag,
+            short_help_flag,
+            short_version_flag,
+            version_command_started,
+            version_command,
+            color_flag,
             version,
+            long_version,
             help,
+            document,
+            dynamic_completion,
         } = self;
 
         let name2 = name.clone();
@@ -42,10 +60,16 @@ impl<'a> ToTokens for StructOutput<'a> {
 
                     // Flag info
                     #flag_info
-                }
 
-                impl #generic_params2 ::argparse::Command for #name2 #generic_args2 {
-                    fn parse(__source: &mut dyn ::argparse::ArgumentSource, __command_list: ::std::string::String) -> ::argparse::Result<Option<Self>> {
+                    // Arg group info
+                    #arg_group_info
+
+                    // Parses the arguments, setting `__unwrapping` once they have all been read
+                    fn __parse(
+                        __source: &mut dyn ::argparse::ArgumentSource,
+                        __command_list: &mut ::argparse::CommandList,
+                        __unwrapping: &mut bool,
+                    ) -> ::argparse::Result<::argparse::ParseOutcome<Self>> {
                         // Positional variables
                         #positional_declarations
 
@@ -57,6 +81,7 @@ impl<'a> ToTokens for StructOutput<'a> {
 
                         // Accounting variables
                         let mut __current_positional = 0;
+                        #version_command_started
 
                         // Main loop
                         while let Some(__argument) = __source.next() {
@@ -65,12 +90,15 @@ impl<'a> ToTokens for StructOutput<'a> {
                                 if __argument.len() > 2 && __argument.starts_with("--") {
                                     match &__argument[2..] {
                                         #flag_long_names
-                                        #version
-                                        #help
+                                        #version_flag
+                                        #help_flag
+                                        #color_flag
                                         __flag_name => {
                                             #flag_group_long_names
 
-                                            return Err(::argparse::Error::unknown_argument(__argument.to_string()));
+                                            let __long_names: &mut ::std::vec::Vec<&'static str> = &mut ::std::vec::Vec::new();
+                                            #long_names
+                                            return Err(::argparse::Error::unknown_argument_among(__argument.to_string(), __long_names));
                                         }
                                     }
 
@@ -84,6 +112,8 @@ impl<'a> ToTokens for StructOutput<'a> {
                                             #flag_short_names
                                             __flag_name => {
                                                 #flag_group_short_names
+                                                #short_help_flag
+                                                #short_version_flag
 
                                                 return Err(::argparse::Error::unknown_argument(::std::format!("-{}", __c)));
                                             }
@@ -95,6 +125,7 @@ impl<'a> ToTokens for StructOutput<'a> {
                             }
 
                             // Check positionals
+                            #version_command
                             let __result = match __current_positional {
                                 #positional_matches
                                 _ => return Err(::argparse::Error::unknown_argument(__argument.to_string())),
@@ -115,14 +146,56 @@ impl<'a> ToTokens for StructOutput<'a> {
                             }
                         }
 
-                        // Unwrap values and return result
-                        Ok(Some(#name3 {
-                            #positional_unwraps
-                            #flag_unwraps
-                            #flag_group_unwraps
+                        // Unwrap values, reporting every error, and return result
+                        *__unwrapping = true;
+                        #[allow(unused_mut)]
+                        let mut __errors = ::argparse::UnwrapErrors::new();
+
+                        // Check conflicting and required flags
+                        #[allow(unused_mut)]
+                        let mut __passed_flags: ::std::vec::Vec<::argparse::PassedFlag> = ::std::vec::Vec::new();
+                        #flag_passed
+                        #flag_group_passed
+                        __errors.take(::argparse::PassedFlag::check(&__passed_flags));
+                        #arg_group_checks
+
+                        #positional_unwraps
+                        #flag_unwraps
+                        #flag_group_unwraps
+                        __errors.finish()?;
+
+                        Ok(::argparse::ParseOutcome::Parsed(#name3 {
+                            #unwrapped_fields
                         }))
                     }
                 }
+
+                impl #generic_params2 ::argparse::Command for #name2 #generic_args2 {
+                    fn parse(__source: &mut dyn ::argparse::ArgumentSource, mut __command_list: ::argparse::CommandList) -> ::argparse::Result<::argparse::ParseOutcome<Self>> {
+                        // Program name
+                        #program_name
+
+                        // Errors are given the command and the last argument read when they
+                        // occurred, like the value of a flag
+                        let mut __unwrapping = false;
+                        Self::__parse(__source, &mut __command_list, &mut __unwrapping).map_err(|__error| {
+                            let __argument_index = if __unwrapping { None } else { __source.index() };
+                            __error.in_command(&__command_list, __argument_index)
+                        })
+                    }
+
+                    #version
+
+                    #long_version
+
+                    #help
+
+                    #document
+
+                    #program_name_function
+
+                    #dynamic_completion
+                }
             }
         }
     }
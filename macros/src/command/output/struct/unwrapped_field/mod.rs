@@ -0,0 +1,12 @@
+use proc_macro_util::tokens::Identifier;
+use std::borrow::Cow;
+
+mod new;
+mod to_tokens;
+
+/// Initializes a field from its unwrapped variable, once every variable has unwrapped without
+/// errors
+pub struct UnwrappedField<'a> {
+    /// The name of the field and the variable holding its value
+    variable_name: Cow<'a, Identifier>,
+}
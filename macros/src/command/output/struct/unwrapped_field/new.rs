@@ -0,0 +1,10 @@
+use super::UnwrappedField;
+use proc_macro_util::tokens::Identifier;
+use std::borrow::Cow;
+
+impl<'a> UnwrappedField<'a> {
+    /// Creates a new [`UnwrappedField`]
+    pub fn new(variable_name: Cow<'a, Identifier>) -> Self {
+        UnwrappedField { variable_name }
+    }
+}
@@ -0,0 +1,13 @@
+use super::UnwrappedField;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for UnwrappedField<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let UnwrappedField { variable_name } = self;
+        let variable_name2 = variable_name.clone();
+
+        to_tokens! { generator
+            #variable_name: #variable_name2.unwrap(),
+        }
+    }
+}
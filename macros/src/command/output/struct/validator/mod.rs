@@ -0,0 +1,10 @@
+use proc_macro_util::ast::Expression;
+
+mod new;
+mod to_tokens;
+
+/// The function validating each value of a flag or positional
+pub struct Validator<'a> {
+    /// The expression naming the function
+    expression: Expression<'a>,
+}
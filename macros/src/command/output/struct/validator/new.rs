@@ -0,0 +1,9 @@
+use super::Validator;
+use proc_macro_util::ast::Expression;
+
+impl<'a> Validator<'a> {
+    /// Creates a new [`Validator`] for `expression`
+    pub fn new(expression: Expression<'a>) -> Self {
+        Validator { expression }
+    }
+}
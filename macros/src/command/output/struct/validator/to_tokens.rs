@@ -0,0 +1,12 @@
+use super::Validator;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for Validator<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let Validator { expression } = self;
+
+        to_tokens! { generator
+            |__value| (#expression)(__value).map_err(::std::convert::Into::into)
+        }
+    }
+}
@@ -4,32 +4,24 @@ use proc_macro_util::{to_tokens, Generator, ToTokens};
 impl<'a> ToTokens for VersionOutput<'a> {
     fn to_tokens(self, generator: &mut Generator) {
         to_tokens! { generator
-            "version" =>
+            fn write_version(__output: &mut dyn ::std::io::Write) -> ::std::io::Result<()>
         };
 
+        let generator = &mut generator.group_brace();
         match self {
             VersionOutput::Default => {
                 to_tokens! { generator
-                    {
-                        ::std::println!("{} v{}", ::std::env!("CARGO_PKG_NAME"), ::std::env!("CARGO_PKG_VERSION"));
-                        return Ok(None);
-                    }
+                    ::std::writeln!(__output, "{} v{}", ::std::env!("CARGO_PKG_NAME"), ::std::env!("CARGO_PKG_VERSION"))
                 }
             }
             VersionOutput::AlternateName(name) => {
                 to_tokens! { generator
-                    {
-                        ::std::println!("{} v{}", #name, ::std::env!("CARGO_PKG_VERSION"));
-                        return Ok(None);
-                    }
+                    ::std::writeln!(__output, "{} v{}", #name, ::std::env!("CARGO_PKG_VERSION"))
                 }
             }
             VersionOutput::UserDefined(expression) => {
                 to_tokens! { generator
-                    {
-                        ::std::println!("{}", #expression);
-                        return Ok(None);
-                    }
+                    ::std::writeln!(__output, "{}", #expression)
                 }
             }
         }
@@ -0,0 +1,10 @@
+mod to_tokens;
+
+/// Generates the parts of the check for the "version" sub-command, which displays the long version
+pub enum VersionCommand {
+    /// The variable tracking if a positional value has been read
+    Started,
+
+    /// The check for "version" as the first positional value
+    Check,
+}
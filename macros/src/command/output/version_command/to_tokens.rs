@@ -0,0 +1,22 @@
+use super::VersionCommand;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for VersionCommand {
+    fn to_tokens(self, generator: &mut Generator) {
+        match self {
+            VersionCommand::Started => {
+                to_tokens! { generator
+                    let mut __positional_started = false;
+                }
+            }
+            VersionCommand::Check => {
+                to_tokens! { generator
+                    if !__positional_started && __argument.as_str().ok() == Some("version") {
+                        return Ok(::argparse::ParseOutcome::Version(<Self as ::argparse::Command>::long_version()));
+                    }
+                    __positional_started = true;
+                }
+            }
+        }
+    }
+}
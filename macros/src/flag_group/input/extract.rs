@@ -1,4 +1,5 @@
 use super::{Input, StructInput};
+use crate::command::ArgGroup;
 use proc_macro_util::{
     ast::{DeriveItem, DeriveItemKind},
     Result, Span,
@@ -7,8 +8,21 @@ use proc_macro_util::{
 impl<'a> Input<'a> {
     /// Extract the required details from `item`
     pub fn extract(item: DeriveItem<'a>) -> Result<Self> {
+        let mut arg_groups = Vec::new();
+        for attribute in item.attributes {
+            if attribute.attr.path.remaining.len() > 0 || attribute.attr.path.leading.is_some() {
+                continue;
+            }
+
+            if attribute.attr.path.first.to_string() == "arg_group" {
+                arg_groups.push(ArgGroup::extract(attribute)?);
+            }
+        }
+
         match item.kind {
-            DeriveItemKind::Struct(r#struct) => Ok(Input::Struct(StructInput::extract(r#struct)?)),
+            DeriveItemKind::Struct(r#struct) => {
+                Ok(Input::Struct(StructInput::extract(r#struct, arg_groups)?))
+            }
             _ => Err(Span::call_site().error("`FlagGroup` derive only supports structs")),
         }
     }
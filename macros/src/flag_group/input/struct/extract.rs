@@ -1,4 +1,5 @@
 use super::{Flag, FlagGroup, StructInput};
+use crate::command::{resolve_flag_names, ArgGroup};
 use proc_macro_util::{
     ast::items::{Struct, StructBody},
     Result,
@@ -6,7 +7,7 @@ use proc_macro_util::{
 
 impl<'a> StructInput<'a> {
     /// Extract the required details from `r#struct`
-    pub fn extract(r#struct: Struct<'a>) -> Result<Self> {
+    pub fn extract(r#struct: Struct<'a>, arg_groups: Vec<ArgGroup>) -> Result<Self> {
         let fields = match r#struct.body {
             StructBody::Normal {
                 where_clause: _,
@@ -36,11 +37,29 @@ impl<'a> StructInput<'a> {
             }
         }
 
+        let long_names: Vec<_> = flags.iter().map(|flag| flag.long_names()).collect();
+        for flag in &mut flags {
+            resolve_flag_names(
+                flag.relationship_names(),
+                &long_names,
+                !flag_groups.is_empty(),
+            )?;
+        }
+
+        for arg_group in &arg_groups {
+            arg_group.check_members(|member| {
+                flags
+                    .iter()
+                    .any(|flag| flag.variable_name().to_string() == member.to_string())
+            })?;
+        }
+
         Ok(StructInput {
             name: r#struct.name,
             generic_params: r#struct.generic_params,
             flags,
             flag_groups,
+            arg_groups,
         })
     }
 }
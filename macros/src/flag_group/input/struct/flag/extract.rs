@@ -1,4 +1,5 @@
 use super::Flag;
+use crate::command::parse_expression_list;
 use proc_macro_util::{
     ast::{items::StructField, AttrInput, Expression},
     tokens::{Group, Identifier, Literal},
@@ -43,7 +44,13 @@ impl<'a> Flag<'a> {
         let mut min = None;
         let mut max = None;
         let mut default = None;
+        let mut validate = None;
+        let mut complete = None;
+        let mut hidden = false;
         let mut description = None;
+        let mut long_description = None;
+        let mut conflicts_with = Vec::new();
+        let mut requires = Vec::new();
         if let Some(flag_attribute) = flag_attribute {
             let flag_attribute = field.attributes.swap_remove(flag_attribute);
             let flag_group = match flag_attribute.attr.input {
@@ -88,24 +95,57 @@ impl<'a> Flag<'a> {
                         parser.parse::<Token![=]>()?;
                         default = Some(parser.parse::<Expression>()?.into_static());
                     }
+                    "validate" => {
+                        parser.parse::<Token![=]>()?;
+                        validate = Some(parser.parse::<Expression>()?.into_static());
+                    }
+                    "hidden" => hidden = true,
+                    "complete" => {
+                        parser.parse::<Token![=]>()?;
+                        complete = Some(parser.parse::<Expression>()?.into_static());
+                    }
                     "description" => {
                         parser.parse::<Token![=]>()?;
 
                         description = Some(if let Ok(group) = parser.step_parse::<&Group>() {
+                            parse_expression_list(group)?
+                        } else {
+                            vec![parser.parse::<Expression>()?.into_static()]
+                        })
+                    }
+                    "long_description" => {
+                        parser.parse::<Token![=]>()?;
+
+                        long_description = Some(if let Ok(group) = parser.step_parse::<&Group>() {
+                            parse_expression_list(group)?
+                        } else {
+                            vec![parser.parse::<Expression>()?.into_static()]
+                        })
+                    }
+                    "conflicts_with" | "requires" => {
+                        parser.parse::<Token![=]>()?;
+
+                        let names = if let Ok(group) = parser.step_parse::<&Group>() {
                             let mut parser = group.parser();
-                            let mut description = vec![parser.parse::<Expression>()?.into_static()];
+                            let mut names = vec![parser.parse::<Literal>()?];
                             while !parser.empty() {
                                 parser.parse::<Token![,]>()?;
                                 if parser.empty() {
                                     break;
                                 }
 
-                                description.push(parser.parse::<Expression>()?.into_static());
+                                names.push(parser.parse::<Literal>()?);
                             }
-                            description
+                            names
                         } else {
-                            vec![parser.parse::<Expression>()?.into_static()]
-                        })
+                            vec![parser.parse::<Literal>()?]
+                        };
+
+                        if tag_str == "requires" {
+                            requires.extend(names);
+                        } else {
+                            conflicts_with.extend(names);
+                        }
                     }
                     _ => return Err(tag.span().error(format!("unknown flag tag \"{tag}\""))),
                 }
@@ -147,7 +187,13 @@ impl<'a> Flag<'a> {
             min,
             max,
             default,
+            validate,
+            complete,
+            hidden,
             description,
+            long_description,
+            conflicts_with,
+            requires,
         })
     }
 }
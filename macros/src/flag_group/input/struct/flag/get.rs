@@ -1,9 +1,42 @@
 use super::Flag;
-use proc_macro_util::tokens::Literal;
+use proc_macro_util::tokens::{Identifier, Literal};
 
 impl<'a> Flag<'a> {
+    /// Gets the name of the variable in the struct
+    pub fn variable_name(&self) -> &Identifier {
+        &self.variable_name
+    }
+
+    /// Gets the name of the constant describing its info and its long name with the leading
+    /// dashes
+    pub fn info_names(&self) -> (Identifier, Literal) {
+        (self.info_name.clone(), self.info_long_name.clone())
+    }
+
     /// Gets the short name of this flag
     pub fn short_name(&self) -> Option<&Literal> {
         self.short_name.as_ref()
     }
+
+    /// Gets the long name of this flag with its leading dashes, if it isn't hidden
+    pub fn visible_long_name(&self) -> Option<&Literal> {
+        if self.hidden {
+            None
+        } else {
+            Some(&self.info_long_name)
+        }
+    }
+
+    /// Gets the long name of this flag as written and with the leading dashes, for
+    /// [`resolve_flag_names`](crate::command::resolve_flag_names)
+    pub fn long_names(&self) -> (String, Literal) {
+        (self.long_name.to_string(), self.info_long_name.clone())
+    }
+
+    /// Gets the names of the flags this flag conflicts with and requires, to be resolved
+    pub fn relationship_names(&mut self) -> impl Iterator<Item = &mut Literal> {
+        self.conflicts_with
+            .iter_mut()
+            .chain(self.requires.iter_mut())
+    }
 }
@@ -1,6 +1,9 @@
 use super::Flag;
 use crate::{
-    command::{DefaultValue, Description, FlagHelpOutput, FlagHelpUsageOutput, FlagInfo},
+    command::{
+        DefaultValue, Description, FlagDocumentOutput, FlagHelpOutput, FlagHelpUsageOutput,
+        FlagInfo, FlagNames, Validator,
+    },
     flag_group::output::{FlagLongName, FlagShortName, FlagUnwrap},
 };
 use proc_macro_util::ast::Type;
@@ -20,6 +23,7 @@ impl<'a> Flag<'a> {
         FlagUnwrap<'a>,
         FlagHelpUsageOutput,
         FlagHelpOutput,
+        FlagDocumentOutput,
     ) {
         (
             FlagInfo::new(
@@ -31,7 +35,13 @@ impl<'a> Flag<'a> {
                 self.min.into(),
                 self.max.into(),
                 self.default.map(DefaultValue::new).into(),
+                self.validate.map(Validator::new).into(),
+                self.complete.into(),
+                self.hidden,
                 self.description.map(Description::new).into(),
+                self.long_description.map(Description::new).into(),
+                FlagNames::new(self.conflicts_with),
+                FlagNames::new(self.requires),
             ),
             self.r#type,
             FlagLongName::new(self.long_name, index, self.info_name.clone()),
@@ -39,7 +49,8 @@ impl<'a> Flag<'a> {
                 .map(|short_name| FlagShortName::new(short_name, index, self.info_name.clone())),
             FlagUnwrap::new(self.variable_name, index, self.info_name.clone()),
             FlagHelpUsageOutput::new(self.info_name.clone()),
-            FlagHelpOutput::new(self.info_name, description_offset, short_names),
+            FlagHelpOutput::new(self.info_name.clone(), description_offset, short_names),
+            FlagDocumentOutput::new(self.info_name),
         )
     }
 }
@@ -44,6 +44,26 @@ pub struct Flag<'a> {
     /// The default value
     default: Option<Expression<'a>>,
 
+    /// The function checking each value
+    validate: Option<Expression<'a>>,
+
+    /// The function listing completion candidates for the value
+    complete: Option<Expression<'a>>,
+
+    /// Is this left out of usage, help and completions?
+    hidden: bool,
+
     /// The description of this flag
     description: Option<Vec<Expression<'a>>>,
+
+    /// The extended description, only included in long help
+    long_description: Option<Vec<Expression<'a>>>,
+
+    /// The long names of flags which can't be passed with this one, with "--" prepended once
+    /// resolved
+    conflicts_with: Vec<Literal>,
+
+    /// The long names of flags which must be passed with this one, with "--" prepended once
+    /// resolved
+    requires: Vec<Literal>,
 }
@@ -1,6 +1,6 @@
 use super::FlagGroup;
 use crate::{
-    command::{FlagGroupHelpOutput, FlagGroupHelpUsageOutput},
+    command::{FlagGroupDocumentOutput, FlagGroupHelpOutput, FlagGroupHelpUsageOutput},
     flag_group::output::{
         FlagGroupDeclaration, FlagGroupInProgress, FlagGroupLongName, FlagGroupShortName,
         FlagGroupUnwrap,
@@ -19,15 +19,17 @@ impl<'a> FlagGroup<'a> {
         FlagGroupUnwrap<'a>,
         FlagGroupHelpUsageOutput<'a>,
         FlagGroupHelpOutput<'a>,
+        FlagGroupDocumentOutput<'a>,
     ) {
         (
             FlagGroupInProgress::new(index == 0, self.r#type.clone()),
             FlagGroupDeclaration::new(index == 0, self.r#type.clone()),
             FlagGroupLongName::new(index, self.r#type.clone()),
             FlagGroupShortName::new(index, self.r#type.clone()),
-            FlagGroupUnwrap::new(self.variable_name, index),
+            FlagGroupUnwrap::new(self.variable_name, index, self.r#type.clone()),
             FlagGroupHelpUsageOutput::new(self.r#type.clone()),
-            FlagGroupHelpOutput::new(self.header_name, self.r#type),
+            FlagGroupHelpOutput::new(self.header_name.clone(), self.r#type.clone()),
+            FlagGroupDocumentOutput::new(self.header_name, self.r#type),
         )
     }
 }
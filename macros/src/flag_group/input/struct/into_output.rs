@@ -1,16 +1,34 @@
 use proc_macro_util::ast::GenericParams;
 
 use super::StructInput;
-use crate::flag_group::output::{InProgress, NewInProgress, Output, StructOutput};
+use crate::{
+    command::LongNamesOutput,
+    flag_group::output::{InProgress, NewInProgress, Output, StructOutput},
+};
 
 impl<'a> StructInput<'a> {
     /// Converts this input into an [`Output`]
     pub fn into_output(self) -> Output<'a> {
         let new_in_progress = NewInProgress::new(self.flags.len());
 
+        let long_names_output = LongNamesOutput::new(
+            self.flags
+                .iter()
+                .filter_map(|flag| flag.visible_long_name().cloned())
+                .collect(),
+            self.flag_groups
+                .iter()
+                .map(|flag_group| flag_group.r#type().clone())
+                .collect(),
+        );
+
         let mut description_offset = 0;
         let mut has_short_names = false;
-        for flag in &self.flags {
+        for flag in self
+            .flags
+            .iter()
+            .filter(|flag| flag.visible_long_name().is_some())
+        {
             description_offset = description_offset.max(flag.help_length());
             has_short_names |= flag.short_name().is_some();
         }
@@ -23,6 +41,38 @@ impl<'a> StructInput<'a> {
 
         let num_flags = self.flags.len();
 
+        let in_arg_group: Vec<bool> = self
+            .flags
+            .iter()
+            .map(|flag| {
+                self.arg_groups
+                    .iter()
+                    .any(|arg_group| arg_group.contains(flag.variable_name()))
+            })
+            .collect();
+
+        let mut arg_group_infos = Vec::with_capacity(self.arg_groups.len());
+        let mut arg_group_checks = Vec::with_capacity(self.arg_groups.len());
+        let mut arg_group_usages = Vec::with_capacity(self.arg_groups.len());
+        let mut arg_group_helps = Vec::with_capacity(self.arg_groups.len());
+        let mut arg_group_documents = Vec::with_capacity(self.arg_groups.len());
+        for (i, arg_group) in self.arg_groups.into_iter().enumerate() {
+            let (info, check, usage, help, document) = arg_group.into_output(i, |member| {
+                let member = member.to_string();
+                self.flags
+                    .iter()
+                    .find(|flag| flag.variable_name().to_string() == member)
+                    .unwrap()
+                    .info_names()
+            });
+
+            arg_group_infos.push(info);
+            arg_group_checks.push(check);
+            arg_group_usages.push(usage);
+            arg_group_helps.push(help);
+            arg_group_documents.push(document);
+        }
+
         let mut infos = Vec::with_capacity(self.flags.len());
         let mut types = Vec::with_capacity(self.flags.len());
         let mut long_names = Vec::with_capacity(self.flags.len());
@@ -30,16 +80,22 @@ impl<'a> StructInput<'a> {
         let mut unwraps = Vec::with_capacity(self.flags.len());
         let mut usages = Vec::with_capacity(self.flags.len());
         let mut helps = Vec::with_capacity(self.flags.len());
-        for (i, flag) in self.flags.into_iter().enumerate() {
-            let (info, r#type, long_name, short_name, unwrap, usage, help) =
+        let mut documents = Vec::with_capacity(self.flags.len());
+        for (i, (flag, in_arg_group)) in self.flags.into_iter().zip(in_arg_group).enumerate() {
+            let (info, r#type, long_name, short_name, unwrap, usage, help, document) =
                 flag.into_output(i, description_offset, has_short_names);
 
             infos.push(info);
             r#types.push(r#type);
             long_names.push(long_name);
             unwraps.push(unwrap);
-            usages.push(usage);
             helps.push(help);
+            documents.push(document);
+
+            // Members of an arg group are written in the usage of their group
+            if !in_arg_group {
+                usages.push(usage);
+            }
 
             if let Some(short_name) = short_name {
                 short_names.push(short_name);
@@ -53,8 +109,9 @@ impl<'a> StructInput<'a> {
         let mut flag_group_unwraps = Vec::with_capacity(self.flag_groups.len());
         let mut flag_group_usages = Vec::with_capacity(self.flag_groups.len());
         let mut flag_group_helps = Vec::with_capacity(self.flag_groups.len());
+        let mut flag_group_documents = Vec::with_capacity(self.flag_groups.len());
         for (i, flag_group) in self.flag_groups.into_iter().enumerate() {
-            let (in_progress, declaration, long_name, short_name, unwrap, usage, help) =
+            let (in_progress, declaration, long_name, short_name, unwrap, usage, help, document) =
                 flag_group.into_output(i + num_flags);
 
             flag_group_in_progress.push(in_progress);
@@ -64,6 +121,7 @@ impl<'a> StructInput<'a> {
             flag_group_unwraps.push(unwrap);
             flag_group_usages.push(usage);
             flag_group_helps.push(help);
+            flag_group_documents.push(document);
         }
 
         let in_progress = InProgress::new(types);
@@ -85,6 +143,7 @@ impl<'a> StructInput<'a> {
             unwraps,
             usages,
             helps,
+            documents,
             flag_group_in_progress,
             flag_group_declarations,
             flag_group_long_names,
@@ -92,6 +151,13 @@ impl<'a> StructInput<'a> {
             flag_group_unwraps,
             flag_group_usages,
             flag_group_helps,
+            flag_group_documents,
+            arg_group_infos,
+            arg_group_checks,
+            arg_group_usages,
+            arg_group_helps,
+            arg_group_documents,
+            long_names_output,
         ))
     }
 }
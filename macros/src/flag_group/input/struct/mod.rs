@@ -1,3 +1,4 @@
+use crate::command::ArgGroup;
 use flag::Flag;
 use flag_group::FlagGroup;
 use proc_macro_util::{ast::GenericParams, tokens::Identifier};
@@ -22,4 +23,7 @@ pub struct StructInput<'a> {
 
     /// Child flag groups of this flag group
     flag_groups: Vec<FlagGroup<'a>>,
+
+    /// The groups of flags with a rule on how many can be passed together
+    arg_groups: Vec<ArgGroup>,
 }
@@ -1,11 +1,13 @@
 mod declaration;
 mod in_progress;
 mod long_name;
+mod passed;
 mod short_name;
 mod unwrap;
 
 pub use declaration::FlagGroupDeclaration;
 pub use in_progress::FlagGroupInProgress;
 pub use long_name::FlagGroupLongName;
+pub use passed::FlagGroupPassed;
 pub use short_name::FlagGroupShortName;
 pub use unwrap::FlagGroupUnwrap;
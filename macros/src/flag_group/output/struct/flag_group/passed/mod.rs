@@ -0,0 +1,13 @@
+use proc_macro_util::ast::Type;
+
+mod new;
+mod to_tokens;
+
+/// Records the flags passed in a child flag group
+pub struct FlagGroupPassed<'a> {
+    /// The index of the child flag group in the in-progress tuple
+    index: usize,
+
+    /// The type of the flag group
+    r#type: Type<'a>,
+}
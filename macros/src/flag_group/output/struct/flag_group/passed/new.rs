@@ -0,0 +1,9 @@
+use super::FlagGroupPassed;
+use proc_macro_util::ast::Type;
+
+impl<'a> FlagGroupPassed<'a> {
+    /// Creates a new [`FlagGroupPassed`]
+    pub fn new(index: usize, r#type: Type<'a>) -> Self {
+        FlagGroupPassed { index, r#type }
+    }
+}
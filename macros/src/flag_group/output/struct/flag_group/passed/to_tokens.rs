@@ -0,0 +1,12 @@
+use super::FlagGroupPassed;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for FlagGroupPassed<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let FlagGroupPassed { index, r#type } = self;
+
+        to_tokens! { generator
+            <#r#type as ::argparse::FlagGroup>::passed_flags(&__this.#index, __passed_flags);
+        }
+    }
+}
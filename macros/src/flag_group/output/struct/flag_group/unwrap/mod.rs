@@ -1,7 +1,9 @@
-use proc_macro_util::tokens::Identifier;
+use proc_macro_util::{ast::Type, tokens::Identifier};
 use std::borrow::Cow;
 
+mod field;
 mod new;
+mod passed;
 mod to_tokens;
 
 /// Unwraps a flag variable
@@ -11,4 +13,7 @@ pub struct FlagGroupUnwrap<'a> {
 
     /// The index of the child flag group in the in-progress tuple
     index: usize,
+
+    /// The type of the child flag group
+    r#type: Type<'a>,
 }
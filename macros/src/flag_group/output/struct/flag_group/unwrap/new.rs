@@ -1,13 +1,14 @@
 use super::FlagGroupUnwrap;
-use proc_macro_util::tokens::Identifier;
+use proc_macro_util::{ast::Type, tokens::Identifier};
 use std::borrow::Cow;
 
 impl<'a> FlagGroupUnwrap<'a> {
     /// Creates a new [`FlagGroupUnwrap`]
-    pub fn new(variable_name: Cow<'a, Identifier>, index: usize) -> Self {
+    pub fn new(variable_name: Cow<'a, Identifier>, index: usize, r#type: Type<'a>) -> Self {
         FlagGroupUnwrap {
             variable_name,
             index,
+            r#type,
         }
     }
 }
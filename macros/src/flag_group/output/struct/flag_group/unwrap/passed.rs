@@ -0,0 +1,9 @@
+use super::FlagGroupUnwrap;
+use crate::flag_group::output::FlagGroupPassed;
+
+impl<'a> FlagGroupUnwrap<'a> {
+    /// Gets the collection of the flags passed in the child flag group
+    pub fn passed(&self) -> FlagGroupPassed<'a> {
+        FlagGroupPassed::new(self.index, self.r#type.clone())
+    }
+}
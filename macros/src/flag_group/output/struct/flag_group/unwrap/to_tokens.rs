@@ -6,10 +6,11 @@ impl<'a> ToTokens for FlagGroupUnwrap<'a> {
         let FlagGroupUnwrap {
             variable_name,
             index,
+            ..
         } = self;
 
         to_tokens! { generator
-            #variable_name: ::argparse::FlagGroup::unwrap(this.#index)?,
+            let #variable_name = __errors.take(::argparse::FlagGroup::unwrap(__this.#index));
         }
     }
 }
@@ -1,5 +1,8 @@
 use crate::command::{
-    FlagGroupHelpOutput, FlagGroupHelpUsageOutput, FlagHelpOutput, FlagHelpUsageOutput, FlagInfo,
+    ArgGroupCheck, ArgGroupDocumentOutput, ArgGroupHelpOutput, ArgGroupHelpUsageOutput,
+    ArgGroupInfo, FlagDocumentOutput, FlagGroupDocumentOutput, FlagGroupHelpOutput,
+    FlagGroupHelpUsageOutput, FlagHelpOutput, FlagHelpUsageOutput, FlagInfo, LongNamesOutput,
+    UnwrappedField,
 };
 use proc_macro_util::{
     ast::{GenericArgs, GenericParams},
@@ -11,6 +14,7 @@ mod flag_group;
 mod in_progress;
 mod long_name;
 mod new_in_progress;
+mod passed;
 mod short_name;
 mod unwrap;
 
@@ -21,6 +25,7 @@ pub use flag_group::*;
 pub use in_progress::InProgress;
 pub use long_name::FlagLongName;
 pub use new_in_progress::NewInProgress;
+pub use passed::FlagPassed;
 pub use short_name::FlagShortName;
 pub use unwrap::FlagUnwrap;
 
@@ -62,6 +67,9 @@ pub struct StructOutput<'a> {
     /// The help displays for the contained flags
     helps: Vec<FlagHelpOutput>,
 
+    /// The documentation entries for the contained flags
+    documents: Vec<FlagDocumentOutput>,
+
     /// Declaration of flag group types
     flag_group_in_progress: Vec<FlagGroupInProgress<'a>>,
 
@@ -77,9 +85,39 @@ pub struct StructOutput<'a> {
     /// Unwraps the flag group variables
     flag_group_unwraps: Vec<FlagGroupUnwrap<'a>>,
 
+    /// Records the flags which were passed
+    passed: Vec<FlagPassed>,
+
+    /// Records the flags which were passed in the child flag groups
+    flag_group_passed: Vec<FlagGroupPassed<'a>>,
+
+    /// Initializes the fields from the unwrapped variables
+    unwrapped_fields: Vec<UnwrappedField<'a>>,
+
     /// The flag group usages
     flag_group_usages: Vec<FlagGroupHelpUsageOutput<'a>>,
 
     /// The flag groups to display help for
     flag_group_helps: Vec<FlagGroupHelpOutput<'a>>,
+
+    /// The flag groups to write documentation entries for
+    flag_group_documents: Vec<FlagGroupDocumentOutput<'a>>,
+
+    /// The info describing the arg groups
+    arg_group_infos: Vec<ArgGroupInfo>,
+
+    /// Checks the passed flags against the arg groups
+    arg_group_checks: Vec<ArgGroupCheck>,
+
+    /// The arg group usages
+    arg_group_usages: Vec<ArgGroupHelpUsageOutput>,
+
+    /// The rules of the arg groups to display after the contained flags
+    arg_group_helps: Vec<ArgGroupHelpOutput>,
+
+    /// The arg groups to write documentation for after the contained flags
+    arg_group_documents: Vec<ArgGroupDocumentOutput>,
+
+    /// The long names of the flags, for suggesting a flag when an unknown one is passed
+    long_names_output: LongNamesOutput<'a>,
 }
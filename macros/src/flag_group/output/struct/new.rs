@@ -4,7 +4,9 @@ use super::{
     StructOutput,
 };
 use crate::command::{
-    FlagGroupHelpOutput, FlagGroupHelpUsageOutput, FlagHelpOutput, FlagHelpUsageOutput, FlagInfo,
+    ArgGroupCheck, ArgGroupDocumentOutput, ArgGroupHelpOutput, ArgGroupHelpUsageOutput,
+    ArgGroupInfo, FlagDocumentOutput, FlagGroupDocumentOutput, FlagGroupHelpOutput,
+    FlagGroupHelpUsageOutput, FlagHelpOutput, FlagHelpUsageOutput, FlagInfo, LongNamesOutput,
 };
 use proc_macro_util::{
     ast::{GenericArgs, GenericParams},
@@ -26,6 +28,7 @@ impl<'a> StructOutput<'a> {
         unwraps: Vec<FlagUnwrap<'a>>,
         usages: Vec<FlagHelpUsageOutput>,
         helps: Vec<FlagHelpOutput>,
+        documents: Vec<FlagDocumentOutput>,
         flag_group_in_progress: Vec<FlagGroupInProgress<'a>>,
         flag_group_declarations: Vec<FlagGroupDeclaration<'a>>,
         flag_group_long_names: Vec<FlagGroupLongName<'a>>,
@@ -33,9 +36,28 @@ impl<'a> StructOutput<'a> {
         flag_group_unwraps: Vec<FlagGroupUnwrap<'a>>,
         flag_group_usages: Vec<FlagGroupHelpUsageOutput<'a>>,
         flag_group_helps: Vec<FlagGroupHelpOutput<'a>>,
+        flag_group_documents: Vec<FlagGroupDocumentOutput<'a>>,
+        arg_group_infos: Vec<ArgGroupInfo>,
+        arg_group_checks: Vec<ArgGroupCheck>,
+        arg_group_usages: Vec<ArgGroupHelpUsageOutput>,
+        arg_group_helps: Vec<ArgGroupHelpOutput>,
+        arg_group_documents: Vec<ArgGroupDocumentOutput>,
+        long_names_output: LongNamesOutput<'a>,
     ) -> Self {
         let module_name = Identifier::new(&format!("__flag_group_{}", name));
 
+        let passed = unwraps.iter().map(FlagUnwrap::passed).collect();
+        let flag_group_passed = flag_group_unwraps
+            .iter()
+            .map(FlagGroupUnwrap::passed)
+            .collect();
+
+        let unwrapped_fields = unwraps
+            .iter()
+            .map(FlagUnwrap::field)
+            .chain(flag_group_unwraps.iter().map(FlagGroupUnwrap::field))
+            .collect();
+
         StructOutput {
             name,
             generic_params,
@@ -49,13 +71,24 @@ impl<'a> StructOutput<'a> {
             unwraps,
             helps,
             usages,
+            documents,
             flag_group_in_progress,
             flag_group_declarations,
             flag_group_long_names,
             flag_group_short_names,
             flag_group_unwraps,
+            passed,
+            flag_group_passed,
+            unwrapped_fields,
             flag_group_usages,
             flag_group_helps,
+            flag_group_documents,
+            arg_group_infos,
+            arg_group_checks,
+            arg_group_usages,
+            arg_group_helps,
+            arg_group_documents,
+            long_names_output,
         }
     }
 }
@@ -0,0 +1,13 @@
+use proc_macro_util::tokens::Identifier;
+
+mod new;
+mod to_tokens;
+
+/// Records a flag as passed if it was set
+pub struct FlagPassed {
+    /// The index in the tuple of the flag
+    index: usize,
+
+    /// The name of the information describing the flag
+    info_name: Identifier,
+}
@@ -0,0 +1,9 @@
+use super::FlagPassed;
+use proc_macro_util::tokens::Identifier;
+
+impl FlagPassed {
+    /// Creates a new [`FlagPassed`]
+    pub fn new(index: usize, info_name: Identifier) -> Self {
+        FlagPassed { index, info_name }
+    }
+}
@@ -0,0 +1,14 @@
+use super::FlagPassed;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for FlagPassed {
+    fn to_tokens(self, generator: &mut Generator) {
+        let FlagPassed { index, info_name } = self;
+
+        to_tokens! { generator
+            if __this.#index.is_some() {
+                __passed_flags.push(Self::#info_name.passed());
+            }
+        }
+    }
+}
@@ -16,13 +16,24 @@ impl<'a> ToTokens for StructOutput<'a> {
             unwraps,
             usages,
             helps,
+            documents,
             flag_group_in_progress,
             flag_group_declarations,
             flag_group_long_names,
             flag_group_short_names,
             flag_group_unwraps,
+            passed,
+            flag_group_passed,
+            unwrapped_fields,
             flag_group_usages,
             flag_group_helps,
+            flag_group_documents,
+            arg_group_infos,
+            arg_group_checks,
+            arg_group_usages,
+            arg_group_helps,
+            arg_group_documents,
+            long_names_output,
         } = self;
 
         let name2 = name.clone();
@@ -45,6 +56,7 @@ impl<'a> ToTokens for StructOutput<'a> {
 
                 impl #generic_params #name #generic_args {
                     #infos
+                    #arg_group_infos
                 }
 
                 impl #generic_params2 ::argparse::FlagGroup for #name2 #generic_args2 {
@@ -88,24 +100,55 @@ impl<'a> ToTokens for StructOutput<'a> {
                         Ok(true)
                     }
 
-                    fn unwrap(this: Self::InProgress) -> ::argparse::Result<Self> {
+                    fn unwrap(__this: Self::InProgress) -> ::argparse::Result<Self> {
+                        #[allow(unused_mut)]
+                        let mut __errors = ::argparse::UnwrapErrors::new();
+
+                        // Check the arg groups
+                        #[allow(unused_mut)]
+                        let mut __passed_flags: ::std::vec::Vec<::argparse::PassedFlag> = ::std::vec::Vec::new();
+                        <Self as ::argparse::FlagGroup>::passed_flags(&__this, &mut __passed_flags);
+                        #arg_group_checks
+
+                        #unwraps
+                        #flag_group_unwraps
+                        __errors.finish()?;
+
                         Ok(#name3 {
-                            #unwraps
-                            #flag_group_unwraps
+                            #unwrapped_fields
                         })
                     }
 
-                    fn print_help_usage() -> bool {
+                    fn passed_flags(__this: &Self::InProgress, __passed_flags: &mut ::std::vec::Vec<::argparse::PassedFlag>) {
+                        #passed
+                        #flag_group_passed
+                    }
+
+                    fn write_help_usage(__output: &mut dyn ::std::io::Write, __style: ::argparse::Style) -> ::std::io::Result<bool> {
                         #[allow(unused_mut)]
                         let mut __optional_flags = false;
                         #usages
                         #flag_group_usages
-                        __optional_flags
+                        #arg_group_usages
+                        Ok(__optional_flags)
                     }
 
-                    fn print_help() {
+                    fn write_help(__output: &mut dyn ::std::io::Write, __wrap: ::argparse::TextWrap, __style: ::argparse::Style, __mode: ::argparse::HelpMode) -> ::std::io::Result<()> {
                         #helps
+                        #arg_group_helps
                         #flag_group_helps
+                        Ok(())
+                    }
+
+                    fn long_names(__long_names: &mut ::std::vec::Vec<&'static str>) {
+                        #long_names_output
+                    }
+
+                    fn write_document<__W: ::argparse::DocumentWriter>(__writer: &mut __W) -> ::std::io::Result<()> {
+                        #documents
+                        #arg_group_documents
+                        #flag_group_documents
+                        Ok(())
                     }
                 }
             }
@@ -0,0 +1,9 @@
+use super::FlagUnwrap;
+use crate::flag_group::output::FlagPassed;
+
+impl<'a> FlagUnwrap<'a> {
+    /// Gets the check recording whether the flag was passed
+    pub fn passed(&self) -> FlagPassed {
+        FlagPassed::new(self.index, self.info_name.clone())
+    }
+}
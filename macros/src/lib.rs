@@ -18,7 +18,43 @@ proc_macro_util::proc_macro_derive!(
     /// attribute to a field cause it to be a flag. Adding a `flag_group` attribute to a field will
     /// cause it to be a flag group. All fields without a `flag` or `flag_group` attribute will be
     /// treated as a positional, parsed in the order they appear.
-    Command (command, flag, arg, flag_group) -> command::generate
+    ///
+    /// Flags and positionals can take a `validate` function, like `flag(validate = check_port)`.
+    /// It is passed a reference to each parsed value, including every element of collections, and
+    /// the error it returns is reported as an invalid value for the argument.
+    ///
+    /// Flags and positionals can take a `complete` function, like `arg(complete = list_branches)`.
+    /// It is passed the partial value being completed and returns the candidates offered by
+    /// completion scripts.
+    ///
+    /// The struct can have a `command(dynamic_completion)` attribute attached to make `parse_env`
+    /// answer the requests of dynamic completion scripts, which run the program with "__complete"
+    /// followed by the arguments being completed.
+    ///
+    /// Flags and positionals can be marked `hidden`, like `flag(hidden)`, to leave them out of
+    /// help, documentation and completions while still being parsed. Hidden items are only shown
+    /// by "--help-all".
+    ///
+    /// The `command` attribute can take a `long_description` written after the description, an
+    /// `after_help` epilogue written at the end and a list of `examples`, like
+    /// `command(examples = ["tool build", "tool clean"])`, written under their own header. These
+    /// are only included in the long help from "--help", not the summary from "-h". Flags and
+    /// positionals can also take a `long_description`, written after their description.
+    ///
+    /// The `command` attribute can take `long_version` build details written after the version by
+    /// "--version", like `command(version, long_version = { commit = env!("GIT_HASH") })`, while
+    /// "-V" writes only the version. Adding `version_command` also treats "version" given as the
+    /// first positional value as a request for the long version.
+    ///
+    /// Flags can take the long names of flags they can't be passed with or must be passed with,
+    /// like `flag(conflicts_with = "quiet", requires = ["output", "format"])`. The names can be
+    /// those of flags in the struct or in its flag groups, and are noted in the flag's help.
+    ///
+    /// The struct can have `arg_group` attributes attached to limit how many of its flags can be
+    /// passed together. Each lists the flags with one of the rules `exactly_one`, `at_least_one` or
+    /// `at_most_one`, like `arg_group(exactly_one = [file, url, stdin])`. Groups which require a
+    /// flag are written as `<--file FILE|--url URL|--stdin>` in the usage.
+    Command (command, flag, arg, flag_group, arg_group) -> command::generate
 );
 
 proc_macro_util::proc_macro_derive!(
@@ -29,7 +65,10 @@ proc_macro_util::proc_macro_derive!(
     ///
     /// Fields can have a `flag_group` attribute attached to mark it as a child flag group. This
     /// cannot be marked at the same time as `flag`.
-    FlagGroup (flag, flag_group) -> flag_group::generate
+    ///
+    /// The struct can have `arg_group` attributes attached to group its flags. See documentation
+    /// for `Command` derive for details on the `arg_group` attribute.
+    FlagGroup (flag, flag_group, arg_group) -> flag_group::generate
 );
 
 proc_macro_util::proc_macro_derive!(
@@ -41,5 +80,15 @@ proc_macro_util::proc_macro_derive!(
     ///
     /// Variants with a single tuple field will be treated as sub-commands rather than a simple
     /// match, and the type associated will be used as a `Command`.
-    Positional -> positional::generate
+    ///
+    /// Enums without any sub-commands can have a `positional(flag)` attribute attached to also
+    /// implement `Flag`, taking the value from the argument after the flag.
+    ///
+    /// Variants can have a `positional(hidden)` attribute attached to leave them out of help,
+    /// documentation and completions while still being parsed.
+    ///
+    /// The enum can have a `positional(help_command)` attribute attached to add a "help"
+    /// sub-command. On its own it lists the sub-commands and their descriptions, taken from the
+    /// variants' doc comments. Followed by sub-command names it prints the help for the last one.
+    Positional (positional) -> positional::generate
 );
@@ -2,8 +2,9 @@ use super::{EnumInput, EnumInputVariant};
 use proc_macro_util::{ast::items::Enumeration, Result};
 
 impl<'a> EnumInput<'a> {
-    /// Extract the required details from `r#enum`
-    pub fn extract(r#enum: Enumeration<'a>) -> Result<Self> {
+    /// Extract the required details from `r#enum`, adding a "help" sub-command if `help_command`
+    /// and implementing `Flag` if `flag`
+    pub fn extract(r#enum: Enumeration<'a>, help_command: bool, flag: bool) -> Result<Self> {
         let mut variants = Vec::new();
         if let Some(items) = r#enum.enum_items {
             variants.push(EnumInputVariant::extract(items.first)?);
@@ -12,9 +13,18 @@ impl<'a> EnumInput<'a> {
             }
         }
 
+        if flag && (help_command || variants.iter().any(EnumInputVariant::is_sub_command)) {
+            return Err(r#enum
+                .name
+                .span()
+                .error("only enums without sub-commands can be used as flags"));
+        }
+
         Ok(EnumInput {
             name: r#enum.name,
             variants,
+            help_command,
+            flag,
         })
     }
 }
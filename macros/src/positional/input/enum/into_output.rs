@@ -1,25 +1,52 @@
 use super::EnumInput;
-use crate::positional::output::{EnumOutput, Output};
+use crate::positional::output::{EnumFlag, EnumOutput, EnumValues, Output};
 use proc_macro_util::tokens::Literal;
 
 impl<'a> EnumInput<'a> {
     /// Converts this input into an [`Output`]
     pub fn into_output(self) -> Output<'a> {
+        let help_command =
+            self.help_command && !self.variants.iter().any(|variant| variant.is_help());
+
         let mut parses = Vec::with_capacity(self.variants.len());
         let mut subs = Vec::with_capacity(self.variants.len());
+        let mut sub_command_infos = Vec::with_capacity(self.variants.len());
+        let mut sub_command_names = Vec::with_capacity(self.variants.len());
+        let mut documents = Vec::with_capacity(self.variants.len());
         let mut displays = Vec::with_capacity(self.variants.len());
+        let mut flag_parses = Vec::with_capacity(self.variants.len());
+        let mut values = Vec::with_capacity(self.variants.len());
         let mut expected = String::new();
-        let last = self.variants.len().checked_sub(1).unwrap_or(0);
-        for (i, variant) in self.variants.into_iter().enumerate() {
-            let (parse, name, sub, display) = variant.into_output();
+        let mut names = Vec::with_capacity(self.variants.len());
+        for variant in self.variants {
+            let (parse, name, sub, display, flag_parse) = variant.into_output();
 
             parses.push(parse);
             displays.push(display);
+            flag_parses.extend(flag_parse);
 
-            if let Some(sub) = sub {
+            let is_sub_command = sub.is_some();
+            if let Some((sub, document, sub_command_info)) = sub {
                 subs.push(sub);
+                documents.push(document);
+                sub_command_infos.extend(sub_command_info);
+            }
+
+            if let Some(name) = name {
+                if is_sub_command {
+                    sub_command_names.push(Literal::new(name.as_str()));
+                }
+                names.push(name);
             }
+        }
 
+        if help_command {
+            sub_command_names.push(Literal::new("help"));
+            names.push("help".to_owned());
+        }
+
+        let last = names.len().checked_sub(1).unwrap_or(0);
+        for (i, name) in names.into_iter().enumerate() {
             if i > 0 {
                 expected.push_str(", ");
             }
@@ -30,14 +57,36 @@ impl<'a> EnumInput<'a> {
             expected.push('"');
             expected.push_str(&name);
             expected.push('"');
+
+            values.push(Literal::new(name.as_str()));
         }
 
+        let expected = Literal::new(expected.as_str());
+        let flag = if self.flag {
+            Some(EnumFlag::new(
+                self.name.clone(),
+                flag_parses,
+                expected.clone(),
+                EnumValues::new(values.clone()),
+                EnumValues::new(values.clone()),
+            ))
+        } else {
+            None
+        };
+
         Output::Enum(EnumOutput::new(
             self.name,
             parses,
-            Literal::new(expected.as_str()),
+            expected,
+            EnumValues::new(values.clone()),
+            EnumValues::new(values),
             subs,
+            help_command,
+            EnumValues::new(sub_command_names),
+            sub_command_infos,
+            documents,
             displays,
+            flag,
         ))
     }
 }
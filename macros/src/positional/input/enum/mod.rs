@@ -14,4 +14,10 @@ pub struct EnumInput<'a> {
 
     /// The variants that make up this enum
     variants: Vec<EnumInputVariant<'a>>,
+
+    /// Does this enum get a built-in "help" sub-command?
+    help_command: bool,
+
+    /// Should this enum also implement `Flag`?
+    flag: bool,
 }
@@ -1,12 +1,65 @@
 use super::EnumInputVariant;
 use proc_macro_util::{
-    ast::items::{EnumItem, EnumItemKind},
-    Result,
+    ast::{
+        items::{EnumItem, EnumItemKind},
+        AttrInput,
+    },
+    tokens::Identifier,
+    Result, Token,
 };
 
 impl<'a> EnumInputVariant<'a> {
     /// Extract the required details from `item`
     pub fn extract(item: EnumItem<'a>) -> Result<Self> {
+        let mut hidden = false;
+        let mut docs = Vec::new();
+        for attribute in item.attributes {
+            if attribute.attr.path.remaining.len() > 0 || attribute.attr.path.leading.is_some() {
+                continue;
+            }
+
+            let group = match attribute.attr.path.first.to_string().as_str() {
+                "positional" => match attribute.attr.input {
+                    Some(AttrInput::Group(group)) => group,
+                    Some(AttrInput::Expression(eq, _)) => {
+                        return Err(eq.spans[0].error("expected a group, not an expression"))
+                    }
+                    None => continue,
+                },
+                "doc" => {
+                    if let Some(AttrInput::Expression(_, expression)) = attribute.attr.input {
+                        docs.push(expression);
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let mut parser = group.parser();
+            while !parser.empty() {
+                let tag = parser.parse::<Identifier>()?;
+                let tag_str = tag.to_string();
+
+                match tag_str.as_str() {
+                    "hidden" => hidden = true,
+                    _ => {
+                        return Err(tag
+                            .span()
+                            .error(format!("unknown positional tag \"{tag_str}\"")))
+                    }
+                }
+
+                match parser.step_parse::<Token![,]>() {
+                    Ok(_) => {}
+                    Err(_) => break,
+                }
+            }
+
+            if !parser.empty() {
+                return Err(parser.error("unexpected token"));
+            }
+        }
+
         let r#type = match item.kind {
             Some(EnumItemKind::Tuple(Some(tuple))) => {
                 if tuple.remaining.len() > 0 {
@@ -30,6 +83,8 @@ impl<'a> EnumInputVariant<'a> {
         Ok(EnumInputVariant {
             name: item.name,
             r#type,
+            hidden,
+            docs,
         })
     }
 }
@@ -0,0 +1,13 @@
+use super::EnumInputVariant;
+
+impl<'a> EnumInputVariant<'a> {
+    /// Does this variant match on "help", hidden or not?
+    pub fn is_help(&self) -> bool {
+        self.name.to_string().to_lowercase() == "help"
+    }
+
+    /// Is this variant a sub-command?
+    pub fn is_sub_command(&self) -> bool {
+        self.r#type.is_some()
+    }
+}
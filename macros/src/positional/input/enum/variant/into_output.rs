@@ -1,16 +1,25 @@
 use super::EnumInputVariant;
-use crate::positional::output::{EnumVariantDisplay, EnumVariantParse, EnumVariantSub};
+use crate::positional::output::{
+    EnumSubCommandInfo, EnumVariantDisplay, EnumVariantDocument, EnumVariantFlagParse,
+    EnumVariantParse, EnumVariantSub,
+};
 use proc_macro_util::tokens::Literal;
 
 impl<'a> EnumInputVariant<'a> {
-    /// Converts this input into the needed output types
+    /// Converts this input into the needed output types, leaving hidden variants out of the name
+    /// and sub-command listing, and only documenting them for writers asking for hidden commands
     pub fn into_output(
         self,
     ) -> (
         EnumVariantParse<'a>,
-        String,
-        Option<EnumVariantSub<'a>>,
+        Option<String>,
+        Option<(
+            EnumVariantSub<'a>,
+            EnumVariantDocument<'a>,
+            Option<EnumSubCommandInfo<'a>>,
+        )>,
         EnumVariantDisplay<'a>,
+        Option<EnumVariantFlagParse<'a>>,
     ) {
         let mut string = self.name.to_string();
         let mut i = 0;
@@ -30,6 +39,7 @@ impl<'a> EnumInputVariant<'a> {
         let string = string.to_lowercase();
         let string_literal = Literal::new(string.as_str());
         let has_field = self.r#type.is_some();
+        let docs = self.docs;
 
         (
             EnumVariantParse::new(
@@ -40,11 +50,24 @@ impl<'a> EnumInputVariant<'a> {
                     Some(self.name.clone())
                 },
             ),
-            string,
+            if self.hidden { None } else { Some(string) },
             self.r#type.map(|r#type| {
-                EnumVariantSub::new(string_literal.clone(), r#type, self.name.clone())
+                (
+                    EnumVariantSub::new(string_literal.clone(), r#type.clone(), self.name.clone()),
+                    EnumVariantDocument::new(string_literal.clone(), r#type, self.hidden),
+                    if self.hidden {
+                        None
+                    } else {
+                        Some(EnumSubCommandInfo::new(string_literal.clone(), docs))
+                    },
+                )
             }),
-            EnumVariantDisplay::new(self.name, has_field, string_literal),
+            EnumVariantDisplay::new(self.name.clone(), has_field, string_literal.clone()),
+            if has_field {
+                None
+            } else {
+                Some(EnumVariantFlagParse::new(string_literal, self.name))
+            },
         )
     }
 }
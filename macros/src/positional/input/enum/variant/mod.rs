@@ -1,7 +1,11 @@
-use proc_macro_util::{ast::Type, tokens::Identifier};
+use proc_macro_util::{
+    ast::{Expression, Type},
+    tokens::Identifier,
+};
 use std::borrow::Cow;
 
 mod extract;
+mod get;
 mod into_output;
 
 /// The details extracted from an enum variant
@@ -11,4 +15,10 @@ pub struct EnumInputVariant<'a> {
 
     /// The type of the field associated with this variant, if there is one
     r#type: Option<Type<'a>>,
+
+    /// Is this variant left out of help, documentation and completions?
+    hidden: bool,
+
+    /// The documentation lines of the variant, describing its sub-command
+    docs: Vec<Expression<'a>>,
 }
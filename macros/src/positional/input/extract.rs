@@ -1,14 +1,61 @@
 use super::{EnumInput, Input};
 use proc_macro_util::{
-    ast::{DeriveItem, DeriveItemKind},
-    Result, Span,
+    ast::{AttrInput, DeriveItem, DeriveItemKind},
+    tokens::Identifier,
+    Result, Span, Token,
 };
 
 impl<'a> Input<'a> {
     /// Extract the required details from `item`
     pub fn extract(item: DeriveItem<'a>) -> Result<Self> {
+        let mut help_command = false;
+        let mut flag = false;
+        for attribute in item.attributes {
+            if attribute.attr.path.remaining.len() > 0
+                || attribute.attr.path.leading.is_some()
+                || attribute.attr.path.first.to_string() != "positional"
+            {
+                continue;
+            }
+
+            let group = match attribute.attr.input {
+                Some(AttrInput::Group(group)) => group,
+                Some(AttrInput::Expression(eq, _)) => {
+                    return Err(eq.spans[0].error("expected a group, not an expression"))
+                }
+                None => continue,
+            };
+
+            let mut parser = group.parser();
+            while !parser.empty() {
+                let tag = parser.parse::<Identifier>()?;
+                let tag_str = tag.to_string();
+
+                match tag_str.as_str() {
+                    "help_command" => help_command = true,
+                    "flag" => flag = true,
+                    _ => {
+                        return Err(tag
+                            .span()
+                            .error(format!("unknown positional tag \"{tag_str}\"")))
+                    }
+                }
+
+                match parser.step_parse::<Token![,]>() {
+                    Ok(_) => {}
+                    Err(_) => break,
+                }
+            }
+
+            if !parser.empty() {
+                return Err(parser.error("unexpected token"));
+            }
+        }
+
         match item.kind {
-            DeriveItemKind::Enum(r#enum) => Ok(Input::Enum(EnumInput::extract(r#enum)?)),
+            DeriveItemKind::Enum(r#enum) => {
+                Ok(Input::Enum(EnumInput::extract(r#enum, help_command, flag)?))
+            }
             _ => Err(Span::call_site().error("`FlagGroup` derive only supports structs")),
         }
     }
@@ -0,0 +1,16 @@
+use proc_macro_util::{ast::Type, tokens::Literal};
+
+mod new;
+mod to_tokens;
+
+/// Generates the tokens to write the documentation sections for the sub-command of an enum variant
+pub struct EnumVariantDocument<'a> {
+    /// The literal which the variant matches on
+    string: Literal,
+
+    /// The type of the sub-command
+    r#type: Type<'a>,
+
+    /// Is the variant hidden, only described to writers asking for hidden commands
+    hidden: bool,
+}
@@ -0,0 +1,13 @@
+use super::EnumVariantDocument;
+use proc_macro_util::{ast::Type, tokens::Literal};
+
+impl<'a> EnumVariantDocument<'a> {
+    /// Creates a new [`EnumVariantDocument`]
+    pub fn new(string: Literal, r#type: Type<'a>, hidden: bool) -> Self {
+        EnumVariantDocument {
+            string,
+            r#type,
+            hidden,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use super::EnumVariantDocument;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for EnumVariantDocument<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let EnumVariantDocument {
+            string,
+            r#type,
+            hidden,
+        } = self;
+
+        if hidden {
+            to_tokens! { generator
+                if writer.hidden_commands() {
+                    <#r#type as ::argparse::Command>::write_document(writer, &command_list.sub_command(#string))?;
+                }
+            }
+        } else {
+            to_tokens! { generator
+                <#r#type as ::argparse::Command>::write_document(writer, &command_list.sub_command(#string))?;
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use super::{EnumValues, EnumVariantFlagParse};
+use proc_macro_util::tokens::{Identifier, Literal};
+use std::borrow::Cow;
+
+mod new;
+mod to_tokens;
+
+/// The output code implementing `Flag` for an enum without sub-commands
+pub struct EnumFlag<'a> {
+    /// The name of the enum
+    name: Cow<'a, Identifier>,
+
+    /// The match arms to parse the flag value
+    parses: Vec<EnumVariantFlagParse<'a>>,
+
+    /// The string describing the expected values
+    expected: Literal,
+
+    /// The values suggested when an unexpected value is passed
+    suggestions: EnumValues,
+
+    /// The values the enum accepts
+    values: EnumValues,
+}
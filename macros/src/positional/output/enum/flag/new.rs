@@ -0,0 +1,22 @@
+use super::{EnumFlag, EnumValues, EnumVariantFlagParse};
+use proc_macro_util::tokens::{Identifier, Literal};
+use std::borrow::Cow;
+
+impl<'a> EnumFlag<'a> {
+    /// Creates a new [`EnumFlag`]
+    pub fn new(
+        name: Cow<'a, Identifier>,
+        parses: Vec<EnumVariantFlagParse<'a>>,
+        expected: Literal,
+        suggestions: EnumValues,
+        values: EnumValues,
+    ) -> Self {
+        EnumFlag {
+            name,
+            parses,
+            expected,
+            suggestions,
+            values,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use super::EnumFlag;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for EnumFlag<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let EnumFlag {
+            name,
+            parses,
+            expected,
+            suggestions,
+            values,
+        } = self;
+
+        to_tokens! { generator
+            impl ::argparse::Flag for #name {
+                type Value = Self;
+
+                fn parse(
+                    this: &mut Option<Self>,
+                    source: &mut dyn ::argparse::ArgumentSource,
+                    info: &::argparse::FlagInfo<Self>,
+                    long: bool,
+                ) -> ::argparse::Result<()> {
+                    if this.is_some() {
+                        return Err(::argparse::Error::repeated_flag(info, long));
+                    }
+
+                    let value = source.next().ok_or(::argparse::Error::missing_flag_value(info, long))?;
+
+                    let value = match value.as_str()? {
+                        #parses
+                        value => {
+                            return Err(::argparse::Error::invalid_flag_value(
+                                info,
+                                long,
+                                ::argparse::UnexpectedError::among(
+                                    value,
+                                    #expected,
+                                    &[#suggestions],
+                                ),
+                            ))
+                        }
+                    };
+
+                    info.validate_value(&value, long)?;
+                    *this = Some(value);
+                    Ok(())
+                }
+
+                fn value_hint(_: &::argparse::FlagInfo<Self>) -> ::argparse::ValueHint {
+                    ::argparse::ValueHint::Values(&[#values])
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use proc_macro_util::tokens::{Identifier, Literal};
+use std::borrow::Cow;
+
+mod new;
+mod to_tokens;
+
+/// Generates the match arm to parse an enum variant as a flag value
+pub struct EnumVariantFlagParse<'a> {
+    /// The literal which the variant matches on
+    string: Literal,
+
+    /// The name of the variant
+    name: Cow<'a, Identifier>,
+}
@@ -0,0 +1,10 @@
+use super::EnumVariantFlagParse;
+use proc_macro_util::tokens::{Identifier, Literal};
+use std::borrow::Cow;
+
+impl<'a> EnumVariantFlagParse<'a> {
+    /// Creates a new [`EnumVariantFlagParse`]
+    pub fn new(string: Literal, name: Cow<'a, Identifier>) -> Self {
+        EnumVariantFlagParse { string, name }
+    }
+}
@@ -0,0 +1,12 @@
+use super::EnumVariantFlagParse;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for EnumVariantFlagParse<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let EnumVariantFlagParse { string, name } = self;
+
+        to_tokens! { generator
+            #string => Self::#name,
+        }
+    }
+}
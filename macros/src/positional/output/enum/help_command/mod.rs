@@ -0,0 +1,10 @@
+mod to_tokens;
+
+/// Generates the match arms for the built-in "help" sub-command of an enum
+pub enum EnumHelpCommand {
+    /// The arm accepting "help" as a sub-command when parsing
+    Parse,
+
+    /// The arm starting the "help" sub-command
+    Sub,
+}
@@ -0,0 +1,19 @@
+use super::EnumHelpCommand;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for EnumHelpCommand {
+    fn to_tokens(self, generator: &mut Generator) {
+        match self {
+            EnumHelpCommand::Parse => {
+                to_tokens! { generator
+                    "help" => ::argparse::PositionalResult::Sub(argument),
+                }
+            }
+            EnumHelpCommand::Sub => {
+                to_tokens! { generator
+                    "help" => <Self as ::argparse::Positional>::help_sub_command(this, source, command_list),
+                }
+            }
+        }
+    }
+}
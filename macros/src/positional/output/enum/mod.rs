@@ -2,15 +2,27 @@ use proc_macro_util::tokens::{Identifier, Literal};
 use std::borrow::Cow;
 
 mod display;
+mod document;
+mod flag;
+mod flag_parse;
+mod help_command;
 mod parse;
 mod sub;
+mod sub_command_info;
+mod values;
 
 mod new;
 mod to_tokens;
 
 pub use display::EnumVariantDisplay;
+pub use document::EnumVariantDocument;
+pub use flag::EnumFlag;
+pub use flag_parse::EnumVariantFlagParse;
+pub use help_command::EnumHelpCommand;
 pub use parse::EnumVariantParse;
 pub use sub::EnumVariantSub;
+pub use sub_command_info::EnumSubCommandInfo;
+pub use values::EnumValues;
 
 /// The output code for an enum
 pub struct EnumOutput<'a> {
@@ -23,9 +35,33 @@ pub struct EnumOutput<'a> {
     /// The string describing the expected values
     expected: Literal,
 
+    /// The values suggested when an unexpected value is passed
+    suggestions: EnumValues,
+
+    /// The values the enum accepts
+    values: EnumValues,
+
+    /// The match arm accepting the built-in "help" sub-command
+    help_parse: Option<EnumHelpCommand>,
+
     /// Produces sub-commands if a variant with one is matched
     subs: Vec<EnumVariantSub<'a>>,
 
+    /// The match arm starting the built-in "help" sub-command
+    help_sub: Option<EnumHelpCommand>,
+
+    /// The sub-commands suggested when an unknown sub-command is passed
+    sub_command_suggestions: EnumValues,
+
+    /// The elements of the array listing the sub-commands
+    sub_command_infos: Vec<EnumSubCommandInfo<'a>>,
+
+    /// Writes the documentation sections for the sub-commands
+    documents: Vec<EnumVariantDocument<'a>>,
+
     /// The match arms of the std::fmt::Display implementation
     displays: Vec<EnumVariantDisplay<'a>>,
+
+    /// The implementation of `Flag`, if the enum has no sub-commands
+    flag: Option<EnumFlag<'a>>,
 }
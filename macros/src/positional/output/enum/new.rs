@@ -1,4 +1,7 @@
-use super::{EnumOutput, EnumVariantDisplay, EnumVariantParse, EnumVariantSub};
+use super::{
+    EnumFlag, EnumHelpCommand, EnumOutput, EnumSubCommandInfo, EnumValues, EnumVariantDisplay,
+    EnumVariantDocument, EnumVariantParse, EnumVariantSub,
+};
 use proc_macro_util::tokens::{Identifier, Literal};
 use std::borrow::Cow;
 
@@ -8,15 +11,30 @@ impl<'a> EnumOutput<'a> {
         name: Cow<'a, Identifier>,
         parses: Vec<EnumVariantParse<'a>>,
         expected: Literal,
+        suggestions: EnumValues,
+        values: EnumValues,
         subs: Vec<EnumVariantSub<'a>>,
+        help_command: bool,
+        sub_command_suggestions: EnumValues,
+        sub_command_infos: Vec<EnumSubCommandInfo<'a>>,
+        documents: Vec<EnumVariantDocument<'a>>,
         displays: Vec<EnumVariantDisplay<'a>>,
+        flag: Option<EnumFlag<'a>>,
     ) -> Self {
         EnumOutput {
             name,
             parses,
             expected,
+            suggestions,
+            values,
+            help_parse: help_command.then_some(EnumHelpCommand::Parse),
             subs,
+            help_sub: help_command.then_some(EnumHelpCommand::Sub),
+            sub_command_suggestions,
+            sub_command_infos,
+            documents,
             displays,
+            flag,
         }
     }
 }
@@ -13,6 +13,7 @@ impl<'a> ToTokens for EnumVariantParse<'a> {
             Some(name) => {
                 to_tokens! { generator
                     {
+                        info.validate_value(&Self::#name)?;
                         *this = Some(Self::#name);
                         ::argparse::PositionalResult::Next
                     }
@@ -10,23 +10,13 @@ impl<'a> ToTokens for EnumVariantSub<'a> {
         } = self;
 
         to_tokens! { generator
-            #string => match <#r#type as ::argparse::Command>::parse(source, command_list)? {
-                Some(value) => {
-                    *this = Some(Self::#name(value));
-                    Ok(true)
-                }
-                None => Ok(false),
-            }
+            #string => Ok(<#r#type as ::argparse::Command>::parse(source, command_list)?
+                .map(|value| *this = Some(Self::#name(value)))),
         }
     }
 }
 
 /*
-"item2" => match <Command2 as ::argparse::Command>::parse(source, command_list)? {
-    Some(value) => {
-        *this = Some(Self::Item2(value));
-        Ok(true)
-    }
-    None => Ok(false),
-},
+"item2" => Ok(<Command2 as ::argparse::Command>::parse(source, command_list)?
+    .map(|value| *this = Some(Self::Item2(value)))),
 */
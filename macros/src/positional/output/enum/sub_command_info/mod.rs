@@ -0,0 +1,14 @@
+use crate::command::{Description, OptionalOutput};
+use proc_macro_util::tokens::Literal;
+
+mod new;
+mod to_tokens;
+
+/// Generates the element of the array listing the sub-commands of an enum for a variant
+pub struct EnumSubCommandInfo<'a> {
+    /// The literal which the variant matches on
+    name: Literal,
+
+    /// The description of the sub-command
+    description: OptionalOutput<Description<'a>>,
+}
@@ -0,0 +1,18 @@
+use super::EnumSubCommandInfo;
+use crate::command::Description;
+use proc_macro_util::{ast::Expression, tokens::Literal};
+
+impl<'a> EnumSubCommandInfo<'a> {
+    /// Creates a new [`EnumSubCommandInfo`]
+    pub fn new(name: Literal, docs: Vec<Expression<'a>>) -> Self {
+        EnumSubCommandInfo {
+            name,
+            description: if docs.is_empty() {
+                None
+            } else {
+                Some(Description::new(docs))
+            }
+            .into(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use super::EnumSubCommandInfo;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl<'a> ToTokens for EnumSubCommandInfo<'a> {
+    fn to_tokens(self, generator: &mut Generator) {
+        let EnumSubCommandInfo { name, description } = self;
+
+        to_tokens! { generator
+            ::argparse::SubCommandInfo {
+                name: #name,
+                description: #description,
+            },
+        }
+    }
+}
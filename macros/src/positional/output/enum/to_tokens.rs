@@ -7,8 +7,16 @@ impl<'a> ToTokens for EnumOutput<'a> {
             name,
             parses,
             expected,
+            suggestions,
+            values,
+            help_parse,
             subs,
+            help_sub,
+            sub_command_suggestions,
+            sub_command_infos,
+            documents,
             displays,
+            flag,
         } = self;
 
         let name2 = name.clone();
@@ -16,6 +24,8 @@ impl<'a> ToTokens for EnumOutput<'a> {
 
         to_tokens! { generator
             impl ::argparse::Positional for #name {
+                type Value = Self;
+
                 fn parse<'a>(
                     this: &mut Option<Self>,
                     argument: ::argparse::Argument<'a>,
@@ -23,12 +33,14 @@ impl<'a> ToTokens for EnumOutput<'a> {
                 ) -> ::argparse::PositionalResult<'a> {
                     match argument.as_str()? {
                         #parses
+                        #help_parse
                         argument => {
                             ::argparse::PositionalResult::Error(::argparse::Error::invalid_positional_value(
                                 info.value,
-                                ::argparse::UnexpectedError::new(
+                                ::argparse::UnexpectedError::among(
                                     argument,
                                     #expected,
+                                    &[#suggestions],
                                 ),
                             ))
                         }
@@ -39,13 +51,35 @@ impl<'a> ToTokens for EnumOutput<'a> {
                     this: &mut Option<Self>,
                     command: ::argparse::Argument,
                     source: &mut dyn ::argparse::ArgumentSource,
-                    command_list: String,
-                ) -> ::argparse::Result<bool> {
+                    command_list: ::argparse::CommandList,
+                ) -> ::argparse::Result<::argparse::ParseOutcome<()>> {
                     match command.as_str()? {
                         #subs
-                        _ => unimplemented!(),
+                        #help_sub
+                        command => Err(::argparse::Error::unknown_argument_among(
+                            command.to_string(),
+                            &[#sub_command_suggestions],
+                        )),
                     }
                 }
+
+                fn sub_commands() -> &'static [::argparse::SubCommandInfo] {
+                    const SUB_COMMANDS: &[::argparse::SubCommandInfo] = &[#sub_command_infos];
+                    SUB_COMMANDS
+                }
+
+                #[allow(unused_variables)]
+                fn write_document_sub_commands<W: ::argparse::DocumentWriter>(
+                    writer: &mut W,
+                    command_list: &::argparse::CommandList,
+                ) -> ::std::io::Result<()> {
+                    #documents
+                    Ok(())
+                }
+
+                fn value_hint(_: &::argparse::PositionalInfo<Self>) -> ::argparse::ValueHint {
+                    ::argparse::ValueHint::Values(&[#values])
+                }
             }
 
             impl ::argparse::DefaultDisplay for #name2 {
@@ -64,6 +98,8 @@ impl<'a> ToTokens for EnumOutput<'a> {
                     .fmt(f)
                 }
             }
+
+            #flag
         }
     }
 }
@@ -0,0 +1,10 @@
+use proc_macro_util::tokens::Literal;
+
+mod new;
+mod to_tokens;
+
+/// Generates the elements of the array listing the values an enum accepts
+pub struct EnumValues {
+    /// The literals which the variants match on
+    values: Vec<Literal>,
+}
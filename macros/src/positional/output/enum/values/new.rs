@@ -0,0 +1,9 @@
+use super::EnumValues;
+use proc_macro_util::tokens::Literal;
+
+impl EnumValues {
+    /// Creates a new [`EnumValues`]
+    pub fn new(values: Vec<Literal>) -> Self {
+        EnumValues { values }
+    }
+}
@@ -0,0 +1,14 @@
+use super::EnumValues;
+use proc_macro_util::{to_tokens, Generator, ToTokens};
+
+impl ToTokens for EnumValues {
+    fn to_tokens(self, generator: &mut Generator) {
+        let EnumValues { values } = self;
+
+        for value in values {
+            to_tokens! { generator
+                #value,
+            }
+        }
+    }
+}
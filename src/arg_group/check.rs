@@ -0,0 +1,21 @@
+use crate::{ArgGroup, Error, PassedFlag, Result};
+
+impl ArgGroup {
+    /// Checks that the number of members among the `passed` flags follows the rule of this group
+    pub fn check(&self, passed: &[PassedFlag]) -> Result<()> {
+        let count = passed
+            .iter()
+            .filter(|flag| self.members.contains(&flag.name))
+            .count();
+
+        if count == 0 && self.rule.requires_one() {
+            return Err(Error::missing_arg_group(self.members));
+        }
+
+        if count > 1 && !self.rule.allows_several() {
+            return Err(Error::conflicting_arg_group(self.members));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+mod check;
+mod rule;
+mod write_help;
+
+pub use rule::ArgGroupRule;
+
+/// A group of flags with a rule on how many of them can be passed together
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgGroup {
+    /// How many of the members must or can be passed
+    pub rule: ArgGroupRule,
+
+    /// The names of the flags in the group, which are their long names if they have one
+    pub members: &'static [&'static str],
+}
@@ -0,0 +1,11 @@
+use super::ArgGroupRule;
+
+impl ArgGroupRule {
+    /// Does this rule allow more than one of the flags to be passed?
+    pub fn allows_several(self) -> bool {
+        match self {
+            ArgGroupRule::AtLeastOne => true,
+            ArgGroupRule::ExactlyOne | ArgGroupRule::AtMostOne => false,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+mod allows_several;
+mod requires_one;
+
+/// The number of flags in an [`ArgGroup`](crate::ArgGroup) which must or can be passed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgGroupRule {
+    /// Exactly one of the flags must be passed
+    ExactlyOne,
+
+    /// One or more of the flags must be passed
+    AtLeastOne,
+
+    /// No more than one of the flags can be passed
+    AtMostOne,
+}
@@ -0,0 +1,11 @@
+use super::ArgGroupRule;
+
+impl ArgGroupRule {
+    /// Does this rule fail when none of the flags are passed?
+    pub fn requires_one(self) -> bool {
+        match self {
+            ArgGroupRule::ExactlyOne | ArgGroupRule::AtLeastOne => true,
+            ArgGroupRule::AtMostOne => false,
+        }
+    }
+}
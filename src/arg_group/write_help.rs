@@ -0,0 +1,15 @@
+use crate::{
+    messages::{messages, DisplayWith},
+    ArgGroup, TextWrap,
+};
+use std::io::Write;
+
+impl ArgGroup {
+    /// Writes the note describing the rule of this group on its own line to `output`, wrapping
+    /// it with `wrap`
+    pub fn write_help(&self, output: &mut dyn Write, wrap: TextWrap) -> std::io::Result<()> {
+        let note = DisplayWith(|f| messages().arg_group(f, self.rule, self.members)).to_string();
+        wrap.write(output, &note, 0)?;
+        writeln!(output)
+    }
+}
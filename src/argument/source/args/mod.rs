@@ -7,4 +7,7 @@ mod source;
 pub struct ArgsSource {
     /// The set of arguments
     args: Args,
+
+    /// The number of arguments taken so far
+    taken: usize,
 }
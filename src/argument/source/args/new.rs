@@ -5,6 +5,6 @@ impl ArgsSource {
     pub fn new() -> Self {
         let args = std::env::args();
 
-        ArgsSource { args }
+        ArgsSource { args, taken: 0 }
     }
 }
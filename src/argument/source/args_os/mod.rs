@@ -7,4 +7,7 @@ mod source;
 pub struct ArgsOsSource {
     /// The set of arguments
     args: ArgsOs,
+
+    /// The number of arguments taken so far
+    taken: usize,
 }
@@ -5,6 +5,6 @@ impl ArgsOsSource {
     pub fn new() -> Self {
         let args = std::env::args_os();
 
-        ArgsOsSource { args }
+        ArgsOsSource { args, taken: 0 }
     }
 }
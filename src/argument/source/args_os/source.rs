@@ -2,10 +2,16 @@ use crate::{ArgsOsSource, Argument, ArgumentSource};
 
 impl<'a> ArgumentSource<'a> for ArgsOsSource {
     fn next(&mut self) -> Option<Argument<'a>> {
-        self.args.next().map(Into::into)
+        let argument = self.args.next()?;
+        self.taken += 1;
+        Some(argument.into())
     }
 
     fn empty(&self) -> bool {
         self.args.len() == 0
     }
+
+    fn index(&self) -> Option<usize> {
+        self.taken.checked_sub(1)
+    }
 }
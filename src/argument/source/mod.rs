@@ -2,6 +2,7 @@ use crate::Argument;
 
 mod args;
 mod args_os;
+mod vec;
 
 pub use args::ArgsSource;
 pub use args_os::ArgsOsSource;
@@ -13,4 +14,9 @@ pub trait ArgumentSource<'a> {
 
     /// Are there no more arguments?
     fn empty(&self) -> bool;
+
+    /// Get the index of the argument most recently taken from the source, if the source tracks it
+    fn index(&self) -> Option<usize> {
+        None
+    }
 }
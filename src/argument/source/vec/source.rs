@@ -0,0 +1,12 @@
+use crate::{Argument, ArgumentSource};
+use std::vec::IntoIter;
+
+impl<'a> ArgumentSource<'a> for IntoIter<Argument<'a>> {
+    fn next(&mut self) -> Option<Argument<'a>> {
+        Iterator::next(self)
+    }
+
+    fn empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{ColorChoice, UnexpectedError};
+
+impl std::str::FromStr for ColorChoice {
+    type Err = UnexpectedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(UnexpectedError::among(
+                s,
+                "\"auto\", \"always\" or \"never\"",
+                &["auto", "always", "never"],
+            )),
+        }
+    }
+}
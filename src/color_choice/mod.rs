@@ -0,0 +1,18 @@
+mod display;
+mod flag;
+mod from_str;
+mod parse_flag;
+
+/// When to color output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Color output if it is going to a terminal, respecting `NO_COLOR` and `CLICOLOR_FORCE`
+    #[default]
+    Auto,
+
+    /// Always color output
+    Always,
+
+    /// Never color output
+    Never,
+}
@@ -0,0 +1,10 @@
+use crate::{ArgumentSource, ColorChoice, Flag, FlagInfo, Result};
+
+impl ColorChoice {
+    /// Parses the value of the built-in "--color" flag from `source`
+    pub fn parse_flag(source: &mut dyn ArgumentSource) -> Result<Self> {
+        let mut choice = None;
+        ColorChoice::parse(&mut choice, source, &FlagInfo::color(), true)?;
+        Ok(choice.unwrap())
+    }
+}
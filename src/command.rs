@@ -1,22 +1,354 @@
-use crate::{ArgsOsSource, ArgumentSource, Result};
+use crate::{
+    completion_writer::COMPLETE_ARGUMENT,
+    messages::{messages, DisplayWith},
+    usage_writer::UsageWriter,
+    ArgsOsSource, ArgumentSource, CommandList, CommandSpec, CompletionWriter, DocumentWriter,
+    HelpMode, ManWriter, MarkdownWriter, ParseOutcome, Result, Shell, SpecWriter, Style, TextWrap,
+};
+use std::io::Write;
 
 /// A command which can be parsed from an [`ArgumentSource`]
 pub trait Command: Sized {
     /// Parse this command from `source`
     ///
-    /// Returns [`None`] if a flag or argument triggers an output without result, like help or version
-    fn parse(source: &mut dyn ArgumentSource, command_list: String) -> Result<Option<Self>>;
+    /// `command_list` contains the names of the program and sub-commands which lead to this
+    /// command, used for displaying usage.
+    ///
+    /// Returns [`ParseOutcome::Help`] or [`ParseOutcome::Version`] with the text to display if a
+    /// flag or argument triggers an output without result
+    ///
+    /// Errors are returned as [`Error::InCommand`](crate::Error::InCommand), naming the command
+    /// or sub-command being parsed when they occurred.
+    fn parse(
+        source: &mut dyn ArgumentSource,
+        command_list: CommandList,
+    ) -> Result<ParseOutcome<Self>>;
+
+    /// Write the description of this command to `output`, indenting new lines by `margin`
+    ///
+    /// The default implementation writes nothing.
+    #[allow(unused_variables)]
+    fn write_description(output: &mut dyn Write, margin: usize) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Write the extended description of this command to `output`, indenting new lines by `margin`
+    ///
+    /// This follows the description in long help and documentation. The default implementation
+    /// writes nothing.
+    #[allow(unused_variables)]
+    fn write_long_description(output: &mut dyn Write, margin: usize) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Write the usage of this command to `output`, styling flag names with `style`
+    ///
+    /// The default implementation writes nothing.
+    #[allow(unused_variables)]
+    fn write_usage(
+        output: &mut dyn Write,
+        command_list: &CommandList,
+        style: Style,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Write the help for this command to `output`, wrapping descriptions with `wrap`, styling
+    /// headers and flag names with `style` and including extended descriptions, examples, the
+    /// epilogue and hidden items if `mode` shows them
+    ///
+    /// Commands without a help flag write nothing.
+    #[allow(unused_variables)]
+    fn write_help(
+        output: &mut dyn Write,
+        command_list: &CommandList,
+        wrap: TextWrap,
+        style: Style,
+        mode: HelpMode,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Write the version of this command to `output`
+    ///
+    /// Commands without a version flag write nothing.
+    #[allow(unused_variables)]
+    fn write_version(output: &mut dyn Write) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Write the long version of this command to `output`, which follows the version with any
+    /// build details like the commit or target
+    ///
+    /// Commands without build details write the version.
+    fn write_long_version(output: &mut dyn Write) -> std::io::Result<()> {
+        Self::write_version(output)
+    }
+
+    /// Write the documentation describing this command and its sub-commands to `writer`
+    ///
+    /// Derived commands write their documentation whether or not they have a help flag. The default
+    /// implementation writes nothing.
+    #[allow(unused_variables)]
+    fn write_document<W: DocumentWriter>(
+        writer: &mut W,
+        command_list: &CommandList,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Write a roff man page for this command to `output`
+    fn write_man(output: &mut dyn Write) -> std::io::Result<()> {
+        Self::write_document(&mut ManWriter::new(output), &CommandList::new())
+    }
+
+    /// Write Markdown reference documentation for this command to `output`
+    fn write_markdown(output: &mut dyn Write) -> std::io::Result<()> {
+        Self::write_document(&mut MarkdownWriter::new(output), &CommandList::new())
+    }
+
+    /// Write the spec of this command and its sub-commands to `output` as a JSON document, as
+    /// described in [`CommandSpec::write_json`]
+    fn write_json(output: &mut dyn Write) -> std::io::Result<()> {
+        Self::spec().write_json(output)
+    }
+
+    /// Write a completion script for `shell` covering this command and its sub-commands to
+    /// `output`
+    fn write_completion(output: &mut dyn Write, shell: Shell) -> std::io::Result<()> {
+        let mut writer = CompletionWriter::new();
+        Self::write_document(&mut writer, &CommandList::new())?;
+        writer.write(output, shell)
+    }
+
+    /// Write a completion script for `shell` to `output` which asks the program for candidates
+    /// through [`Command::parse_env`] each time a completion is requested
+    ///
+    /// The requests are only answered if [`Command::dynamic_completion_enabled`] is true.
+    fn write_dynamic_completion(output: &mut dyn Write, shell: Shell) -> std::io::Result<()> {
+        let mut writer = CompletionWriter::new();
+        Self::write_document(&mut writer, &CommandList::new())?;
+        writer.write_dynamic(output, shell)
+    }
+
+    /// Get the candidates for completing `words[index]`, where `words` is the command line
+    /// starting with the program name
+    ///
+    /// Candidates come from the flags, sub-commands and values of this command, and from the
+    /// completer functions of its flags and positionals, as written by [`Command::write_document`].
+    fn complete(words: &[&str], index: usize) -> Vec<String> {
+        let mut writer = CompletionWriter::new();
+        Self::write_document(&mut writer, &CommandList::new()).unwrap();
+        writer.candidates(words, index)
+    }
+
+    /// The program name set by this command, replacing the name taken from the first argument
+    ///
+    /// Derived commands return the name given with `command(name = ...)`. The default is [`None`].
+    fn program_name() -> Option<&'static str> {
+        None
+    }
+
+    /// Does [`Command::parse_env`] answer requests from dynamic completion scripts
+    ///
+    /// Derived commands enable this with `command(dynamic_completion)`. The default is false, so
+    /// "__complete" is parsed like any other argument.
+    fn dynamic_completion_enabled() -> bool {
+        false
+    }
+
+    /// Get the candidates asked for by a dynamic completion script, if `arguments` is a request
+    /// and [`Command::dynamic_completion_enabled`] is true
+    ///
+    /// `arguments` follow the program name. A request starts with "__complete", followed by the
+    /// index of the argument to complete and the command line, as passed to [`Command::complete`].
+    fn complete_request(arguments: &[&str]) -> Option<Vec<String>> {
+        if !Self::dynamic_completion_enabled() || arguments.first() != Some(&COMPLETE_ARGUMENT) {
+            return None;
+        }
+
+        let index = arguments
+            .get(1)
+            .and_then(|index| index.parse().ok())
+            .unwrap_or(0);
+        let words = arguments.get(2..).unwrap_or_default();
+        Some(Self::complete(words, index))
+    }
+
+    /// Get the description of this command, its arguments and its sub-commands as data, as written
+    /// by [`Command::write_document`]
+    fn spec() -> CommandSpec {
+        let mut writer = SpecWriter::new();
+        Self::write_document(&mut writer, &CommandList::new()).unwrap();
+        writer.finish()
+    }
+
+    /// Get the help for this command as a string, wrapped to the width of the terminal and styled
+    /// following the color choice of `command_list`, including extended descriptions and hidden
+    /// items if `mode` shows them
+    fn help(command_list: &CommandList, mode: HelpMode) -> String {
+        let mut output = Vec::new();
+        Self::write_help(
+            &mut output,
+            command_list,
+            TextWrap::detect(),
+            Style::choose(command_list.color(), &std::io::stdout()),
+            mode,
+        )
+        .unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// Get the version of this command as a string
+    fn version() -> String {
+        let mut output = Vec::new();
+        Self::write_version(&mut output).unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// Get the long version of this command as a string
+    fn long_version() -> String {
+        let mut output = Vec::new();
+        Self::write_long_version(&mut output).unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// Get a roff man page for this command as a string
+    fn man() -> String {
+        let mut output = Vec::new();
+        Self::write_man(&mut output).unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// Get Markdown reference documentation for this command as a string
+    fn markdown() -> String {
+        let mut output = Vec::new();
+        Self::write_markdown(&mut output).unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// Get the spec of this command as a JSON document
+    fn json() -> String {
+        let mut output = Vec::new();
+        Self::write_json(&mut output).unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// Get a completion script for `shell` covering this command as a string
+    fn completion(shell: Shell) -> String {
+        let mut output = Vec::new();
+        Self::write_completion(&mut output, shell).unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// Get a dynamic completion script for `shell` covering this command as a string
+    fn dynamic_completion(shell: Shell) -> String {
+        let mut output = Vec::new();
+        Self::write_dynamic_completion(&mut output, shell).unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
 
     /// Parse this command from the environment arguments
     ///
+    /// The program name is taken from the basename of the first argument. Help and version
+    /// output is printed to stdout.
+    ///
+    /// If [`Command::dynamic_completion_enabled`] is true and the first argument is "__complete",
+    /// the arguments are instead treated as a request from a dynamic completion script, as
+    /// described in [`Command::complete_request`]. The candidates are printed to stdout one per
+    /// line.
+    ///
     /// Returns [`None`] if a flag or argument triggers an output without result, like help or version
     fn parse_env() -> Result<Option<Self>> {
+        if Self::dynamic_completion_enabled()
+            && std::env::args_os().nth(1).as_deref() == Some(COMPLETE_ARGUMENT.as_ref())
+        {
+            let arguments: Vec<String> = std::env::args_os()
+                .skip(1)
+                .map(|argument| argument.to_string_lossy().into_owned())
+                .collect();
+            let arguments: Vec<&str> = arguments.iter().map(String::as_str).collect();
+
+            let mut stdout = std::io::stdout();
+            for candidate in Self::complete_request(&arguments).unwrap_or_default() {
+                writeln!(stdout, "{}", candidate)?;
+            }
+            return Ok(None);
+        }
+
         let mut source = ArgsOsSource::new();
         let command_list = match source.next() {
-            Some(first_arg) => format!("{} ", first_arg),
-            None => String::new(),
+            Some(first_arg) => CommandList::from_first_argument(first_arg),
+            None => CommandList::new(),
+        };
+
+        match Self::parse(&mut source, command_list)? {
+            ParseOutcome::Parsed(value) => Ok(Some(value)),
+            ParseOutcome::Help(output) | ParseOutcome::Version(output) => {
+                std::io::stdout().write_all(output.as_bytes())?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Parse this command from the environment arguments like [`Command::parse_env`], exiting the
+    /// process instead of returning when there is no value
+    ///
+    /// Errors are printed to stderr, followed by the usage of the sub-command they occurred in, even
+    /// a hidden one, and, if it has a help flag, a hint to use it like "tool build --help",
+    /// exiting with [`Error::exit_code`](crate::Error::exit_code). Help, version and completion
+    /// output exits with 0.
+    fn parse_or_exit() -> Self {
+        let error = match Self::parse_env() {
+            Ok(Some(value)) => return value,
+            Ok(None) => std::process::exit(0),
+            Err(error) => error,
+        };
+
+        let command_list = match (error.command_list(), ArgsOsSource::new().next()) {
+            (Some(command_list), _) => command_list.clone(),
+            (None, first_arg) => {
+                let mut command_list = first_arg
+                    .map(CommandList::from_first_argument)
+                    .unwrap_or_default();
+                if let Some(name) = Self::program_name() {
+                    command_list.set_program_name(name);
+                }
+                command_list
+            }
         };
 
-        Self::parse(&mut source, command_list)
+        let style = Style::choose(command_list.color(), &std::io::stderr());
+        let mut usage = Vec::new();
+        let mut usage_writer = UsageWriter::new(&mut usage, &command_list, style);
+        let _ = Self::write_document(&mut usage_writer, &CommandList::new());
+        let has_help = usage_writer.has_help();
+
+        let mut stderr = std::io::stderr().lock();
+        let _ = writeln!(stderr, "{}", error.report(style));
+        if !usage.is_empty() {
+            let _ = writeln!(
+                stderr,
+                "\n{}\n    {}",
+                style.header(messages().usage_header()),
+                String::from_utf8_lossy(&usage).trim_end(),
+            );
+        }
+
+        if has_help {
+            let help_flag = if command_list.is_empty() {
+                "--help".to_owned()
+            } else {
+                format!("{} --help", command_list)
+            };
+
+            let _ = writeln!(
+                stderr,
+                "\n{}",
+                DisplayWith(|f| messages().help_hint(f, &help_flag))
+            );
+        }
+
+        std::process::exit(error.exit_code())
     }
 }
@@ -0,0 +1,18 @@
+use crate::CommandList;
+
+impl std::fmt::Display for CommandList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for name in self.program.iter().chain(self.sub_commands.iter()) {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" ")?;
+            }
+
+            name.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{ColorChoice, CommandList};
+
+impl CommandList {
+    /// Gets the name of the program, if there is one
+    pub fn program(&self) -> Option<&str> {
+        self.program.as_deref()
+    }
+
+    /// Gets the names of the sub-commands, in the order they were given
+    pub fn sub_commands(&self) -> &[String] {
+        &self.sub_commands
+    }
+
+    /// Gets when to color output, as chosen by the built-in "--color" flag
+    pub fn color(&self) -> ColorChoice {
+        self.color
+    }
+
+    /// Is this list for the top-level command?
+    pub fn is_root(&self) -> bool {
+        self.sub_commands.is_empty()
+    }
+
+    /// Are there no names in this list?
+    pub fn is_empty(&self) -> bool {
+        self.program.is_none() && self.sub_commands.is_empty()
+    }
+}
@@ -0,0 +1,22 @@
+mod display;
+mod get;
+mod new;
+mod parent;
+mod program_name;
+mod set_color;
+mod sub_command;
+
+use crate::ColorChoice;
+
+/// The names of the program and sub-commands leading to the command currently being parsed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandList {
+    /// The name of the program
+    program: Option<String>,
+
+    /// The names of the sub-commands, in the order they were given
+    sub_commands: Vec<String>,
+
+    /// When to color output, as chosen by the built-in "--color" flag
+    color: ColorChoice,
+}
@@ -0,0 +1,43 @@
+use crate::{Argument, ColorChoice, CommandList};
+use std::path::Path;
+
+impl CommandList {
+    /// Creates a new empty [`CommandList`]
+    pub fn new() -> Self {
+        CommandList {
+            program: None,
+            sub_commands: Vec::new(),
+            color: ColorChoice::Auto,
+        }
+    }
+
+    /// Creates a new [`CommandList`] with `program` as the program name
+    pub fn with_program<S: Into<String>>(program: S) -> Self {
+        CommandList {
+            program: Some(program.into()),
+            sub_commands: Vec::new(),
+            color: ColorChoice::Auto,
+        }
+    }
+
+    /// Creates a new [`CommandList`] from the first argument given to a program, using the
+    /// basename of the path as the program name
+    ///
+    /// Invalid UTF-8 in the name is replaced lossily
+    pub fn from_first_argument(argument: Argument) -> Self {
+        let program = match &argument {
+            Argument::OsStr(os_str) => Path::new(&**os_str)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+            Argument::Str(str) => Path::new(&**str)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+        };
+
+        CommandList {
+            program: program.or_else(|| Some(argument.to_string())),
+            sub_commands: Vec::new(),
+            color: ColorChoice::Auto,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::CommandList;
+
+impl CommandList {
+    /// Creates a new [`CommandList`] for the command containing the current sub-command
+    ///
+    /// The list is returned unchanged if it has no sub-commands
+    pub fn parent(&self) -> Self {
+        let mut sub_commands = self.sub_commands.clone();
+        sub_commands.pop();
+
+        CommandList {
+            program: self.program.clone(),
+            sub_commands,
+            color: self.color,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::CommandList;
+
+impl CommandList {
+    /// Replaces the program name with `name` if this list is for the top-level command
+    pub fn set_program_name<S: Into<String>>(&mut self, name: S) {
+        if self.is_root() {
+            self.program = Some(name.into());
+        }
+    }
+}
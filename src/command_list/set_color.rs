@@ -0,0 +1,8 @@
+use crate::{ColorChoice, CommandList};
+
+impl CommandList {
+    /// Sets when to color output, passed on to the sub-commands created from this list
+    pub fn set_color(&mut self, color: ColorChoice) {
+        self.color = color;
+    }
+}
@@ -0,0 +1,15 @@
+use crate::CommandList;
+
+impl CommandList {
+    /// Creates a new [`CommandList`] for the sub-command `name` of the current command
+    pub fn sub_command<S: Into<String>>(&self, name: S) -> Self {
+        let mut sub_commands = self.sub_commands.clone();
+        sub_commands.push(name.into());
+
+        CommandList {
+            program: self.program.clone(),
+            sub_commands,
+            color: self.color,
+        }
+    }
+}
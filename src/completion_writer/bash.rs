@@ -0,0 +1,141 @@
+use super::{quote, CompletionFlag};
+use crate::{CompletionWriter, ValueHint};
+use std::io::Write;
+
+impl CompletionWriter {
+    /// Writes the completion script for bash to `output`
+    pub(super) fn write_bash(&self, output: &mut dyn Write) -> std::io::Result<()> {
+        let function_name = self.function_name();
+
+        writeln!(output, "{}() {{", function_name)?;
+        writeln!(output, "    local cur=\"${{COMP_WORDS[COMP_CWORD]}}\"")?;
+        writeln!(output, "    local prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"")?;
+        writeln!(
+            output,
+            "    local command={} positional=0 skip=0 i word",
+            quote(self.program())
+        )?;
+        writeln!(output)?;
+
+        // Find the command being completed and the index of the positional under the cursor
+        writeln!(output, "    for ((i = 1; i < COMP_CWORD; i++)); do")?;
+        writeln!(output, "        word=\"${{COMP_WORDS[i]}}\"")?;
+        writeln!(output, "        if ((skip)); then")?;
+        writeln!(output, "            skip=0")?;
+        writeln!(output, "            continue")?;
+        writeln!(output, "        fi")?;
+        writeln!(output)?;
+        writeln!(output, "        case \"${{command}}\" in")?;
+        for command in &self.commands {
+            writeln!(output, "        {})", quote(&command.key()))?;
+            writeln!(output, "            case \"${{word}}\" in")?;
+
+            let value_flags: Vec<_> = command
+                .flags
+                .iter()
+                .filter(|flag| flag.takes_value)
+                .map(pattern)
+                .collect();
+            if !value_flags.is_empty() {
+                writeln!(output, "            {}) skip=1 ;;", value_flags.join("|"))?;
+            }
+
+            for sub_command in &command.sub_commands {
+                writeln!(
+                    output,
+                    "            {}) command={}; positional=0 ;;",
+                    quote(sub_command),
+                    quote(&format!("{} {}", command.key(), sub_command))
+                )?;
+            }
+
+            writeln!(output, "            -*) ;;")?;
+            writeln!(output, "            *) positional=$((positional + 1)) ;;")?;
+            writeln!(output, "            esac")?;
+            writeln!(output, "            ;;")?;
+        }
+        writeln!(output, "        esac")?;
+        writeln!(output, "    done")?;
+        writeln!(output)?;
+
+        writeln!(output, "    case \"${{command}}\" in")?;
+        for command in &self.commands {
+            writeln!(output, "    {})", quote(&command.key()))?;
+
+            let value_flags: Vec<_> = command
+                .flags
+                .iter()
+                .filter(|flag| flag.takes_value)
+                .collect();
+            if !value_flags.is_empty() {
+                writeln!(output, "        case \"${{prev}}\" in")?;
+                for flag in value_flags {
+                    writeln!(
+                        output,
+                        "        {}) {}; return ;;",
+                        pattern(flag),
+                        reply(flag.hint)
+                    )?;
+                }
+                writeln!(output, "        esac")?;
+            }
+
+            let names: Vec<_> = command
+                .flags
+                .iter()
+                .filter(|flag| !flag.hidden)
+                .flat_map(|flag| flag.names())
+                .collect();
+            writeln!(output, "        if [[ \"${{cur}}\" == -* ]]; then")?;
+            writeln!(
+                output,
+                "            COMPREPLY=($(compgen -W {} -- \"${{cur}}\"))",
+                quote(&names.join(" "))
+            )?;
+            writeln!(output, "            return")?;
+            writeln!(output, "        fi")?;
+
+            if !command.positionals.is_empty() {
+                writeln!(output, "        case \"${{positional}}\" in")?;
+                for (i, positional) in command.positionals.iter().enumerate() {
+                    if positional.multiple {
+                        writeln!(output, "        *) {} ;;", reply(positional.hint))?;
+                        break;
+                    }
+
+                    writeln!(output, "        {}) {} ;;", i, reply(positional.hint))?;
+                }
+                writeln!(output, "        esac")?;
+            }
+
+            writeln!(output, "        ;;")?;
+        }
+        writeln!(output, "    esac")?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+
+        writeln!(
+            output,
+            "complete -o filenames -F {} {}",
+            function_name,
+            quote(self.program())
+        )
+    }
+}
+
+/// Gets the case pattern matching the names of `flag`
+fn pattern(flag: &CompletionFlag) -> String {
+    flag.names().map(quote).collect::<Vec<_>>().join("|")
+}
+
+/// Gets the command setting the completions for a value described by `hint`
+fn reply(hint: ValueHint) -> String {
+    match hint {
+        ValueHint::Any | ValueHint::Integer | ValueHint::Float => "COMPREPLY=()".to_owned(),
+        ValueHint::Path => "COMPREPLY=($(compgen -f -- \"${cur}\"))".to_owned(),
+        ValueHint::Values(values) => format!(
+            "COMPREPLY=($(compgen -W {} -- \"${{cur}}\"))",
+            quote(&values.join(" "))
+        ),
+    }
+}
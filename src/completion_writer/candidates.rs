@@ -0,0 +1,138 @@
+use super::{CompletionCommand, CompletionFlag};
+use crate::{CompletionWriter, ValueHint};
+use std::path::Path;
+
+impl CompletionWriter {
+    /// Gets the candidates for completing `words[index]`, where `words` is the command line
+    /// starting with the program name
+    ///
+    /// `index` may be `words.len()` when completing a new, empty argument.
+    pub fn candidates(&self, words: &[&str], index: usize) -> Vec<String> {
+        let mut command = match self.commands.first() {
+            Some(command) => command,
+            None => return Vec::new(),
+        };
+
+        let current = words.get(index).copied().unwrap_or("");
+        let mut positional = 0;
+        let mut value_flag: Option<&CompletionFlag> = None;
+        for word in words.iter().take(index).skip(1) {
+            if value_flag.take().is_some() {
+                continue;
+            }
+
+            if let Some(flag) = command
+                .flags
+                .iter()
+                .find(|flag| flag.names().any(|name| name == *word))
+            {
+                if flag.takes_value {
+                    value_flag = Some(flag);
+                }
+            } else if let Some(sub_command) = self.sub_command(command, word) {
+                command = sub_command;
+                positional = 0;
+            } else if !word.starts_with('-') {
+                positional += 1;
+            }
+        }
+
+        let mut candidates = if let Some(flag) = value_flag {
+            values(flag.hint, flag.complete, current)
+        } else if current.starts_with('-') {
+            command
+                .flags
+                .iter()
+                .filter(|flag| !flag.hidden)
+                .flat_map(|flag| flag.names())
+                .map(str::to_owned)
+                .collect()
+        } else {
+            let first_positional = positional == 0;
+
+            // A positional taking multiple values takes all of the arguments after it
+            let positional = command
+                .positionals
+                .iter()
+                .take(positional + 1)
+                .find(|positional| positional.multiple)
+                .or_else(|| command.positionals.get(positional));
+
+            let mut candidates = match positional {
+                Some(positional) => values(positional.hint, positional.complete, current),
+                None => Vec::new(),
+            };
+
+            // Sub-commands built into the command, like "version", only have a section
+            if first_positional {
+                for sub_command in &command.sub_commands {
+                    if !candidates.contains(sub_command) {
+                        candidates.push(sub_command.clone());
+                    }
+                }
+            }
+
+            candidates
+        };
+
+        candidates.retain(|candidate| candidate.starts_with(current));
+        candidates
+    }
+
+    /// Gets the sub-command of `command` named `name`
+    fn sub_command(&self, command: &CompletionCommand, name: &str) -> Option<&CompletionCommand> {
+        if !command
+            .sub_commands
+            .iter()
+            .any(|sub_command| sub_command == name)
+        {
+            return None;
+        }
+
+        self.commands.iter().find(|sub_command| {
+            sub_command.names.len() == command.names.len() + 1
+                && sub_command.names.starts_with(&command.names)
+                && sub_command.names.last().map(String::as_str) == Some(name)
+        })
+    }
+}
+
+/// Gets the candidates for a value described by `hint`, preferring the results of `complete`
+fn values(
+    hint: ValueHint,
+    complete: Option<fn(&str) -> Vec<String>>,
+    current: &str,
+) -> Vec<String> {
+    if let Some(complete) = complete {
+        return complete(current);
+    }
+
+    match hint {
+        ValueHint::Any | ValueHint::Integer | ValueHint::Float => Vec::new(),
+        ValueHint::Path => paths(current),
+        ValueHint::Values(values) => values.iter().map(|value| value.to_string()).collect(),
+    }
+}
+
+/// Gets the paths in the directory `current` is in, hiding hidden files unless `current` names
+/// one
+fn paths(current: &str) -> Vec<String> {
+    let (directory, prefix) = match current.rfind('/') {
+        Some(index) => current.split_at(index + 1),
+        None => ("", current),
+    };
+
+    let entries = match Path::new(if directory.is_empty() { "." } else { directory }).read_dir() {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut paths: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| prefix.starts_with('.') || !name.starts_with('.'))
+        .map(|name| format!("{}{}", directory, name))
+        .collect();
+    paths.sort();
+    paths
+}
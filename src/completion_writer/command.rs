@@ -0,0 +1,24 @@
+use super::{CompletionFlag, CompletionPositional};
+
+/// A command which completions are written for
+pub(super) struct CompletionCommand {
+    /// The names of the program and sub-commands leading to this command
+    pub(super) names: Vec<String>,
+
+    /// The flags this command accepts
+    pub(super) flags: Vec<CompletionFlag>,
+
+    /// The positionals this command accepts, in order
+    pub(super) positionals: Vec<CompletionPositional>,
+
+    /// The names of the sub-commands of this command
+    pub(super) sub_commands: Vec<String>,
+}
+
+impl CompletionCommand {
+    /// Gets the names leading to this command separated by spaces, which identifies it in the
+    /// scripts
+    pub(super) fn key(&self) -> String {
+        self.names.join(" ")
+    }
+}
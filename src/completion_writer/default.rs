@@ -0,0 +1,7 @@
+use crate::CompletionWriter;
+
+impl Default for CompletionWriter {
+    fn default() -> Self {
+        CompletionWriter::new()
+    }
+}
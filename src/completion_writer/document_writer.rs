@@ -0,0 +1,95 @@
+use super::{CompletionCommand, CompletionFlag, CompletionPositional};
+use crate::{
+    Command, CommandList, CompletionWriter, DocumentWriter, Flag, FlagInfo, Positional,
+    PositionalInfo, ValueHint,
+};
+
+impl DocumentWriter for CompletionWriter {
+    /// Sub-commands are registered with the command leading to them
+    fn command<C: Command>(
+        &mut self,
+        name: &str,
+        command_list: &CommandList,
+    ) -> std::io::Result<CommandList> {
+        let command_list = match command_list.program() {
+            None if command_list.is_root() => CommandList::with_program(name),
+            _ => command_list.clone(),
+        };
+
+        let names: Vec<String> = command_list
+            .program()
+            .map(str::to_owned)
+            .into_iter()
+            .chain(command_list.sub_commands().iter().cloned())
+            .collect();
+
+        if let Some((name, parent_names)) = names.split_last() {
+            if let Some(parent) = self
+                .commands
+                .iter_mut()
+                .find(|command| command.names == parent_names)
+            {
+                parent.sub_commands.push(name.clone());
+            }
+        }
+
+        self.commands.push(CompletionCommand {
+            names,
+            flags: Vec::new(),
+            positionals: Vec::new(),
+            sub_commands: Vec::new(),
+        });
+
+        Ok(command_list)
+    }
+
+    /// Sections have no effect on completions
+    fn section(&mut self, _: &str) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn positional<T: Positional>(&mut self, info: &PositionalInfo<T>) -> std::io::Result<()> {
+        if let Some(command) = self.commands.last_mut() {
+            command.positionals.push(CompletionPositional {
+                hint: if info.hidden {
+                    ValueHint::Any
+                } else {
+                    T::value_hint(info)
+                },
+                multiple: T::multiple(info),
+                complete: if info.hidden { None } else { info.complete },
+            });
+        }
+
+        Ok(())
+    }
+
+    fn flag<T: Flag>(&mut self, info: &FlagInfo<T>) -> std::io::Result<()> {
+        let description = match info.description {
+            Some(description) => {
+                let mut buffer = Vec::new();
+                description(&mut buffer, 0)?;
+                String::from_utf8_lossy(&buffer)
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(str::to_owned)
+            }
+            None => None,
+        };
+
+        if let Some(command) = self.commands.last_mut() {
+            command.flags.push(CompletionFlag {
+                short_name: info.short_name,
+                long_name: info.long_name,
+                takes_value: T::takes_value(info),
+                hint: T::value_hint(info),
+                description,
+                complete: info.complete,
+                hidden: info.hidden,
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use super::{quote, quote_fish, COMPLETE_ARGUMENT};
+use crate::{CompletionWriter, Shell};
+use std::io::Write;
+
+impl CompletionWriter {
+    /// Writes a completion script for `shell` to `output` which asks the program for candidates
+    /// each time a completion is requested
+    ///
+    /// Unlike [`CompletionWriter::write`], this allows candidates which depend on the state of the
+    /// system, listed by completer functions. Nothing is written if no commands have been
+    /// described.
+    pub fn write_dynamic(&self, output: &mut dyn Write, shell: Shell) -> std::io::Result<()> {
+        if self.commands.is_empty() {
+            return Ok(());
+        }
+
+        let function_name = self.function_name();
+        match shell {
+            Shell::Bash => {
+                writeln!(output, "{}() {{", function_name)?;
+                writeln!(output, "    local IFS=$'\\n'")?;
+                writeln!(
+                    output,
+                    "    COMPREPLY=($(\"${{COMP_WORDS[0]}}\" {} \"${{COMP_CWORD}}\" \"${{COMP_WORDS[@]}}\"))",
+                    COMPLETE_ARGUMENT
+                )?;
+                writeln!(output, "}}")?;
+                writeln!(output)?;
+                writeln!(
+                    output,
+                    "complete -o filenames -F {} {}",
+                    function_name,
+                    quote(self.program())
+                )
+            }
+            Shell::Zsh => {
+                writeln!(output, "#compdef {}", self.program())?;
+                writeln!(output)?;
+                writeln!(output, "{}() {{", function_name)?;
+                writeln!(output, "    local -a candidates")?;
+                writeln!(
+                    output,
+                    "    candidates=(${{(f)\"$(\"${{words[1]}}\" {} \"$((CURRENT - 1))\" \"${{words[@]}}\")\"}})",
+                    COMPLETE_ARGUMENT
+                )?;
+                writeln!(output, "    compadd -f -- \"${{candidates[@]}}\"")?;
+                writeln!(output, "}}")?;
+                writeln!(output)?;
+                writeln!(
+                    output,
+                    "if [ \"${{funcstack[1]}}\" = {} ]; then",
+                    quote(&function_name)
+                )?;
+                writeln!(output, "    {} \"$@\"", function_name)?;
+                writeln!(output, "else")?;
+                writeln!(
+                    output,
+                    "    compdef {} {}",
+                    function_name,
+                    quote(self.program())
+                )?;
+                writeln!(output, "fi")
+            }
+            Shell::Fish => {
+                let function_name = format!("_{}_candidates", function_name);
+                writeln!(output, "function {}", function_name)?;
+                writeln!(output, "    set -l words (commandline -opc)")?;
+                writeln!(
+                    output,
+                    "    $words[1] {} (count $words) $words (commandline -ct)",
+                    COMPLETE_ARGUMENT
+                )?;
+                writeln!(output, "end")?;
+                writeln!(output)?;
+                writeln!(
+                    output,
+                    "complete -c {} -f -a {}",
+                    quote_fish(self.program()),
+                    quote_fish(&format!("({})", function_name))
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::quote_fish;
+use crate::{CompletionWriter, ValueHint};
+use std::io::Write;
+
+impl CompletionWriter {
+    /// Writes the completion script for fish to `output`
+    pub(super) fn write_fish(&self, output: &mut dyn Write) -> std::io::Result<()> {
+        let program = quote_fish(self.program());
+        let function_name = format!("_{}_using_command", self.function_name());
+
+        // A function which checks if the command being completed is the one in its arguments
+        writeln!(output, "function {}", function_name)?;
+        writeln!(output, "    set -l words (commandline -opc)")?;
+        writeln!(output, "    set -l command {}", program)?;
+        writeln!(output, "    set -l skip 0")?;
+        writeln!(output, "    for word in $words[2..-1]")?;
+        writeln!(output, "        if test $skip -eq 1")?;
+        writeln!(output, "            set skip 0")?;
+        writeln!(output, "            continue")?;
+        writeln!(output, "        end")?;
+        writeln!(output)?;
+        writeln!(output, "        switch $command")?;
+        for command in &self.commands {
+            writeln!(output, "            case {}", quote_fish(&command.key()))?;
+            writeln!(output, "                switch $word")?;
+
+            let value_flags: Vec<_> = command
+                .flags
+                .iter()
+                .filter(|flag| flag.takes_value)
+                .flat_map(|flag| flag.names())
+                .map(quote_fish)
+                .collect();
+            if !value_flags.is_empty() {
+                writeln!(output, "                    case {}", value_flags.join(" "))?;
+                writeln!(output, "                        set skip 1")?;
+            }
+
+            for sub_command in &command.sub_commands {
+                writeln!(
+                    output,
+                    "                    case {}",
+                    quote_fish(sub_command)
+                )?;
+                writeln!(
+                    output,
+                    "                        set command {}",
+                    quote_fish(&format!("{} {}", command.key(), sub_command))
+                )?;
+            }
+
+            writeln!(output, "                end")?;
+        }
+        writeln!(output, "        end")?;
+        writeln!(output, "    end")?;
+        writeln!(output)?;
+        writeln!(output, "    test \"$command\" = \"$argv\"")?;
+        writeln!(output, "end")?;
+        writeln!(output)?;
+
+        writeln!(output, "complete -c {} -f", program)?;
+        for command in &self.commands {
+            let condition = quote_fish(&format!("{} {}", function_name, command.key()));
+
+            for flag in command.flags.iter().filter(|flag| !flag.hidden) {
+                write!(output, "complete -c {} -n {}", program, condition)?;
+
+                if let Some(short_name) = flag.short_name {
+                    let short_name = short_name.trim_start_matches('-');
+                    if short_name.chars().count() == 1 {
+                        write!(output, " -s {}", quote_fish(short_name))?;
+                    } else {
+                        write!(output, " -o {}", quote_fish(short_name))?;
+                    }
+                }
+
+                if let Some(long_name) = flag.long_name {
+                    write!(
+                        output,
+                        " -l {}",
+                        quote_fish(long_name.trim_start_matches('-'))
+                    )?;
+                }
+
+                if flag.takes_value {
+                    write!(output, " {}", complete(flag.hint, true))?;
+                }
+
+                if let Some(description) = &flag.description {
+                    write!(output, " -d {}", quote_fish(description))?;
+                }
+
+                writeln!(output)?;
+            }
+
+            for positional in &command.positionals {
+                if let ValueHint::Any | ValueHint::Integer | ValueHint::Float = positional.hint {
+                    continue;
+                }
+
+                writeln!(
+                    output,
+                    "complete -c {} -n {} {}",
+                    program,
+                    condition,
+                    complete(positional.hint, false)
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Gets the options completing a value described by `hint`, which `requires` a flag be before it
+fn complete(hint: ValueHint, requires: bool) -> String {
+    match (hint, requires) {
+        (ValueHint::Any | ValueHint::Integer | ValueHint::Float, _) => "-x".to_owned(),
+        (ValueHint::Path, true) => "-r -F".to_owned(),
+        (ValueHint::Path, false) => "-F".to_owned(),
+        (ValueHint::Values(values), true) => format!("-x -a {}", quote_fish(&values.join(" "))),
+        (ValueHint::Values(values), false) => format!("-a {}", quote_fish(&values.join(" "))),
+    }
+}
@@ -0,0 +1,34 @@
+use crate::ValueHint;
+
+/// A flag which completions are written for
+pub(super) struct CompletionFlag {
+    /// The short name of the flag, including the leading dash
+    pub(super) short_name: Option<&'static str>,
+
+    /// The long name of the flag, including the leading dashes
+    pub(super) long_name: Option<&'static str>,
+
+    /// Does the flag take a value?
+    pub(super) takes_value: bool,
+
+    /// The values the flag accepts
+    pub(super) hint: ValueHint,
+
+    /// The first line of the description of the flag
+    pub(super) description: Option<String>,
+
+    /// The function listing candidates for the value of the flag
+    pub(super) complete: Option<fn(&str) -> Vec<String>>,
+
+    /// Is the flag left out of the candidates?
+    ///
+    /// Hidden flags are still recognized so the values following them are skipped
+    pub(super) hidden: bool,
+}
+
+impl CompletionFlag {
+    /// Gets the names of the flag, short name first
+    pub(super) fn names(&self) -> impl Iterator<Item = &'static str> {
+        self.short_name.into_iter().chain(self.long_name)
+    }
+}
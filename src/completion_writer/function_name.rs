@@ -0,0 +1,18 @@
+use crate::CompletionWriter;
+
+impl CompletionWriter {
+    /// Gets the name of the program being completed
+    pub(super) fn program(&self) -> &str {
+        &self.commands[0].names[0]
+    }
+
+    /// Gets the name of the completion function for the program, replacing characters which
+    /// can't be used in function names
+    pub(super) fn function_name(&self) -> String {
+        let mut name = String::from("_");
+        for c in self.program().chars() {
+            name.push(if c.is_ascii_alphanumeric() { c } else { '_' });
+        }
+        name
+    }
+}
@@ -0,0 +1,33 @@
+use command::CompletionCommand;
+use flag::CompletionFlag;
+use positional::CompletionPositional;
+use quote::{quote, quote_fish};
+
+mod command;
+mod flag;
+mod positional;
+mod quote;
+
+mod bash;
+mod candidates;
+mod default;
+mod document_writer;
+mod dynamic;
+mod fish;
+mod function_name;
+mod new;
+mod write;
+mod zsh;
+
+/// The argument which, when passed first to a program, requests candidates for completing the
+/// rest of the arguments instead of parsing them
+pub(crate) const COMPLETE_ARGUMENT: &str = "__complete";
+
+/// Collects the commands written to it and writes shell completion scripts for them
+///
+/// Commands are described to this writer through [`DocumentWriter`](crate::DocumentWriter), after
+/// which [`CompletionWriter::write`] produces the script for a shell.
+pub struct CompletionWriter {
+    /// The commands described so far, starting with the root command
+    commands: Vec<CompletionCommand>,
+}
@@ -0,0 +1,10 @@
+use crate::CompletionWriter;
+
+impl CompletionWriter {
+    /// Creates a new [`CompletionWriter`] with no commands
+    pub fn new() -> Self {
+        CompletionWriter {
+            commands: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::ValueHint;
+
+/// A positional which completions are written for
+pub(super) struct CompletionPositional {
+    /// The values the positional accepts
+    pub(super) hint: ValueHint,
+
+    /// Can the positional take multiple values?
+    pub(super) multiple: bool,
+
+    /// The function listing candidates for the value of the positional
+    pub(super) complete: Option<fn(&str) -> Vec<String>>,
+}
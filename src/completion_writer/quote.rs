@@ -0,0 +1,9 @@
+/// Quotes `text` for bash or zsh so it is used literally
+pub(super) fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "'\\''"))
+}
+
+/// Quotes `text` for fish so it is used literally
+pub(super) fn quote_fish(text: &str) -> String {
+    format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
@@ -0,0 +1,19 @@
+use crate::{CompletionWriter, Shell};
+use std::io::Write;
+
+impl CompletionWriter {
+    /// Writes the completion script for `shell` to `output`
+    ///
+    /// Nothing is written if no commands have been described.
+    pub fn write(&self, output: &mut dyn Write, shell: Shell) -> std::io::Result<()> {
+        if self.commands.is_empty() {
+            return Ok(());
+        }
+
+        match shell {
+            Shell::Bash => self.write_bash(output),
+            Shell::Zsh => self.write_zsh(output),
+            Shell::Fish => self.write_fish(output),
+        }
+    }
+}
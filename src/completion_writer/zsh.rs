@@ -0,0 +1,158 @@
+use super::{quote, CompletionFlag};
+use crate::{CompletionWriter, ValueHint};
+use std::io::Write;
+
+impl CompletionWriter {
+    /// Writes the completion script for zsh to `output`
+    pub(super) fn write_zsh(&self, output: &mut dyn Write) -> std::io::Result<()> {
+        let function_name = self.function_name();
+
+        writeln!(output, "#compdef {}", self.program())?;
+        writeln!(output)?;
+        writeln!(output, "{}() {{", function_name)?;
+        writeln!(output, "    local cur=\"${{words[CURRENT]}}\"")?;
+        writeln!(output, "    local prev=\"${{words[CURRENT-1]}}\"")?;
+        writeln!(
+            output,
+            "    local command={} positional=0 skip=0 i word",
+            quote(self.program())
+        )?;
+        writeln!(output, "    local -a flags")?;
+        writeln!(output)?;
+
+        // Find the command being completed and the index of the positional under the cursor
+        writeln!(output, "    for ((i = 2; i < CURRENT; i++)); do")?;
+        writeln!(output, "        word=\"${{words[i]}}\"")?;
+        writeln!(output, "        if ((skip)); then")?;
+        writeln!(output, "            skip=0")?;
+        writeln!(output, "            continue")?;
+        writeln!(output, "        fi")?;
+        writeln!(output)?;
+        writeln!(output, "        case \"${{command}}\" in")?;
+        for command in &self.commands {
+            writeln!(output, "        {})", quote(&command.key()))?;
+            writeln!(output, "            case \"${{word}}\" in")?;
+
+            let value_flags: Vec<_> = command
+                .flags
+                .iter()
+                .filter(|flag| flag.takes_value)
+                .map(pattern)
+                .collect();
+            if !value_flags.is_empty() {
+                writeln!(output, "            {}) skip=1 ;;", value_flags.join("|"))?;
+            }
+
+            for sub_command in &command.sub_commands {
+                writeln!(
+                    output,
+                    "            {}) command={}; positional=0 ;;",
+                    quote(sub_command),
+                    quote(&format!("{} {}", command.key(), sub_command))
+                )?;
+            }
+
+            writeln!(output, "            -*) ;;")?;
+            writeln!(output, "            *) positional=$((positional + 1)) ;;")?;
+            writeln!(output, "            esac")?;
+            writeln!(output, "            ;;")?;
+        }
+        writeln!(output, "        esac")?;
+        writeln!(output, "    done")?;
+        writeln!(output)?;
+
+        writeln!(output, "    case \"${{command}}\" in")?;
+        for command in &self.commands {
+            writeln!(output, "    {})", quote(&command.key()))?;
+
+            let value_flags: Vec<_> = command
+                .flags
+                .iter()
+                .filter(|flag| flag.takes_value)
+                .collect();
+            if !value_flags.is_empty() {
+                writeln!(output, "        case \"${{prev}}\" in")?;
+                for flag in value_flags {
+                    writeln!(
+                        output,
+                        "        {}) {}; return ;;",
+                        pattern(flag),
+                        complete(flag.hint)
+                    )?;
+                }
+                writeln!(output, "        esac")?;
+            }
+
+            let mut flags = Vec::new();
+            for flag in command.flags.iter().filter(|flag| !flag.hidden) {
+                for name in flag.names() {
+                    let name = name.replace(':', "\\:");
+                    flags.push(quote(&match &flag.description {
+                        Some(description) => format!("{}:{}", name, description),
+                        None => name,
+                    }));
+                }
+            }
+
+            writeln!(output, "        if [[ \"${{cur}}\" == -* ]]; then")?;
+            writeln!(output, "            flags=({})", flags.join(" "))?;
+            writeln!(output, "            _describe -t options option flags")?;
+            writeln!(output, "            return")?;
+            writeln!(output, "        fi")?;
+
+            if !command.positionals.is_empty() {
+                writeln!(output, "        case \"${{positional}}\" in")?;
+                for (i, positional) in command.positionals.iter().enumerate() {
+                    if positional.multiple {
+                        writeln!(output, "        *) {} ;;", complete(positional.hint))?;
+                        break;
+                    }
+
+                    writeln!(output, "        {}) {} ;;", i, complete(positional.hint))?;
+                }
+                writeln!(output, "        esac")?;
+            }
+
+            writeln!(output, "        ;;")?;
+        }
+        writeln!(output, "    esac")?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+
+        writeln!(
+            output,
+            "if [ \"${{funcstack[1]}}\" = {} ]; then",
+            quote(&function_name)
+        )?;
+        writeln!(output, "    {} \"$@\"", function_name)?;
+        writeln!(output, "else")?;
+        writeln!(
+            output,
+            "    compdef {} {}",
+            function_name,
+            quote(self.program())
+        )?;
+        writeln!(output, "fi")
+    }
+}
+
+/// Gets the case pattern matching the names of `flag`
+fn pattern(flag: &CompletionFlag) -> String {
+    flag.names().map(quote).collect::<Vec<_>>().join("|")
+}
+
+/// Gets the command completing a value described by `hint`
+fn complete(hint: ValueHint) -> String {
+    match hint {
+        ValueHint::Any | ValueHint::Integer | ValueHint::Float => "true".to_owned(),
+        ValueHint::Path => "_files".to_owned(),
+        ValueHint::Values(values) => format!(
+            "compadd -- {}",
+            values
+                .iter()
+                .map(|value| quote(value))
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+    }
+}
@@ -48,5 +48,7 @@ impl_display!(
     std::net::Ipv6Addr,
     std::net::SocketAddr,
     std::net::SocketAddrV4,
-    std::net::SocketAddrV6
+    std::net::SocketAddrV6,
+    crate::ColorChoice,
+    crate::Shell
 );
@@ -0,0 +1,64 @@
+use crate::{
+    ArgGroup, Command, CommandList, Flag, FlagGroup, FlagInfo, Positional, PositionalInfo,
+};
+
+/// A writer for reference documentation describing commands, like a man page
+///
+/// Derived commands describe themselves to a [`DocumentWriter`] with
+/// [`Command::write_document`], starting with [`DocumentWriter::command`] and followed by their
+/// sections and the entries in them. Sub-commands follow the command leading to them.
+pub trait DocumentWriter {
+    /// Starts the description of the command `C`, returning the command list to use for its
+    /// sub-commands
+    ///
+    /// `name` is used as the program name if `command_list` doesn't contain one.
+    fn command<C: Command>(
+        &mut self,
+        name: &str,
+        command_list: &CommandList,
+    ) -> std::io::Result<CommandList>;
+
+    /// Should hidden sub-commands be described to this writer
+    ///
+    /// Writers for reference documentation and completions leave them out, which is the default.
+    fn hidden_commands(&self) -> bool {
+        false
+    }
+
+    /// Starts a new section titled `title` in the current command
+    ///
+    /// Titles are the headers of the sections in help, like
+    /// [`Messages::options_header`](crate::Messages::options_header), which may end with a colon.
+    fn section(&mut self, title: &str) -> std::io::Result<()>;
+
+    /// Starts the entries of the flag group `G` titled `title` in the current command, which last
+    /// until the matching [`DocumentWriter::end_flag_group`]
+    ///
+    /// Flag groups are written as a section titled `title` by default.
+    fn flag_group<G: FlagGroup>(&mut self, title: &str) -> std::io::Result<()> {
+        self.section(title)
+    }
+
+    /// Ends the entries of the flag group most recently started
+    fn end_flag_group(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Writes the entry for a positional described by `info` in the current section
+    ///
+    /// Hidden positionals are passed too, and writers for reference documentation leave them out
+    fn positional<T: Positional>(&mut self, info: &PositionalInfo<T>) -> std::io::Result<()>;
+
+    /// Writes the entry for a flag described by `info` in the current section
+    ///
+    /// Hidden flags are passed too, and writers for reference documentation leave them out
+    fn flag<T: Flag>(&mut self, info: &FlagInfo<T>) -> std::io::Result<()>;
+
+    /// Writes the rule of an arg group of the flags in the current section, after its members
+    ///
+    /// Arg groups are left out by default.
+    #[allow(unused_variables)]
+    fn arg_group(&mut self, arg_group: &ArgGroup) -> std::io::Result<()> {
+        Ok(())
+    }
+}
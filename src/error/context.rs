@@ -0,0 +1,54 @@
+use crate::{CommandList, Error};
+
+impl Error {
+    /// Adds the command being parsed and the index of the argument being parsed to this error,
+    /// making it an [`Error::InCommand`]
+    ///
+    /// Errors which already have a command keep it, so errors from sub-commands name the
+    /// sub-command rather than the commands leading to it. Each error in an [`Error::Multiple`]
+    /// is given the command individually.
+    pub fn in_command(self, command_list: &CommandList, index: Option<usize>) -> Self {
+        match self {
+            Error::InCommand(..) => self,
+            Error::Multiple(errors) => Error::Multiple(
+                errors
+                    .into_iter()
+                    .map(|error| error.in_command(command_list, index))
+                    .collect(),
+            ),
+            error => Error::InCommand(command_list.clone(), index, Box::new(error)),
+        }
+    }
+
+    /// Gets the names of the program and sub-commands leading to the command being parsed when
+    /// this error occurred, if known
+    ///
+    /// The command of an [`Error::Multiple`] is the command of its first error.
+    pub fn command_list(&self) -> Option<&CommandList> {
+        match self {
+            Error::InCommand(command_list, _, _) => Some(command_list),
+            Error::Multiple(errors) => errors.first().and_then(Error::command_list),
+            _ => None,
+        }
+    }
+
+    /// Gets the index of the argument being parsed when this error occurred, counting the program
+    /// name as 0, if known
+    ///
+    /// For an invalid flag value this is the index of the value. Errors found after all arguments
+    /// were read, like missing values, have no index.
+    pub fn argument_index(&self) -> Option<usize> {
+        match self {
+            Error::InCommand(_, index, _) => *index,
+            _ => None,
+        }
+    }
+
+    /// Gets this error without the command and argument it occurred in
+    pub fn without_context(&self) -> &Error {
+        match self {
+            Error::InCommand(_, _, error) => error.without_context(),
+            error => error,
+        }
+    }
+}
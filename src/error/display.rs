@@ -1,23 +1,49 @@
-use crate::Error;
+use crate::{messages, Error};
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages = messages();
         match self {
-            Error::InvalidUTF8(string) => write!(f, "invalid UTF-8 \"{string}\""),
-            Error::MissingArgument(argument) => write!(f, "missing argument \"{argument}\""),
-            Error::MissingPositionalValue(value) => write!(f, "missing \"{value}\""),
+            Error::InvalidUTF8(string) => messages.invalid_utf8(f, string),
+            Error::MissingArgument(argument) => messages.missing_argument(f, argument),
+            Error::MissingPositionalValue(value) => messages.missing_positional_value(f, value),
             Error::InvalidPositionalValue(value, error) => {
-                write!(f, "invalid \"{value}\" - {error}")
+                messages.invalid_positional_value(f, value, error.as_ref())
             }
             Error::MissingFlagValue(argument, value) => {
-                write!(f, "missing \"{value}\" for \"{argument}\"")
+                messages.missing_flag_value(f, argument, value)
             }
             Error::InvalidFlagValue(argument, value, error) => {
-                write!(f, "invalid \"{value}\" for \"{argument}\" - {error}")
+                messages.invalid_flag_value(f, argument, value, error.as_ref())
             }
-            Error::RepeatedFlag(argument) => write!(f, "repeated flag \"{argument}\""),
-            Error::UnknownArgument(argument) => write!(f, "unknown argument \"{argument}\""),
+            Error::RepeatedFlag(argument) => messages.repeated_flag(f, argument),
+            Error::ConflictingFlags(flag, other) => messages.conflicting_flags(f, flag, other),
+            Error::MissingRequiredFlag(flag, required) => {
+                messages.missing_required_flag(f, flag, required)
+            }
+            Error::MissingArgGroup(members) => messages.missing_arg_group(f, members),
+            Error::ConflictingArgGroup(members) => messages.conflicting_arg_group(f, members),
+            Error::UnknownArgument(argument, suggestion) => {
+                messages.unknown_argument(f, argument)?;
+                if let Some(suggestion) = suggestion {
+                    messages.did_you_mean(f, suggestion)?;
+                }
+                Ok(())
+            }
+            Error::Io(error) => messages.io_error(f, error),
             Error::Custom(message) => message.fmt(f),
+            Error::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    error.fmt(f)?;
+                }
+                Ok(())
+            }
+            Error::InCommand(command_list, index, error) => {
+                messages.in_command(f, &command_list.to_string(), *index, error)
+            }
         }
     }
 }
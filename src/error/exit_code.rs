@@ -0,0 +1,16 @@
+use crate::Error;
+
+impl Error {
+    /// The exit code of a process ending with this error
+    ///
+    /// Failing to write output exits with 1, while every other error is a usage error exiting with
+    /// 2.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Io(_) => 1,
+            Error::InCommand(_, _, error) => error.exit_code(),
+            Error::Multiple(errors) => errors.iter().map(Error::exit_code).max().unwrap_or(2),
+            _ => 2,
+        }
+    }
+}
@@ -1,7 +1,7 @@
-use crate::InvalidAddressError;
+use crate::{messages, InvalidAddressError};
 
 impl std::fmt::Display for InvalidAddressError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        "invalid address provided".fmt(f)
+        messages().invalid_address(f)
     }
 }
@@ -1,7 +1,7 @@
-use crate::InvalidCharError;
+use crate::{messages, InvalidCharError};
 
 impl std::fmt::Display for InvalidCharError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        "expected exactly 1 character".fmt(f)
+        messages().invalid_char(f)
     }
 }
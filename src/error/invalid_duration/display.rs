@@ -1,7 +1,7 @@
-use crate::InvalidDurationError;
+use crate::{messages, InvalidDurationError};
 
 impl std::fmt::Display for InvalidDurationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "invalid duration \"{}\"", self.duration)
+        messages().invalid_duration(f, self.duration)
     }
 }
@@ -1,11 +1,10 @@
-use crate::InvalidLengthError;
+use crate::{messages, InvalidLengthError};
 
 impl std::fmt::Display for InvalidLengthError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            InvalidLengthError::TooShort => "value is too short",
-            InvalidLengthError::TooLong => "value is too long",
+            InvalidLengthError::TooShort => messages().value_too_short(f),
+            InvalidLengthError::TooLong => messages().value_too_long(f),
         }
-        .fmt(f)
     }
 }
@@ -1,12 +1,12 @@
-use crate::InvalidNumberError;
+use crate::{messages, InvalidNumberError};
 
 impl std::fmt::Display for InvalidNumberError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            InvalidNumberError::Invalid => "invalid number provided".fmt(f),
-            InvalidNumberError::PosOverflow => "provided number is too large".fmt(f),
-            InvalidNumberError::NegOverflow => "provided number is too small".fmt(f),
-            InvalidNumberError::Zero => "value cannot be zero".fmt(f),
+            InvalidNumberError::Invalid => messages().invalid_number(f),
+            InvalidNumberError::PosOverflow => messages().number_too_large(f),
+            InvalidNumberError::NegOverflow => messages().number_too_small(f),
+            InvalidNumberError::Zero => messages().zero_value(f),
         }
     }
 }
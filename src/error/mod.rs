@@ -3,17 +3,31 @@ mod invalid_char;
 mod invalid_duration;
 mod invalid_length;
 mod invalid_number;
+mod report;
+mod suggest;
 mod unexpected;
+mod unwrap_errors;
 
+mod context;
 mod display;
+mod exit_code;
+mod from;
 mod new;
+mod source;
+mod value_error;
+
+use crate::CommandList;
 
 pub use invalid_address::InvalidAddressError;
 pub use invalid_char::InvalidCharError;
 pub use invalid_duration::InvalidDurationError;
 pub use invalid_length::InvalidLengthError;
 pub use invalid_number::InvalidNumberError;
+pub use report::ErrorReport;
 pub use unexpected::UnexpectedError;
+pub use unwrap_errors::UnwrapErrors;
+
+pub(crate) use suggest::suggest;
 
 /// A result of an argparse operation
 pub type Result<T> = core::result::Result<T, Error>;
@@ -31,22 +45,47 @@ pub enum Error {
     MissingPositionalValue(&'static str),
 
     /// An invalid value was given for a positional
-    InvalidPositionalValue(&'static str, Box<dyn std::error::Error>),
+    InvalidPositionalValue(&'static str, Box<dyn std::error::Error + Send + Sync>),
 
     /// A required value for a flag is missing
     MissingFlagValue(&'static str, &'static str),
 
     /// An invalid value was given for a flag
-    InvalidFlagValue(&'static str, &'static str, Box<dyn std::error::Error>),
+    InvalidFlagValue(
+        &'static str,
+        &'static str,
+        Box<dyn std::error::Error + Send + Sync>,
+    ),
 
     /// A second repeated flag was passed that can't be repeated
     RepeatedFlag(&'static str),
 
-    /// An unknown argument was passed
-    UnknownArgument(String),
+    /// A flag was passed with another flag it conflicts with
+    ConflictingFlags(&'static str, &'static str),
+
+    /// A flag was passed without another flag it requires
+    MissingRequiredFlag(&'static str, &'static str),
+
+    /// None of the flags in a group which requires one were passed
+    MissingArgGroup(&'static [&'static str]),
+
+    /// More than one of the flags in a group which allows only one were passed
+    ConflictingArgGroup(&'static [&'static str]),
+
+    /// An unknown argument was passed, with the closest known argument if one is close enough to be
+    /// a likely typo
+    UnknownArgument(String, Option<String>),
+
+    /// Writing output, like help or version, failed
+    Io(std::io::Error),
 
     /// A custom error used by consumers of this crate
-    Custom(Box<dyn std::error::Error>),
-}
+    Custom(Box<dyn std::error::Error + Send + Sync>),
 
-impl std::error::Error for Error {}
+    /// An error which occurred while parsing a command, with the names leading to the command and
+    /// the index of the argument being parsed, if known
+    InCommand(CommandList, Option<usize>, Box<Error>),
+
+    /// Several missing or invalid values found while unwrapping a command
+    Multiple(Vec<Error>),
+}
@@ -1,8 +1,8 @@
-use crate::{flag::DEFUALT_FLAG_VALUE, Error, Flag, FlagInfo};
+use crate::{error::suggest, messages, Error, Flag, FlagInfo};
 
 impl Error {
     /// Create an [`Error::Custom`] containing `message`
-    pub fn custom<T: 'static + std::error::Error>(message: T) -> Self {
+    pub fn custom<T: 'static + std::error::Error + Send + Sync>(message: T) -> Self {
         Error::Custom(Box::new(message))
     }
 
@@ -22,7 +22,7 @@ impl Error {
     }
 
     /// Create an [`Error::InvalidPositionalValue`] for `value`
-    pub fn invalid_positional_value<T: 'static + std::error::Error>(
+    pub fn invalid_positional_value<T: 'static + std::error::Error + Send + Sync>(
         value: &'static str,
         error: T,
     ) -> Self {
@@ -38,12 +38,32 @@ impl Error {
                 info.short_name
             }
             .unwrap(),
-            info.value.unwrap_or(DEFUALT_FLAG_VALUE),
+            info.value.unwrap_or_else(|| messages().value()),
         )
     }
 
+    /// Create an [`Error::ConflictingFlags`] for `flag` passed with `other`
+    pub fn conflicting_flags(flag: &'static str, other: &'static str) -> Self {
+        Error::ConflictingFlags(flag, other)
+    }
+
+    /// Create an [`Error::MissingRequiredFlag`] for `flag` passed without `required`
+    pub fn missing_required_flag(flag: &'static str, required: &'static str) -> Self {
+        Error::MissingRequiredFlag(flag, required)
+    }
+
+    /// Create an [`Error::MissingArgGroup`] for a group with `members`
+    pub fn missing_arg_group(members: &'static [&'static str]) -> Self {
+        Error::MissingArgGroup(members)
+    }
+
+    /// Create an [`Error::ConflictingArgGroup`] for a group with `members`
+    pub fn conflicting_arg_group(members: &'static [&'static str]) -> Self {
+        Error::ConflictingArgGroup(members)
+    }
+
     /// Create an [`Error::InvalidFlagValue`] for `value` of `argument`
-    pub fn invalid_flag_value<T: Flag, E: 'static + std::error::Error>(
+    pub fn invalid_flag_value<T: Flag, E: 'static + std::error::Error + Send + Sync>(
         info: &FlagInfo<T>,
         long: bool,
         error: E,
@@ -55,7 +75,7 @@ impl Error {
                 info.short_name
             }
             .unwrap(),
-            info.value.unwrap_or(DEFUALT_FLAG_VALUE),
+            info.value.unwrap_or_else(|| messages().value()),
             Box::new(error),
         )
     }
@@ -74,6 +94,12 @@ impl Error {
 
     /// Create an [`Error::UnknownArgument`] for `argument`
     pub fn unknown_argument(argument: String) -> Self {
-        Error::UnknownArgument(argument)
+        Error::UnknownArgument(argument, None)
+    }
+
+    /// Create an [`Error::UnknownArgument`] for `argument`, suggesting the closest of `candidates`
+    pub fn unknown_argument_among(argument: String, candidates: &[&str]) -> Self {
+        let suggestion = suggest(&argument, candidates);
+        Error::UnknownArgument(argument, suggestion)
     }
 }
@@ -0,0 +1,23 @@
+use crate::{messages, Error, ErrorReport};
+
+/// Each error of an [`Error::Multiple`] is written on its own line with its own prefix
+impl<'a> std::fmt::Display for ErrorReport<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Error::Multiple(errors) = self.error {
+            for (i, error) in errors.iter().enumerate() {
+                if i > 0 {
+                    writeln!(f)?;
+                }
+                error.report(self.style).fmt(f)?;
+            }
+            return Ok(());
+        }
+
+        write!(
+            f,
+            "{} {}",
+            self.style.error(messages().error_prefix()),
+            self.error
+        )
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{Error, Style};
+
+mod display;
+mod new;
+
+/// Displays an [`Error`] for the user, prefixed with "error:"
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorReport<'a> {
+    /// The error to display
+    error: &'a Error,
+
+    /// The style to display the prefix with
+    style: Style,
+}
@@ -0,0 +1,8 @@
+use crate::{Error, ErrorReport, Style};
+
+impl Error {
+    /// Creates an [`ErrorReport`] for displaying this error to the user with `style`
+    pub fn report(&self, style: Style) -> ErrorReport<'_> {
+        ErrorReport { error: self, style }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::Error;
+
+/// The messages of the errors wrapped by [`Error::InvalidPositionalValue`],
+/// [`Error::InvalidFlagValue`], [`Error::Io`], [`Error::Custom`] and [`Error::InCommand`] are
+/// already part of their display, so their sources are the sources of the wrapped errors. Use
+/// [`Error::value_error`] to get the wrapped error itself.
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidPositionalValue(_, error)
+            | Error::InvalidFlagValue(_, _, error)
+            | Error::Custom(error) => error.source(),
+            Error::Io(error) => error.source(),
+            Error::InCommand(_, _, error) => error.source(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/// Gets the closest of `candidates` to `value`, if any is close enough to be a likely typo
+///
+/// Closeness is measured by the number of single character insertions, deletions, substitutions
+/// and swaps of adjacent characters needed to turn `value` into a candidate.
+pub(crate) fn suggest(value: &str, candidates: &[&str]) -> Option<String> {
+    let value: Vec<char> = value.chars().collect();
+    let threshold = (value.len() / 3).max(1);
+
+    let mut best: Option<(usize, &str)> = None;
+    for candidate in candidates {
+        let distance = edit_distance(&value, &candidate.chars().collect::<Vec<_>>());
+        if distance <= threshold && best.is_none_or(|(best, _)| distance < best) {
+            best = Some((distance, candidate));
+        }
+    }
+
+    best.map(|(_, candidate)| candidate.to_string())
+}
+
+/// Gets the optimal string alignment distance between `a` and `b`
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous_previous = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(previous_previous[j - 2] + 1);
+            }
+        }
+
+        std::mem::swap(&mut previous_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
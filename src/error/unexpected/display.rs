@@ -1,11 +1,14 @@
-use crate::UnexpectedError;
+use crate::{messages, UnexpectedError};
 
 impl std::fmt::Display for UnexpectedError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "unexpected \"{}\", expected {}",
-            self.unexpected, self.expected
-        )
+        let messages = messages();
+        messages.unexpected_value(f, &self.unexpected, self.expected)?;
+
+        if let Some(suggestion) = &self.suggestion {
+            messages.did_you_mean(f, suggestion)?;
+        }
+
+        Ok(())
     }
 }
@@ -9,6 +9,9 @@ pub struct UnexpectedError {
 
     /// The values that were expected
     expected: &'static str,
+
+    /// The closest expected value, if one is close enough to be a likely typo
+    suggestion: Option<String>,
 }
 
 impl std::error::Error for UnexpectedError {}
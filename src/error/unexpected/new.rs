@@ -1,4 +1,4 @@
-use crate::UnexpectedError;
+use crate::{error::suggest, UnexpectedError};
 
 impl UnexpectedError {
     /// Creates a new [`UnexpectedError`]
@@ -6,6 +6,21 @@ impl UnexpectedError {
         UnexpectedError {
             unexpected: unexpected.into(),
             expected,
+            suggestion: None,
+        }
+    }
+
+    /// Creates a new [`UnexpectedError`], suggesting the closest of `candidates`
+    pub fn among<S: Into<String>>(
+        unexpected: S,
+        expected: &'static str,
+        candidates: &[&str],
+    ) -> Self {
+        let unexpected = unexpected.into();
+        UnexpectedError {
+            suggestion: suggest(&unexpected, candidates),
+            unexpected,
+            expected,
         }
     }
 }
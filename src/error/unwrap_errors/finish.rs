@@ -0,0 +1,14 @@
+use crate::{Error, Result, UnwrapErrors};
+
+impl UnwrapErrors {
+    /// Returns the collected errors, if there are any
+    ///
+    /// A single error is returned on its own, while more are returned as an [`Error::Multiple`].
+    pub fn finish(mut self) -> Result<()> {
+        match self.errors.len() {
+            0 => Ok(()),
+            1 => Err(self.errors.pop().unwrap()),
+            _ => Err(Error::Multiple(self.errors)),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::Error;
+
+mod finish;
+mod new;
+mod take;
+
+/// Collects the errors from unwrapping the values of a command or flag group, so every missing or
+/// invalid value is reported at once
+#[derive(Debug, Default)]
+pub struct UnwrapErrors {
+    /// The errors collected so far
+    errors: Vec<Error>,
+}
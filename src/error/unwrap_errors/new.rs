@@ -0,0 +1,8 @@
+use crate::UnwrapErrors;
+
+impl UnwrapErrors {
+    /// Creates a new empty [`UnwrapErrors`]
+    pub fn new() -> Self {
+        UnwrapErrors { errors: Vec::new() }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{Error, Result, UnwrapErrors};
+
+impl UnwrapErrors {
+    /// Takes the value from `result`, or collects its error and returns [`None`]
+    ///
+    /// The errors of an [`Error::Multiple`] are collected individually.
+    pub fn take<T>(&mut self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(Error::Multiple(mut errors)) => {
+                self.errors.append(&mut errors);
+                None
+            }
+            Err(error) => {
+                self.errors.push(error);
+                None
+            }
+        }
+    }
+}
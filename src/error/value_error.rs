@@ -0,0 +1,18 @@
+use crate::Error;
+
+impl Error {
+    /// Gets the error wrapped by this error, looking through [`Error::InCommand`]
+    ///
+    /// The specific error, like an [`InvalidNumberError`](crate::InvalidNumberError), can be found
+    /// by downcasting.
+    pub fn value_error(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
+        match self {
+            Error::InvalidPositionalValue(_, error)
+            | Error::InvalidFlagValue(_, _, error)
+            | Error::Custom(error) => Some(error.as_ref()),
+            Error::Io(error) => Some(error),
+            Error::InCommand(_, _, error) => error.value_error(),
+            _ => None,
+        }
+    }
+}
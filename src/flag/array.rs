@@ -2,6 +2,8 @@ use crate::{ArgumentSource, Error, Flag, FlagInfo, Result};
 use std::mem::MaybeUninit;
 
 impl<T: Flag, const N: usize> Flag for [T; N] {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -12,7 +14,7 @@ impl<T: Flag, const N: usize> Flag for [T; N] {
             return Err(Error::repeated_flag(info, long));
         }
 
-        let info = info.drop_default();
+        let info = info.inner_info();
 
         let mut array = MaybeUninit::uninit().transpose();
         for i in 0..N {
@@ -2,6 +2,8 @@ use crate::{ArgumentSource, Error, Flag, FlagInfo, Result};
 use std::sync::atomic::AtomicBool;
 
 impl Flag for bool {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         _: &mut dyn ArgumentSource,
@@ -30,6 +32,8 @@ impl Flag for bool {
 }
 
 impl Flag for AtomicBool {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         _: &mut dyn ArgumentSource,
@@ -1,7 +1,9 @@
-use crate::{ArgumentSource, DefaultDisplay, Error, Flag, FlagInfo, Result};
+use crate::{ArgumentSource, DefaultDisplay, Error, Flag, FlagInfo, Result, ValueHint};
 use std::borrow::Cow;
 
 impl<'a, T: Flag, B: DefaultDisplay + ToOwned<Owned = T> + ?Sized> Flag for Cow<'a, B> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -13,7 +15,7 @@ impl<'a, T: Flag, B: DefaultDisplay + ToOwned<Owned = T> + ?Sized> Flag for Cow<
         }
 
         let mut new = None;
-        let info = info.drop_default();
+        let info = info.inner_info();
         T::parse(&mut new, source, &info, long)?;
         *this = Some(Cow::Owned(T::unwrap(new, &info)?));
         Ok(())
@@ -22,4 +24,12 @@ impl<'a, T: Flag, B: DefaultDisplay + ToOwned<Owned = T> + ?Sized> Flag for Cow<
     fn takes_value(info: &FlagInfo<Self>) -> bool {
         T::takes_value(&info.drop_default())
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        T::multiple(&info.drop_default())
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
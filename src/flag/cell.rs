@@ -1,7 +1,9 @@
-use crate::{ArgumentSource, Error, Flag, FlagInfo, Result};
+use crate::{ArgumentSource, Error, Flag, FlagInfo, Result, ValueHint};
 use std::cell::RefCell;
 
 impl<T: Flag> Flag for RefCell<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -13,7 +15,7 @@ impl<T: Flag> Flag for RefCell<T> {
         }
 
         let mut new = None;
-        let info = info.drop_default();
+        let info = info.inner_info();
         T::parse(&mut new, source, &info, long)?;
         *this = Some(RefCell::new(T::unwrap(new, &info)?));
         Ok(())
@@ -22,4 +24,12 @@ impl<T: Flag> Flag for RefCell<T> {
     fn takes_value(info: &FlagInfo<Self>) -> bool {
         T::takes_value(&info.drop_default())
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        T::multiple(&info.drop_default())
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
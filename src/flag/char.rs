@@ -1,6 +1,8 @@
 use crate::{ArgumentSource, Error, Flag, FlagInfo, InvalidCharError, Result};
 
 impl Flag for char {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -13,9 +15,12 @@ impl Flag for char {
 
         let value = source.next().ok_or(Error::missing_flag_value(info, long))?;
 
-        *this = Some(value.as_str()?.parse().map_err(|error| {
+        let value = value.as_str()?.parse().map_err(|error| {
             Error::invalid_flag_value(info, long, InvalidCharError::from(error))
-        })?);
+        })?;
+
+        info.validate_value(&value, long)?;
+        *this = Some(value);
         Ok(())
     }
 }
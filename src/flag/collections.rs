@@ -1,10 +1,12 @@
-use crate::{ArgumentSource, Error, Flag, FlagInfo, InvalidLengthError, Result};
+use crate::{ArgumentSource, Error, Flag, FlagInfo, InvalidLengthError, Result, ValueHint};
 use std::{
     collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque},
     hash::Hash,
 };
 
 impl<T: Flag> Flag for Vec<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -27,7 +29,13 @@ impl<T: Flag> Flag for Vec<T> {
             min: None,
             max: None,
             default: None,
+            validate: info.validate,
             description: None,
+            long_description: None,
+            complete: None,
+            hidden: false,
+            conflicts_with: &[],
+            requires: &[],
         };
         T::parse(&mut value, source, &info, long)?;
 
@@ -61,9 +69,20 @@ impl<T: Flag> Flag for Vec<T> {
     fn is_required(info: &FlagInfo<Self>) -> bool {
         info.min.map(|f| f as usize > 0).unwrap_or(false)
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        info.max.is_none()
+            || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Flag> Flag for VecDeque<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -86,7 +105,13 @@ impl<T: Flag> Flag for VecDeque<T> {
             min: None,
             max: None,
             default: None,
+            validate: info.validate,
             description: None,
+            long_description: None,
+            complete: None,
+            hidden: false,
+            conflicts_with: &[],
+            requires: &[],
         };
         T::parse(&mut value, source, &info, long)?;
 
@@ -120,9 +145,20 @@ impl<T: Flag> Flag for VecDeque<T> {
     fn is_required(info: &FlagInfo<Self>) -> bool {
         info.min.map(|f| f as usize > 0).unwrap_or(false)
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        info.max.is_none()
+            || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Flag> Flag for LinkedList<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -145,7 +181,13 @@ impl<T: Flag> Flag for LinkedList<T> {
             min: None,
             max: None,
             default: None,
+            validate: info.validate,
             description: None,
+            long_description: None,
+            complete: None,
+            hidden: false,
+            conflicts_with: &[],
+            requires: &[],
         };
         T::parse(&mut value, source, &info, long)?;
 
@@ -179,9 +221,20 @@ impl<T: Flag> Flag for LinkedList<T> {
     fn is_required(info: &FlagInfo<Self>) -> bool {
         info.min.map(|f| f as usize > 0).unwrap_or(false)
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        info.max.is_none()
+            || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Flag + Eq + Hash> Flag for HashSet<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -204,7 +257,13 @@ impl<T: Flag + Eq + Hash> Flag for HashSet<T> {
             min: None,
             max: None,
             default: None,
+            validate: info.validate,
             description: None,
+            long_description: None,
+            complete: None,
+            hidden: false,
+            conflicts_with: &[],
+            requires: &[],
         };
         T::parse(&mut value, source, &info, long)?;
 
@@ -238,9 +297,20 @@ impl<T: Flag + Eq + Hash> Flag for HashSet<T> {
     fn is_required(info: &FlagInfo<Self>) -> bool {
         info.min.map(|f| f as usize > 0).unwrap_or(false)
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        info.max.is_none()
+            || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Flag + Ord> Flag for BTreeSet<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -263,7 +333,13 @@ impl<T: Flag + Ord> Flag for BTreeSet<T> {
             min: None,
             max: None,
             default: None,
+            validate: info.validate,
             description: None,
+            long_description: None,
+            complete: None,
+            hidden: false,
+            conflicts_with: &[],
+            requires: &[],
         };
         T::parse(&mut value, source, &info, long)?;
 
@@ -297,9 +373,20 @@ impl<T: Flag + Ord> Flag for BTreeSet<T> {
     fn is_required(info: &FlagInfo<Self>) -> bool {
         info.min.map(|f| f as usize > 0).unwrap_or(false)
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        info.max.is_none()
+            || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Flag + Ord> Flag for BinaryHeap<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -322,7 +409,13 @@ impl<T: Flag + Ord> Flag for BinaryHeap<T> {
             min: None,
             max: None,
             default: None,
+            validate: info.validate,
             description: None,
+            long_description: None,
+            complete: None,
+            hidden: false,
+            conflicts_with: &[],
+            requires: &[],
         };
         T::parse(&mut value, source, &info, long)?;
 
@@ -356,4 +449,13 @@ impl<T: Flag + Ord> Flag for BinaryHeap<T> {
     fn is_required(info: &FlagInfo<Self>) -> bool {
         info.min.map(|f| f as usize > 0).unwrap_or(false)
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        info.max.is_none()
+            || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
@@ -0,0 +1,13 @@
+use crate::{messages, ColorChoice, FlagInfo};
+
+impl FlagInfo<ColorChoice> {
+    /// Creates the info for the built-in "--color" flag, described to documentation writers
+    pub fn color() -> Self {
+        FlagInfo {
+            long_name: Some("--color"),
+            value: Some("WHEN"),
+            description: Some(|output, _| write!(output, "{}", messages().color_flag())),
+            ..FlagInfo::default()
+        }
+    }
+}
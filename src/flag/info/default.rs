@@ -9,7 +9,13 @@ impl<T: Flag> Default for FlagInfo<T> {
             min: None,
             max: None,
             default: None,
+            validate: None,
             description: None,
+            long_description: None,
+            complete: None,
+            hidden: false,
+            conflicts_with: &[],
+            requires: &[],
         }
     }
 }
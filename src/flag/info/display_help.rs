@@ -1,46 +1,17 @@
-use crate::{Flag, FlagInfo};
+use crate::{Flag, FlagInfo, HelpMode, Style, TextWrap};
 
 impl<T: Flag> FlagInfo<T> {
-    /// Displays the help for this flag on stdout
+    /// Displays the help for this flag on stdout, wrapped to the width of the terminal and styled
+    /// if stdout supports it
     pub fn display_help(&self, short_names: bool, description_offset: usize) {
-        let mut offset = 3;
-        if let Some(short_name) = self.short_name {
-            print!("{}", short_name);
-
-            if self.long_name.is_some() {
-                print!(",");
-            }
-        } else if short_names {
-            print!("   ");
-        } else {
-            offset = 0;
-        }
-
-        if let Some(long_name) = self.long_name {
-            print!("{}", long_name);
-            offset += long_name.len();
-        }
-
-        if let Some(value) = self.value {
-            print!(" {}", value);
-            offset += value.len() + 1;
-        } else if T::takes_value(self) {
-            print!(" VALUE");
-            offset += 6;
-        }
-
-        if let Some(description) = self.description {
-            for _ in 0..description_offset - offset {
-                print!(" ");
-            }
-
-            description(description_offset);
-
-            if let Some(default) = self.default {
-                print!(" [Default: {}]", default().as_display());
-            }
-        }
-
-        println!();
+        self.write_help(
+            &mut std::io::stdout(),
+            short_names,
+            description_offset,
+            TextWrap::detect(),
+            Style::stdout(),
+            HelpMode::Long,
+        )
+        .expect("failed printing to stdout")
     }
 }
@@ -10,7 +10,13 @@ impl<T: Flag> FlagInfo<T> {
             min: self.min,
             max: self.max,
             default: None,
+            validate: None,
             description: self.description,
+            long_description: self.long_description,
+            complete: self.complete,
+            hidden: self.hidden,
+            conflicts_with: self.conflicts_with,
+            requires: self.requires,
         }
     }
 }
@@ -0,0 +1,14 @@
+use crate::{messages, FlagInfo};
+
+impl FlagInfo<bool> {
+    /// Creates the info for the built-in "--help" flag, described to documentation writers, with
+    /// the short name "-h" if `short_name` is set
+    pub fn help(short_name: bool) -> Self {
+        FlagInfo {
+            long_name: Some("--help"),
+            short_name: short_name.then_some("-h"),
+            description: Some(|output, _| write!(output, "{}", messages().help_flag())),
+            ..FlagInfo::default()
+        }
+    }
+}
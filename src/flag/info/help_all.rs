@@ -0,0 +1,12 @@
+use crate::{messages, FlagInfo};
+
+impl FlagInfo<bool> {
+    /// Creates the info for the built-in "--help-all" flag, described to documentation writers
+    pub fn help_all() -> Self {
+        FlagInfo {
+            long_name: Some("--help-all"),
+            description: Some(|output, _| write!(output, "{}", messages().help_all_flag())),
+            ..FlagInfo::default()
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{Flag, FlagInfo};
+
+impl<T: Flag> FlagInfo<T> {
+    /// Copies this info into a new struct for the type wrapped by `T` without copying the default
+    /// value, keeping the validator as both types parse the same values
+    pub fn inner_info<T2: Flag<Value = T::Value>>(&self) -> FlagInfo<T2> {
+        FlagInfo {
+            long_name: self.long_name,
+            short_name: self.short_name,
+            value: self.value,
+            min: self.min,
+            max: self.max,
+            default: None,
+            validate: self.validate,
+            description: self.description,
+            long_description: self.long_description,
+            complete: self.complete,
+            hidden: self.hidden,
+            conflicts_with: self.conflicts_with,
+            requires: self.requires,
+        }
+    }
+}
@@ -1,9 +1,20 @@
 use crate::Flag;
+use std::io::Write;
 
+mod color;
 mod default;
 mod display_help;
 mod display_usage;
 mod drop_default;
+mod help;
+mod help_all;
+mod inner_info;
+mod passed;
+mod validate_value;
+mod version;
+mod write_help;
+mod write_usage;
+mod write_usage_name;
 
 /// Information about a flag
 ///
@@ -28,6 +39,30 @@ pub struct FlagInfo<T: Flag> {
     /// The default value if none is provided by the user
     pub default: Option<fn() -> T>,
 
-    /// A function which writes the description of this flag to stdout, taking a margin for printing new lines
-    pub description: Option<fn(usize)>,
+    /// A function which checks each value parsed for this flag, rejecting it with an error
+    pub validate:
+        Option<fn(&T::Value) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>>>,
+
+    /// A function which writes the description of this flag to an output, taking a margin for
+    /// printing new lines
+    pub description: Option<fn(&mut dyn Write, usize) -> std::io::Result<()>>,
+
+    /// A function which writes the extended description of this flag to an output, taking a
+    /// margin for printing new lines, only included in long help
+    pub long_description: Option<fn(&mut dyn Write, usize) -> std::io::Result<()>>,
+
+    /// A function which lists the candidates for completing the value of this flag, given
+    /// the partial value being completed
+    pub complete: Option<fn(&str) -> Vec<String>>,
+
+    /// Is this flag left out of usage, help and completions unless hidden items are requested?
+    pub hidden: bool,
+
+    /// The long names, including the leading dashes, of the flags which can't be passed with this
+    /// flag
+    pub conflicts_with: &'static [&'static str],
+
+    /// The long names, including the leading dashes, of the flags which must be passed with this
+    /// flag
+    pub requires: &'static [&'static str],
 }
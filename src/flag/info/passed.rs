@@ -0,0 +1,15 @@
+use crate::{Flag, FlagInfo, PassedFlag};
+
+impl<T: Flag> FlagInfo<T> {
+    /// Gets the [`PassedFlag`] recording that this flag was passed
+    pub fn passed(&self) -> PassedFlag {
+        PassedFlag {
+            long_name: self.long_name,
+            name: self
+                .long_name
+                .unwrap_or_else(|| self.short_name.unwrap_or_default()),
+            conflicts_with: self.conflicts_with,
+            requires: self.requires,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{messages, Error, Flag, FlagInfo, Result};
+
+impl<T: Flag> FlagInfo<T> {
+    /// Checks `value` using the validator of this flag, if it has one
+    pub fn validate_value(&self, value: &T::Value, long: bool) -> Result<()> {
+        let validate = match self.validate {
+            Some(validate) => validate,
+            None => return Ok(()),
+        };
+
+        validate(value).map_err(|error| {
+            Error::InvalidFlagValue(
+                if long {
+                    self.long_name
+                } else {
+                    self.short_name
+                }
+                .unwrap(),
+                self.value.unwrap_or_else(|| messages().value()),
+                error,
+            )
+        })
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{messages, FlagInfo};
+
+impl FlagInfo<bool> {
+    /// Creates the info for the built-in "--version" flag, described to documentation writers,
+    /// with the short name "-V" if `short_name` is set
+    pub fn version(short_name: bool) -> Self {
+        FlagInfo {
+            long_name: Some("--version"),
+            short_name: short_name.then_some("-V"),
+            description: Some(|output, _| write!(output, "{}", messages().version_flag())),
+            ..FlagInfo::default()
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    messages::{messages, DisplayWith},
+    text_wrap::{display_width, first_paragraph},
+    Flag, FlagInfo, HelpMode, Style, TextWrap, ValueHint,
+};
+use std::io::Write;
+
+impl<T: Flag> FlagInfo<T> {
+    /// Writes the help for this flag to `output`, wrapping the description with `wrap` and styling
+    /// the names with `style`
+    ///
+    /// Only the first paragraph of the description is written unless `mode` is long, in which case
+    /// the extended description follows it. Flags accepting a fixed set of values list them after
+    /// the description, followed by the flags this flag conflicts with or requires. Nothing is
+    /// written for hidden flags unless `mode` shows them.
+    pub fn write_help(
+        &self,
+        output: &mut dyn Write,
+        short_names: bool,
+        description_offset: usize,
+        wrap: TextWrap,
+        style: Style,
+        mode: HelpMode,
+    ) -> std::io::Result<()> {
+        if self.hidden && !mode.shows_hidden() {
+            return Ok(());
+        }
+
+        let mut offset = 3;
+        if let Some(short_name) = self.short_name {
+            write!(output, "{}", style.literal(short_name))?;
+
+            if self.long_name.is_some() {
+                write!(output, ",")?;
+            }
+        } else if short_names {
+            write!(output, "   ")?;
+        } else {
+            offset = 0;
+        }
+
+        if let Some(long_name) = self.long_name {
+            write!(output, "{}", style.literal(long_name))?;
+            offset += display_width(long_name);
+        }
+
+        if let Some(value) = self.value {
+            write!(output, " {}", value)?;
+            offset += display_width(value) + 1;
+        } else if T::takes_value(self) {
+            let value = messages().value();
+            write!(output, " {}", value)?;
+            offset += display_width(value) + 1;
+        }
+
+        let possible_values = match T::value_hint(self) {
+            ValueHint::Values(values) => Some(values),
+            _ => None,
+        };
+
+        let long_description = self.long_description.filter(|_| mode.shows_long());
+        if self.description.is_some()
+            || long_description.is_some()
+            || possible_values.is_some()
+            || !self.conflicts_with.is_empty()
+            || !self.requires.is_empty()
+        {
+            for _ in offset..description_offset {
+                write!(output, " ")?;
+            }
+
+            let mut buffer = Vec::new();
+            if let Some(description) = self.description {
+                description(&mut buffer, description_offset)?;
+            }
+
+            let mut text = String::from_utf8_lossy(&buffer).into_owned();
+            if !mode.shows_long() {
+                text.truncate(first_paragraph(&text).trim_end().len());
+            }
+
+            if let Some(default) = self.default {
+                let default = default();
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(
+                    &DisplayWith(|f| messages().default_value(f, &default.as_display()))
+                        .to_string(),
+                );
+            }
+
+            if let Some(values) = possible_values {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(&DisplayWith(|f| messages().possible_values(f, values)).to_string());
+            }
+
+            if !self.conflicts_with.is_empty() {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(
+                    &DisplayWith(|f| messages().conflicts_with(f, self.conflicts_with)).to_string(),
+                );
+            }
+
+            if !self.requires.is_empty() {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(&DisplayWith(|f| messages().requires(f, self.requires)).to_string());
+            }
+
+            if let Some(long_description) = long_description {
+                let mut buffer = Vec::new();
+                long_description(&mut buffer, description_offset)?;
+
+                if !text.is_empty() {
+                    text.push_str("\n\n");
+                }
+                text.push_str(&String::from_utf8_lossy(&buffer));
+            }
+
+            wrap.write(output, &text, description_offset)?;
+        }
+
+        writeln!(output)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{Flag, FlagInfo, Style};
+use std::io::Write;
+
+impl<T: Flag> FlagInfo<T> {
+    /// Writes the usage value for this flag to `output`, returning true if this flag is optional
+    /// and won't be written
+    ///
+    /// The names of the flag are styled with `style`. Hidden flags are never written.
+    pub fn write_usage(&self, output: &mut dyn Write, style: Style) -> std::io::Result<bool> {
+        if self.hidden {
+            return Ok(false);
+        }
+
+        if !T::is_required(self) {
+            return Ok(true);
+        }
+
+        self.write_usage_name(output, style)?;
+        write!(output, " ")?;
+
+        Ok(false)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{messages, Flag, FlagInfo, Style};
+use std::io::Write;
+
+impl<T: Flag> FlagInfo<T> {
+    /// Writes the names of this flag followed by its value, if it takes one, to `output`
+    ///
+    /// The names of the flag are styled with `style`.
+    pub fn write_usage_name(&self, output: &mut dyn Write, style: Style) -> std::io::Result<()> {
+        if let Some(short_name) = self.short_name {
+            write!(output, "{}", style.literal(short_name))?;
+            if self.long_name.is_some() {
+                write!(output, "/")?;
+            }
+        }
+
+        if let Some(long_name) = self.long_name {
+            write!(output, "{}", style.literal(long_name))?;
+        }
+
+        if let Some(value) = self.value {
+            write!(output, " {}", value)?;
+        } else if T::takes_value(self) {
+            write!(output, " {}", messages().value())?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
-use crate::{ArgumentSource, DefaultDisplay, Error, Result};
+use crate::{ArgumentSource, DefaultDisplay, Error, Result, ValueHint};
 
 mod info;
+mod passed_flag;
 
 mod array;
 mod bool;
@@ -20,11 +21,15 @@ mod time;
 mod tuple;
 
 pub use info::FlagInfo;
-
-pub(crate) const DEFUALT_FLAG_VALUE: &str = "VALUE";
+pub use passed_flag::PassedFlag;
 
 /// A type which can parsed from a flag
 pub trait Flag: Sized + DefaultDisplay {
+    /// The type of each value parsed for this flag, which is passed to the validator of the flag
+    ///
+    /// Defaults to the flag type itself, as for flags taking a single value.
+    type Value = Self;
+
     /// Parse arguments from `source` to get the value
     fn parse(
         this: &mut Option<Self>,
@@ -54,4 +59,16 @@ pub trait Flag: Sized + DefaultDisplay {
     fn takes_value(info: &FlagInfo<Self>) -> bool {
         true
     }
+
+    /// Can this flag be given multiple times, collecting each value
+    #[allow(unused_variables)]
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        false
+    }
+
+    /// Get the hint describing the values this flag accepts, used for shell completion
+    #[allow(unused_variables)]
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        ValueHint::Any
+    }
 }
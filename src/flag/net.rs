@@ -3,6 +3,8 @@ use crate::{ArgumentSource, Error, Flag, FlagInfo, InvalidAddressError, Result};
 macro_rules! impl_net {
     ($($t: ty),*) => {$(
         impl Flag for $t {
+            type Value = Self;
+
             fn parse(
                 this: &mut Option<Self>,
                 source: &mut dyn ArgumentSource,
@@ -15,9 +17,12 @@ macro_rules! impl_net {
 
                 let value = source.next().ok_or(Error::missing_flag_value(info, long))?;
 
-                *this = Some(value.as_str()?.parse().map_err(|_| {
+                let value = value.as_str()?.parse().map_err(|_| {
                     Error::invalid_flag_value(info, long, InvalidAddressError)
-                })?);
+                })?;
+
+                info.validate_value(&value, long)?;
+                *this = Some(value);
                 Ok(())
             }
         }
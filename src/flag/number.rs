@@ -1,8 +1,10 @@
-use crate::{ArgumentSource, Error, Flag, FlagInfo, InvalidNumberError, Result};
+use crate::{ArgumentSource, Error, Flag, FlagInfo, InvalidNumberError, Result, ValueHint};
 
 macro_rules! impl_number {
     ($($t: ty),*) => {$(
         impl Flag for $t {
+            type Value = Self;
+
             fn parse(
                 this: &mut Option<Self>,
                 source: &mut dyn ArgumentSource,
@@ -32,12 +34,19 @@ macro_rules! impl_number {
                     }
                 }
 
+                info.validate_value(&value, long)?;
                 *this = Some(value);
                 Ok(())
             }
+
+            fn value_hint(_: &FlagInfo<Self>) -> ValueHint {
+                ValueHint::Integer
+            }
         }
 
         impl Flag for std::num::NonZero<$t> {
+            type Value = Self;
+
             fn parse(
                 this: &mut Option<Self>,
                 source: &mut dyn ArgumentSource,
@@ -63,9 +72,14 @@ macro_rules! impl_number {
                     }
                 }
 
+                info.validate_value(&value, long)?;
                 *this = Some(value);
                 Ok(())
             }
+
+            fn value_hint(_: &FlagInfo<Self>) -> ValueHint {
+                ValueHint::Integer
+            }
         }
     )*};
 }
@@ -73,6 +87,8 @@ macro_rules! impl_number {
 macro_rules! impl_atomic {
     ($($t: ty),*) => {$(
         impl Flag for $t {
+            type Value = Self;
+
             fn parse(
                 this: &mut Option<Self>,
                 source: &mut dyn ArgumentSource,
@@ -98,9 +114,15 @@ macro_rules! impl_atomic {
                     }
                 }
 
-                *this = Some(<$t>::new(value));
+                let value = <$t>::new(value);
+                info.validate_value(&value, long)?;
+                *this = Some(value);
                 Ok(())
             }
+
+            fn value_hint(_: &FlagInfo<Self>) -> ValueHint {
+                ValueHint::Integer
+            }
         }
     )*};
 }
@@ -121,6 +143,8 @@ impl_atomic!(
 );
 
 impl Flag for f32 {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -153,12 +177,19 @@ impl Flag for f32 {
             }
         }
 
+        info.validate_value(&value, long)?;
         *this = Some(value);
         Ok(())
     }
+
+    fn value_hint(_: &FlagInfo<Self>) -> ValueHint {
+        ValueHint::Float
+    }
 }
 
 impl Flag for f64 {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -191,7 +222,12 @@ impl Flag for f64 {
             }
         }
 
+        info.validate_value(&value, long)?;
         *this = Some(value);
         Ok(())
     }
+
+    fn value_hint(_: &FlagInfo<Self>) -> ValueHint {
+        ValueHint::Float
+    }
 }
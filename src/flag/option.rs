@@ -1,6 +1,8 @@
-use crate::{ArgumentSource, Error, Flag, FlagInfo, Result};
+use crate::{ArgumentSource, Error, Flag, FlagInfo, Result, ValueHint};
 
 impl<T: Flag> Flag for Option<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -12,7 +14,7 @@ impl<T: Flag> Flag for Option<T> {
         }
 
         let mut new = None;
-        let info = info.drop_default();
+        let info = info.inner_info();
         T::parse(&mut new, source, &info, long)?;
         *this = Some(Some(T::unwrap(new, &info)?));
         Ok(())
@@ -32,4 +34,12 @@ impl<T: Flag> Flag for Option<T> {
     fn takes_value(info: &FlagInfo<Self>) -> bool {
         T::takes_value(&info.drop_default())
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        T::multiple(&info.drop_default())
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
@@ -0,0 +1,39 @@
+use crate::{Error, PassedFlag, Result, UnwrapErrors};
+
+impl PassedFlag {
+    /// Checks that none of the `passed` flags conflict with each other and that every flag they
+    /// require was passed, returning every violation
+    ///
+    /// A pair of flags which both declare the conflict is only reported once.
+    pub fn check(passed: &[PassedFlag]) -> Result<()> {
+        let mut errors = UnwrapErrors::new();
+
+        for (i, flag) in passed.iter().enumerate() {
+            for (j, other) in passed.iter().enumerate() {
+                if i == j || other.long_name.is_none() {
+                    continue;
+                }
+
+                if flag.conflicts_with.contains(&other.long_name.unwrap())
+                    && !(j < i
+                        && flag
+                            .long_name
+                            .is_some_and(|long_name| other.conflicts_with.contains(&long_name)))
+                {
+                    errors.take::<()>(Err(Error::conflicting_flags(flag.name, other.name)));
+                }
+            }
+
+            for required in flag.requires {
+                if !passed
+                    .iter()
+                    .any(|other| other.long_name == Some(*required))
+                {
+                    errors.take::<()>(Err(Error::missing_required_flag(flag.name, required)));
+                }
+            }
+        }
+
+        errors.finish()
+    }
+}
@@ -0,0 +1,17 @@
+mod check;
+
+/// A flag which was passed to a command, with the flags it conflicts with and requires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassedFlag {
+    /// The long name of the flag, including the leading dashes
+    pub long_name: Option<&'static str>,
+
+    /// The name of the flag used in errors, which is the long name if it has one
+    pub name: &'static str,
+
+    /// The long names of the flags which can't be passed with this flag
+    pub conflicts_with: &'static [&'static str],
+
+    /// The long names of the flags which must be passed with this flag
+    pub requires: &'static [&'static str],
+}
@@ -1,7 +1,11 @@
-use crate::{Argument, ArgumentSource, Error, Flag, FlagInfo, InvalidLengthError, Result};
+use crate::{
+    Argument, ArgumentSource, Error, Flag, FlagInfo, InvalidLengthError, Result, ValueHint,
+};
 use std::path::PathBuf;
 
 impl Flag for PathBuf {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -37,7 +41,11 @@ impl Flag for PathBuf {
             }
         }
 
+        info.validate_value(&path, long)?;
         *this = Some(path);
         Ok(())
     }
+    fn value_hint(_: &FlagInfo<Self>) -> ValueHint {
+        ValueHint::Path
+    }
 }
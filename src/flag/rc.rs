@@ -1,7 +1,9 @@
-use crate::{ArgumentSource, Error, Flag, FlagInfo, Result};
+use crate::{ArgumentSource, Error, Flag, FlagInfo, Result, ValueHint};
 use std::{rc::Rc, sync::Arc};
 
 impl<T: Flag> Flag for Rc<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -13,14 +15,24 @@ impl<T: Flag> Flag for Rc<T> {
         }
 
         let mut new = None;
-        let info = info.drop_default();
+        let info = info.inner_info();
         T::parse(&mut new, source, &info, long)?;
         *this = Some(Rc::new(T::unwrap(new, &info)?));
         Ok(())
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        T::multiple(&info.drop_default())
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Flag> Flag for Arc<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -32,7 +44,7 @@ impl<T: Flag> Flag for Arc<T> {
         }
 
         let mut new = None;
-        let info = info.drop_default();
+        let info = info.inner_info();
         T::parse(&mut new, source, &info, long)?;
         *this = Some(Arc::new(T::unwrap(new, &info)?));
         Ok(())
@@ -41,4 +53,12 @@ impl<T: Flag> Flag for Arc<T> {
     fn takes_value(info: &FlagInfo<Self>) -> bool {
         T::takes_value(&info.drop_default())
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        T::multiple(&info.drop_default())
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
@@ -2,6 +2,8 @@ use crate::{Argument, ArgumentSource, Error, Flag, FlagInfo, InvalidLengthError,
 use std::ffi::OsString;
 
 impl Flag for String {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -38,12 +40,15 @@ impl Flag for String {
             }
         }
 
+        info.validate_value(&str, long)?;
         *this = Some(str);
         Ok(())
     }
 }
 
 impl Flag for OsString {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -79,6 +84,7 @@ impl Flag for OsString {
             }
         }
 
+        info.validate_value(&str, long)?;
         *this = Some(str);
         Ok(())
     }
@@ -1,7 +1,9 @@
-use crate::{ArgumentSource, Error, Flag, FlagInfo, Result};
+use crate::{ArgumentSource, Error, Flag, FlagInfo, Result, ValueHint};
 use std::sync::{Mutex, RwLock};
 
 impl<T: Flag> Flag for Mutex<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -13,7 +15,7 @@ impl<T: Flag> Flag for Mutex<T> {
         }
 
         let mut new = None;
-        let info = info.drop_default();
+        let info = info.inner_info();
         T::parse(&mut new, source, &info, long)?;
         *this = Some(Mutex::new(T::unwrap(new, &info)?));
         Ok(())
@@ -22,9 +24,19 @@ impl<T: Flag> Flag for Mutex<T> {
     fn takes_value(info: &FlagInfo<Self>) -> bool {
         T::takes_value(&info.drop_default())
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        T::multiple(&info.drop_default())
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Flag> Flag for RwLock<T> {
+    type Value = T::Value;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -36,7 +48,7 @@ impl<T: Flag> Flag for RwLock<T> {
         }
 
         let mut new = None;
-        let info = info.drop_default();
+        let info = info.inner_info();
         T::parse(&mut new, source, &info, long)?;
         *this = Some(RwLock::new(T::unwrap(new, &info)?));
         Ok(())
@@ -45,4 +57,12 @@ impl<T: Flag> Flag for RwLock<T> {
     fn takes_value(info: &FlagInfo<Self>) -> bool {
         T::takes_value(&info.drop_default())
     }
+
+    fn multiple(info: &FlagInfo<Self>) -> bool {
+        T::multiple(&info.drop_default())
+    }
+
+    fn value_hint(info: &FlagInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
@@ -2,6 +2,8 @@ use crate::{ArgumentSource, Error, Flag, FlagInfo, InvalidDurationError, Result}
 use std::time::Duration;
 
 impl Flag for Duration {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -20,7 +22,9 @@ impl Flag for Duration {
             ));
         }
 
-        *this = Some(Duration::from_secs_f64(duration));
+        let duration = Duration::from_secs_f64(duration);
+        info.validate_value(&duration, long)?;
+        *this = Some(duration);
         Ok(())
     }
 }
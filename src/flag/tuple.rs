@@ -1,6 +1,8 @@
 use crate::{ArgumentSource, Error, Flag, FlagInfo, Result};
 
 impl<T1: Flag, T2: Flag> Flag for (T1, T2) {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -18,12 +20,16 @@ impl<T1: Flag, T2: Flag> Flag for (T1, T2) {
         T1::parse(&mut new1, source, &info1, long)?;
         T2::parse(&mut new2, source, &info2, long)?;
 
-        *this = Some((T1::unwrap(new1, &info1)?, T2::unwrap(new2, &info2)?));
+        let value = (T1::unwrap(new1, &info1)?, T2::unwrap(new2, &info2)?);
+        info.validate_value(&value, long)?;
+        *this = Some(value);
         Ok(())
     }
 }
 
 impl<T1: Flag, T2: Flag, T3: Flag> Flag for (T1, T2, T3) {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -42,16 +48,20 @@ impl<T1: Flag, T2: Flag, T3: Flag> Flag for (T1, T2, T3) {
         T2::parse(&mut new2, source, &info2, long)?;
         T3::parse(&mut new3, source, &info3, long)?;
 
-        *this = Some((
+        let value = (
             T1::unwrap(new1, &info1)?,
             T2::unwrap(new2, &info2)?,
             T3::unwrap(new3, &info3)?,
-        ));
+        );
+        info.validate_value(&value, long)?;
+        *this = Some(value);
         Ok(())
     }
 }
 
 impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag> Flag for (T1, T2, T3, T4) {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -72,17 +82,21 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag> Flag for (T1, T2, T3, T4) {
         T3::parse(&mut new3, source, &info3, long)?;
         T4::parse(&mut new4, source, &info4, long)?;
 
-        *this = Some((
+        let value = (
             T1::unwrap(new1, &info1)?,
             T2::unwrap(new2, &info2)?,
             T3::unwrap(new3, &info3)?,
             T4::unwrap(new4, &info4)?,
-        ));
+        );
+        info.validate_value(&value, long)?;
+        *this = Some(value);
         Ok(())
     }
 }
 
 impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag> Flag for (T1, T2, T3, T4, T5) {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -105,18 +119,22 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag> Flag for (T1, T2, T3, T4,
         T4::parse(&mut new4, source, &info4, long)?;
         T5::parse(&mut new5, source, &info5, long)?;
 
-        *this = Some((
+        let value = (
             T1::unwrap(new1, &info1)?,
             T2::unwrap(new2, &info2)?,
             T3::unwrap(new3, &info3)?,
             T4::unwrap(new4, &info4)?,
             T5::unwrap(new5, &info5)?,
-        ));
+        );
+        info.validate_value(&value, long)?;
+        *this = Some(value);
         Ok(())
     }
 }
 
 impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag> Flag for (T1, T2, T3, T4, T5, T6) {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -142,14 +160,16 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag> Flag for (T1, T
         T5::parse(&mut new5, source, &info5, long)?;
         T6::parse(&mut new6, source, &info6, long)?;
 
-        *this = Some((
+        let value = (
             T1::unwrap(new1, &info1)?,
             T2::unwrap(new2, &info2)?,
             T3::unwrap(new3, &info3)?,
             T4::unwrap(new4, &info4)?,
             T5::unwrap(new5, &info5)?,
             T6::unwrap(new6, &info6)?,
-        ));
+        );
+        info.validate_value(&value, long)?;
+        *this = Some(value);
         Ok(())
     }
 }
@@ -157,6 +177,8 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag> Flag for (T1, T
 impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag, T7: Flag> Flag
     for (T1, T2, T3, T4, T5, T6, T7)
 {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -184,7 +206,7 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag, T7: Flag> Flag
         T6::parse(&mut new6, source, &info6, long)?;
         T7::parse(&mut new7, source, &info7, long)?;
 
-        *this = Some((
+        let value = (
             T1::unwrap(new1, &info1)?,
             T2::unwrap(new2, &info2)?,
             T3::unwrap(new3, &info3)?,
@@ -192,7 +214,9 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag, T7: Flag> Flag
             T5::unwrap(new5, &info5)?,
             T6::unwrap(new6, &info6)?,
             T7::unwrap(new7, &info7)?,
-        ));
+        );
+        info.validate_value(&value, long)?;
+        *this = Some(value);
         Ok(())
     }
 }
@@ -200,6 +224,8 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag, T7: Flag> Flag
 impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag, T7: Flag, T8: Flag> Flag
     for (T1, T2, T3, T4, T5, T6, T7, T8)
 {
+    type Value = Self;
+
     fn parse(
         this: &mut Option<Self>,
         source: &mut dyn ArgumentSource,
@@ -229,7 +255,7 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag, T7: Flag, T8: F
         T7::parse(&mut new7, source, &info7, long)?;
         T8::parse(&mut new8, source, &info8, long)?;
 
-        *this = Some((
+        let value = (
             T1::unwrap(new1, &info1)?,
             T2::unwrap(new2, &info2)?,
             T3::unwrap(new3, &info3)?,
@@ -238,7 +264,9 @@ impl<T1: Flag, T2: Flag, T3: Flag, T4: Flag, T5: Flag, T6: Flag, T7: Flag, T8: F
             T6::unwrap(new6, &info6)?,
             T7::unwrap(new7, &info7)?,
             T8::unwrap(new8, &info8)?,
-        ));
+        );
+        info.validate_value(&value, long)?;
+        *this = Some(value);
         Ok(())
     }
 }
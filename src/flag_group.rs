@@ -1,4 +1,8 @@
-use crate::{ArgumentSource, Result};
+use crate::{
+    ArgumentSource, DocumentWriter, FlagGroupSpec, HelpMode, PassedFlag, Result, SpecWriter, Style,
+    TextWrap,
+};
+use std::io::Write;
 
 /// A group of flags that can be used in a larger command or flag group
 pub trait FlagGroup: Sized {
@@ -27,10 +31,59 @@ pub trait FlagGroup: Sized {
     /// Unwrap the flags in this group
     fn unwrap(this: Self::InProgress) -> Result<Self>;
 
+    /// Write the required flags' help usage to `output` styled with `style`, returning if there
+    /// are any optional flags in the group
+    fn write_help_usage(output: &mut dyn Write, style: Style) -> std::io::Result<bool>;
+
+    /// Write the help information of the contained flags to `output`, wrapping descriptions with
+    /// `wrap`, styling flag names with `style` and including hidden flags if `mode` shows them
+    fn write_help(
+        output: &mut dyn Write,
+        wrap: TextWrap,
+        style: Style,
+        mode: HelpMode,
+    ) -> std::io::Result<()>;
+
+    /// Add the long names of the contained flags which aren't hidden to `names`, used for
+    /// suggesting a flag when an unknown one is passed
+    #[allow(unused_variables)]
+    fn long_names(names: &mut Vec<&'static str>) {}
+
+    /// Add the flags in `this` which were passed, including those in child flag groups, to
+    /// `passed` for checking the relationships between flags
+    #[allow(unused_variables)]
+    fn passed_flags(this: &Self::InProgress, passed: &mut Vec<PassedFlag>) {}
+
+    /// Write the documentation entries of the contained flags to `writer`
+    #[allow(unused_variables)]
+    fn write_document<W: DocumentWriter>(writer: &mut W) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Get the description of the flags in this group as data, with an empty title
+    fn spec() -> FlagGroupSpec {
+        let mut writer = SpecWriter::new();
+        writer.flag_group::<Self>("").unwrap();
+        Self::write_document(&mut writer).unwrap();
+        writer.finish_flag_group()
+    }
+
     /// Print the required flags' help usage to stdout, returning if there are any optional flags
     /// in the group
-    fn print_help_usage() -> bool;
+    fn print_help_usage() -> bool {
+        Self::write_help_usage(&mut std::io::stdout(), Style::stdout())
+            .expect("failed printing to stdout")
+    }
 
-    /// Print the help information of the contained flags to stdout
-    fn print_help();
+    /// Print the help information of the contained flags to stdout, wrapped to the width of the
+    /// terminal and styled if stdout supports it
+    fn print_help() {
+        Self::write_help(
+            &mut std::io::stdout(),
+            TextWrap::detect(),
+            Style::stdout(),
+            HelpMode::Long,
+        )
+        .expect("failed printing to stdout")
+    }
 }
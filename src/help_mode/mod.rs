@@ -0,0 +1,18 @@
+mod shows_hidden;
+mod shows_long;
+
+/// Selects what is included when writing help
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HelpMode {
+    /// Write a summary with the first paragraph of each description, as requested by "-h"
+    Short,
+
+    /// Write the help for everything which isn't hidden, including extended descriptions,
+    /// examples and the epilogue, as requested by "--help"
+    #[default]
+    Long,
+
+    /// Write the long help for everything, including hidden flags and positionals, as requested
+    /// by "--help-all"
+    All,
+}
@@ -0,0 +1,8 @@
+use crate::HelpMode;
+
+impl HelpMode {
+    /// Does this mode include hidden flags and positionals?
+    pub fn shows_hidden(self) -> bool {
+        self == HelpMode::All
+    }
+}
@@ -0,0 +1,8 @@
+use crate::HelpMode;
+
+impl HelpMode {
+    /// Does this mode include extended descriptions, examples and the epilogue?
+    pub fn shows_long(self) -> bool {
+        self != HelpMode::Short
+    }
+}
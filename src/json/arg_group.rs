@@ -0,0 +1,18 @@
+use super::{JsonObject, JsonValue};
+use crate::{ArgGroup, ArgGroupRule};
+
+impl JsonValue for ArgGroup {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        let mut object = JsonObject::new(output, indent)?;
+        object.field(
+            "rule",
+            match self.rule {
+                ArgGroupRule::ExactlyOne => "exactly_one",
+                ArgGroupRule::AtLeastOne => "at_least_one",
+                ArgGroupRule::AtMostOne => "at_most_one",
+            },
+        )?;
+        object.field("members", self.members)?;
+        object.finish()
+    }
+}
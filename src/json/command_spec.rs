@@ -0,0 +1,33 @@
+use super::{JsonObject, JsonValue};
+use crate::CommandSpec;
+
+impl CommandSpec {
+    /// Write this spec to `output` as a JSON document
+    ///
+    /// Each command is an object with its name, descriptions, version, positionals, flags, arg
+    /// groups, flag groups and sub-commands. Each flag and positional has a "type" of "string", "integer",
+    /// "float", "path" or "enum" taken from its value hint, with the accepted "values" of enums,
+    /// and whether it is a "list" collecting multiple values. Flags which don't take a value have
+    /// the type "boolean". Each arg group has a "rule" of "exactly_one", "at_least_one" or
+    /// "at_most_one" and its "members".
+    pub fn write_json(&self, output: &mut dyn std::io::Write) -> std::io::Result<()> {
+        self.write_value(output, 0)?;
+        writeln!(output)
+    }
+}
+
+impl JsonValue for CommandSpec {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        let mut object = JsonObject::new(output, indent)?;
+        object.field("name", &self.name)?;
+        object.field("description", &self.description)?;
+        object.field("long_description", &self.long_description)?;
+        object.field("version", &self.version)?;
+        object.field("positionals", &self.positionals)?;
+        object.field("flags", &self.flags)?;
+        object.field("arg_groups", &self.arg_groups)?;
+        object.field("flag_groups", &self.flag_groups)?;
+        object.field("sub_commands", &self.sub_commands)?;
+        object.finish()
+    }
+}
@@ -0,0 +1,13 @@
+use super::{JsonObject, JsonValue};
+use crate::FlagGroupSpec;
+
+impl JsonValue for FlagGroupSpec {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        let mut object = JsonObject::new(output, indent)?;
+        object.field("title", &self.title)?;
+        object.field("flags", &self.flags)?;
+        object.field("arg_groups", &self.arg_groups)?;
+        object.field("flag_groups", &self.flag_groups)?;
+        object.finish()
+    }
+}
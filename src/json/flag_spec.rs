@@ -0,0 +1,23 @@
+use super::{write_value_type, JsonObject, JsonValue};
+use crate::FlagSpec;
+
+impl JsonValue for FlagSpec {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        let mut object = JsonObject::new(output, indent)?;
+        object.field("long_name", &self.long_name)?;
+        object.field("short_name", &self.short_name)?;
+        object.field("value", &self.value)?;
+        write_value_type(&mut object, self.value_hint, self.value.is_some())?;
+        object.field("list", &self.multiple)?;
+        object.field("min", &self.min)?;
+        object.field("max", &self.max)?;
+        object.field("default", &self.default)?;
+        object.field("description", &self.description)?;
+        object.field("long_description", &self.long_description)?;
+        object.field("required", &self.required)?;
+        object.field("conflicts_with", self.conflicts_with)?;
+        object.field("requires", self.requires)?;
+        object.field("hidden", &self.hidden)?;
+        object.finish()
+    }
+}
@@ -0,0 +1,21 @@
+use super::JsonObject;
+use crate::json::{write_string, JsonValue};
+
+impl<'a> JsonObject<'a> {
+    /// Writes the field `key` with `value`
+    pub(in crate::json) fn field<T: JsonValue + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> std::io::Result<()> {
+        if !self.empty {
+            self.output.write_all(b",")?;
+        }
+        self.empty = false;
+
+        write!(self.output, "\n{:1$}", "", (self.indent + 1) * 2)?;
+        write_string(self.output, key)?;
+        self.output.write_all(b": ")?;
+        value.write_value(self.output, self.indent + 1)
+    }
+}
@@ -0,0 +1,12 @@
+use super::JsonObject;
+
+impl<'a> JsonObject<'a> {
+    /// Ends the object, closing it on its own line if it has any fields
+    pub(in crate::json) fn finish(self) -> std::io::Result<()> {
+        if self.empty {
+            return self.output.write_all(b"}");
+        }
+
+        write!(self.output, "\n{:1$}}}", "", self.indent * 2)
+    }
+}
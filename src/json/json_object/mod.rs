@@ -0,0 +1,15 @@
+mod field;
+mod finish;
+mod new;
+
+/// Writes the fields of a JSON object, one per line
+pub(super) struct JsonObject<'a> {
+    /// The output the object is written to
+    output: &'a mut dyn std::io::Write,
+
+    /// The indent level of the line the object starts on
+    indent: usize,
+
+    /// Has no field been written yet?
+    empty: bool,
+}
@@ -0,0 +1,17 @@
+use super::JsonObject;
+
+impl<'a> JsonObject<'a> {
+    /// Starts a new [`JsonObject`] in `output` at the indent level `indent`
+    pub(in crate::json) fn new(
+        output: &'a mut dyn std::io::Write,
+        indent: usize,
+    ) -> std::io::Result<Self> {
+        output.write_all(b"{")?;
+
+        Ok(JsonObject {
+            output,
+            indent,
+            empty: true,
+        })
+    }
+}
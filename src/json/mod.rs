@@ -0,0 +1,20 @@
+use json_object::JsonObject;
+use string::write_string;
+use value_type::write_value_type;
+
+mod json_object;
+mod string;
+mod value_type;
+
+mod arg_group;
+mod command_spec;
+mod flag_group_spec;
+mod flag_spec;
+mod positional_spec;
+mod primitives;
+
+/// A value which can be written as JSON
+pub(crate) trait JsonValue {
+    /// Write this value to `output`, with lines after the first indented by `indent` levels
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()>;
+}
@@ -0,0 +1,19 @@
+use super::{write_value_type, JsonObject, JsonValue};
+use crate::PositionalSpec;
+
+impl JsonValue for PositionalSpec {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        let mut object = JsonObject::new(output, indent)?;
+        object.field("value", self.value)?;
+        write_value_type(&mut object, self.value_hint, true)?;
+        object.field("list", &self.multiple)?;
+        object.field("min", &self.min)?;
+        object.field("max", &self.max)?;
+        object.field("default", &self.default)?;
+        object.field("description", &self.description)?;
+        object.field("long_description", &self.long_description)?;
+        object.field("required", &self.required)?;
+        object.field("hidden", &self.hidden)?;
+        object.finish()
+    }
+}
@@ -0,0 +1,71 @@
+use super::{write_string, JsonValue};
+
+impl JsonValue for str {
+    fn write_value(&self, output: &mut dyn std::io::Write, _: usize) -> std::io::Result<()> {
+        write_string(output, self)
+    }
+}
+
+impl JsonValue for String {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        self.as_str().write_value(output, indent)
+    }
+}
+
+impl JsonValue for bool {
+    fn write_value(&self, output: &mut dyn std::io::Write, _: usize) -> std::io::Result<()> {
+        write!(output, "{}", self)
+    }
+}
+
+/// Infinite and NaN values have no JSON representation, so they are written as null
+impl JsonValue for f64 {
+    fn write_value(&self, output: &mut dyn std::io::Write, _: usize) -> std::io::Result<()> {
+        if self.is_finite() {
+            write!(output, "{}", self)
+        } else {
+            output.write_all(b"null")
+        }
+    }
+}
+
+impl<T: JsonValue + ?Sized> JsonValue for &T {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        T::write_value(self, output, indent)
+    }
+}
+
+impl<T: JsonValue> JsonValue for Option<T> {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        match self {
+            Some(value) => value.write_value(output, indent),
+            None => output.write_all(b"null"),
+        }
+    }
+}
+
+/// Arrays are written with one element per line
+impl<T: JsonValue> JsonValue for [T] {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        if self.is_empty() {
+            return output.write_all(b"[]");
+        }
+
+        output.write_all(b"[")?;
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                output.write_all(b",")?;
+            }
+
+            write!(output, "\n{:1$}", "", (indent + 1) * 2)?;
+            value.write_value(output, indent + 1)?;
+        }
+        write!(output, "\n{:1$}]", "", indent * 2)
+    }
+}
+
+impl<T: JsonValue> JsonValue for Vec<T> {
+    fn write_value(&self, output: &mut dyn std::io::Write, indent: usize) -> std::io::Result<()> {
+        self.as_slice().write_value(output, indent)
+    }
+}
@@ -0,0 +1,19 @@
+/// Writes `string` to `output` as a quoted JSON string, escaping quotes, backslashes and control
+/// characters
+pub(super) fn write_string(output: &mut dyn std::io::Write, string: &str) -> std::io::Result<()> {
+    output.write_all(b"\"")?;
+
+    for c in string.chars() {
+        match c {
+            '"' => output.write_all(b"\\\"")?,
+            '\\' => output.write_all(b"\\\\")?,
+            '\n' => output.write_all(b"\\n")?,
+            '\r' => output.write_all(b"\\r")?,
+            '\t' => output.write_all(b"\\t")?,
+            c if c.is_control() => write!(output, "\\u{:04x}", c as u32)?,
+            c => write!(output, "{}", c)?,
+        }
+    }
+
+    output.write_all(b"\"")
+}
@@ -0,0 +1,25 @@
+use super::JsonObject;
+use crate::ValueHint;
+
+/// Writes the "type" and "values" fields describing the values of an argument with `hint` to
+/// `object`
+///
+/// Arguments which don't take a value have the type "boolean", and "values" is only set for
+/// arguments taking one of a fixed set of values.
+pub(super) fn write_value_type(
+    object: &mut JsonObject,
+    hint: ValueHint,
+    takes_value: bool,
+) -> std::io::Result<()> {
+    let (r#type, values) = match hint {
+        _ if !takes_value => ("boolean", None),
+        ValueHint::Any => ("string", None),
+        ValueHint::Integer => ("integer", None),
+        ValueHint::Float => ("float", None),
+        ValueHint::Path => ("path", None),
+        ValueHint::Values(values) => ("enum", Some(values)),
+    };
+
+    object.field("type", r#type)?;
+    object.field("values", &values)
+}
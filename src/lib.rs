@@ -10,25 +10,60 @@
 #![feature(maybe_uninit_array_assume_init)]
 #![feature(box_into_inner)]
 #![feature(maybe_uninit_uninit_array_transpose)]
+#![feature(associated_type_defaults)]
 
+mod arg_group;
 mod argument;
+mod color_choice;
 mod command;
+mod command_list;
+mod completion_writer;
 mod default_display;
+mod document_writer;
 mod error;
 mod flag;
 mod flag_group;
+mod help_mode;
+mod json;
+mod man_writer;
+mod markdown_writer;
+mod messages;
+mod parse_outcome;
 mod positional;
+mod shell;
+mod spec_writer;
+mod style;
+mod text_wrap;
+mod usage_writer;
+mod value_hint;
+mod version_sub_command;
 
+pub use arg_group::{ArgGroup, ArgGroupRule};
 pub use argument::{
     ArgsOsSource, ArgsSource, Argument, ArgumentSource, OsStrArgument, StrArgument,
 };
+pub use color_choice::ColorChoice;
 pub use command::Command;
+pub use command_list::CommandList;
+pub use completion_writer::CompletionWriter;
 pub use default_display::DefaultDisplay;
+pub use document_writer::DocumentWriter;
 pub use error::{
-    Error, InvalidAddressError, InvalidCharError, InvalidDurationError, InvalidLengthError,
-    InvalidNumberError, Result, UnexpectedError,
+    Error, ErrorReport, InvalidAddressError, InvalidCharError, InvalidDurationError,
+    InvalidLengthError, InvalidNumberError, Result, UnexpectedError, UnwrapErrors,
 };
-pub use flag::{Flag, FlagInfo};
+pub use flag::{Flag, FlagInfo, PassedFlag};
 pub use flag_group::FlagGroup;
+pub use help_mode::HelpMode;
 pub use macros::{Command, FlagGroup, Positional};
-pub use positional::{Positional, PositionalInfo, PositionalResult};
+pub use man_writer::ManWriter;
+pub use markdown_writer::MarkdownWriter;
+pub use messages::{messages, set_messages, English, Messages};
+pub use parse_outcome::ParseOutcome;
+pub use positional::{Positional, PositionalInfo, PositionalResult, SubCommandInfo};
+pub use shell::Shell;
+pub use spec_writer::{CommandSpec, FlagGroupSpec, FlagSpec, PositionalSpec, SpecWriter};
+pub use style::{Style, Styled};
+pub use text_wrap::TextWrap;
+pub use value_hint::ValueHint;
+pub use version_sub_command::VersionSubCommand;
@@ -0,0 +1,189 @@
+use super::{escape, ManWriter};
+use crate::{
+    messages::{messages, DisplayWith},
+    ArgGroup, Command, CommandList, DocumentWriter, Flag, FlagInfo, Positional, PositionalInfo,
+    Style,
+};
+use std::io::Write;
+
+impl<'a> DocumentWriter for ManWriter<'a> {
+    /// Root commands write the title, NAME, SYNOPSIS and DESCRIPTION sections. Sub-commands start
+    /// a section of their own containing their usage and description, and their sections are
+    /// written as sub-sections.
+    fn command<C: Command>(
+        &mut self,
+        name: &str,
+        command_list: &CommandList,
+    ) -> std::io::Result<CommandList> {
+        let command_list = match command_list.program() {
+            None if command_list.is_root() => CommandList::with_program(name),
+            _ => command_list.clone(),
+        };
+
+        let mut description = Vec::new();
+        C::write_description(&mut description, 0)?;
+        let mut long_description = Vec::new();
+        C::write_long_description(&mut long_description, 0)?;
+        if !long_description.is_empty() {
+            description.push(b'\n');
+            description.append(&mut long_description);
+        }
+        let description = String::from_utf8_lossy(&description);
+
+        let mut usage = Vec::new();
+        C::write_usage(&mut usage, &command_list, Style::default())?;
+        let usage = String::from_utf8_lossy(&usage);
+        let command = command_list.to_string();
+        let arguments = usage
+            .strip_prefix(command.as_str())
+            .unwrap_or(&usage)
+            .trim();
+
+        self.sub_command = !command_list.is_root();
+        if self.sub_command {
+            writeln!(self.output, ".SH \"{}\"", escape(&command.to_uppercase()))?;
+        } else {
+            let version = C::version();
+            writeln!(
+                self.output,
+                ".TH \"{}\" \"1\" \"\" \"{}\"",
+                escape(&command.to_uppercase()),
+                escape(version.trim())
+            )?;
+
+            writeln!(self.output, ".SH NAME")?;
+            match description
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+            {
+                Some(summary) => {
+                    writeln!(self.output, "{} \\- {}", escape(&command), escape(summary))?
+                }
+                None => writeln!(self.output, "{}", escape(&command))?,
+            }
+
+            writeln!(self.output, ".SH SYNOPSIS")?;
+        }
+
+        writeln!(
+            self.output,
+            "\\fB{}\\fR {}",
+            escape(&command),
+            escape(arguments)
+        )?;
+
+        if !description.trim().is_empty() {
+            if self.sub_command {
+                writeln!(self.output, ".PP")?;
+            } else {
+                writeln!(self.output, ".SH DESCRIPTION")?;
+            }
+
+            self.text(&description)?;
+        }
+
+        Ok(command_list)
+    }
+
+    /// The sections of sub-commands are written as sub-sections of the sub-command's section. Titles
+    /// are written without the colon ending help headers.
+    fn section(&mut self, title: &str) -> std::io::Result<()> {
+        writeln!(
+            self.output,
+            "{} \"{}\"",
+            if self.sub_command { ".SS" } else { ".SH" },
+            escape(&title.trim_end_matches(':').to_uppercase())
+        )
+    }
+
+    fn positional<T: Positional>(&mut self, info: &PositionalInfo<T>) -> std::io::Result<()> {
+        if info.hidden {
+            return Ok(());
+        }
+
+        self.item(&format!("\\fI{}\\fR", escape(info.value)))?;
+
+        if let Some(description) = info.description {
+            let mut buffer = Vec::new();
+            description(&mut buffer, 0)?;
+            self.text(&String::from_utf8_lossy(&buffer))?;
+        }
+
+        if let Some(long_description) = info.long_description {
+            let mut buffer = Vec::new();
+            long_description(&mut buffer, 0)?;
+
+            if info.description.is_some() {
+                writeln!(self.output, ".sp")?;
+            }
+            self.text(&String::from_utf8_lossy(&buffer))?;
+        }
+
+        Ok(())
+    }
+
+    fn flag<T: Flag>(&mut self, info: &FlagInfo<T>) -> std::io::Result<()> {
+        if info.hidden {
+            return Ok(());
+        }
+
+        let mut term = String::new();
+        if let Some(short_name) = info.short_name {
+            term.push_str(&format!("\\fB{}\\fR", escape(short_name)));
+
+            if info.long_name.is_some() {
+                term.push_str(", ");
+            }
+        }
+
+        if let Some(long_name) = info.long_name {
+            term.push_str(&format!("\\fB{}\\fR", escape(long_name)));
+        }
+
+        if let Some(value) = info.value {
+            term.push_str(&format!(" \\fI{}\\fR", escape(value)));
+        } else if T::takes_value(info) {
+            term.push_str(&format!(" \\fI{}\\fR", messages().value()));
+        }
+
+        self.item(&term)?;
+
+        if let Some(description) = info.description {
+            let mut buffer = Vec::new();
+            description(&mut buffer, 0)?;
+
+            if let Some(default) = info.default {
+                let default = default();
+                write!(
+                    buffer,
+                    " {}",
+                    DisplayWith(|f| messages().default_value(f, &default.as_display()))
+                )?;
+            }
+
+            self.text(&String::from_utf8_lossy(&buffer))?;
+        }
+
+        if let Some(long_description) = info.long_description {
+            let mut buffer = Vec::new();
+            long_description(&mut buffer, 0)?;
+
+            if info.description.is_some() {
+                writeln!(self.output, ".sp")?;
+            }
+            self.text(&String::from_utf8_lossy(&buffer))?;
+        }
+
+        Ok(())
+    }
+
+    /// Arg groups are written as a paragraph following the list of flags
+    fn arg_group(&mut self, arg_group: &ArgGroup) -> std::io::Result<()> {
+        writeln!(self.output, ".PP")?;
+        self.text(
+            &DisplayWith(|f| messages().arg_group(f, arg_group.rule, arg_group.members))
+                .to_string(),
+        )
+    }
+}
@@ -0,0 +1,17 @@
+/// Escapes `text` so roff displays it literally
+pub(super) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\e"),
+            '-' => escaped.push_str("\\-"),
+            _ => escaped.push(c),
+        }
+    }
+
+    if escaped.starts_with('.') || escaped.starts_with('\'') {
+        escaped.insert_str(0, "\\&");
+    }
+
+    escaped
+}
@@ -0,0 +1,11 @@
+use crate::ManWriter;
+
+impl<'a> ManWriter<'a> {
+    /// Starts a new item in a list, labeled by `term`
+    ///
+    /// `term` must already be formatted for roff
+    pub(super) fn item(&mut self, term: &str) -> std::io::Result<()> {
+        writeln!(self.output, ".TP")?;
+        writeln!(self.output, "{}", term)
+    }
+}
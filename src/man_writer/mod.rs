@@ -0,0 +1,17 @@
+use escape::escape;
+use std::io::Write;
+
+mod document_writer;
+mod escape;
+mod item;
+mod new;
+mod text;
+
+/// Writes a roff man page describing commands
+pub struct ManWriter<'a> {
+    /// The output to write the page to
+    output: &'a mut dyn Write,
+
+    /// Is the command currently being written a sub-command?
+    sub_command: bool,
+}
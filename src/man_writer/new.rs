@@ -0,0 +1,12 @@
+use crate::ManWriter;
+use std::io::Write;
+
+impl<'a> ManWriter<'a> {
+    /// Creates a new [`ManWriter`] writing a man page to `output`
+    pub fn new(output: &'a mut dyn Write) -> Self {
+        ManWriter {
+            output,
+            sub_command: false,
+        }
+    }
+}
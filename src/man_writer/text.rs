@@ -0,0 +1,29 @@
+use super::{escape, ManWriter};
+
+impl<'a> ManWriter<'a> {
+    /// Writes `text` as a paragraph, breaking the line at each new line in `text` and spacing
+    /// out the paragraphs separated by blank lines
+    pub(super) fn text(&mut self, text: &str) -> std::io::Result<()> {
+        let mut first = true;
+        let mut blank = false;
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() {
+                blank = true;
+                continue;
+            }
+
+            if first {
+                first = false;
+            } else if blank {
+                writeln!(self.output, ".sp")?;
+            } else {
+                writeln!(self.output, ".br")?;
+            }
+            blank = false;
+
+            writeln!(self.output, "{}", escape(line))?;
+        }
+
+        Ok(())
+    }
+}
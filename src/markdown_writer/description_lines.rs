@@ -0,0 +1,18 @@
+/// Gets the non-empty lines of the description written by `description`, trimmed of whitespace
+pub(super) fn description_lines<
+    F: FnOnce(&mut dyn std::io::Write, usize) -> std::io::Result<()>,
+>(
+    description: Option<F>,
+) -> std::io::Result<Vec<String>> {
+    let mut buffer = Vec::new();
+    if let Some(description) = description {
+        description(&mut buffer, 0)?;
+    }
+
+    Ok(String::from_utf8_lossy(&buffer)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect())
+}
@@ -0,0 +1,156 @@
+use super::{description_lines, escape_cell, MarkdownWriter};
+use crate::{
+    messages::{messages, DisplayWith},
+    ArgGroup, Command, CommandList, DocumentWriter, Flag, FlagInfo, Positional, PositionalInfo,
+    Style,
+};
+
+impl<'a> DocumentWriter for MarkdownWriter<'a> {
+    /// Root commands write a top-level heading, their description, and their usage in a code
+    /// block. Sub-commands do the same under a second-level heading, and their sections are
+    /// written one level lower.
+    fn command<C: Command>(
+        &mut self,
+        name: &str,
+        command_list: &CommandList,
+    ) -> std::io::Result<CommandList> {
+        let command_list = match command_list.program() {
+            None if command_list.is_root() => CommandList::with_program(name),
+            _ => command_list.clone(),
+        };
+
+        let mut description = Vec::new();
+        C::write_description(&mut description, 0)?;
+        let mut long_description = Vec::new();
+        C::write_long_description(&mut long_description, 0)?;
+        if !long_description.is_empty() {
+            description.push(b'\n');
+            description.append(&mut long_description);
+        }
+        let description = String::from_utf8_lossy(&description);
+
+        let mut usage = Vec::new();
+        C::write_usage(&mut usage, &command_list, Style::default())?;
+        let usage = String::from_utf8_lossy(&usage);
+
+        self.sub_command = !command_list.is_root();
+        self.heading(
+            if self.sub_command { 2 } else { 1 },
+            &format!("`{}`", command_list),
+        )?;
+
+        let mut paragraph = false;
+        for line in description.lines().map(str::trim) {
+            if line.is_empty() {
+                if paragraph {
+                    writeln!(self.output)?;
+                    paragraph = false;
+                }
+            } else {
+                writeln!(self.output, "{}", line)?;
+                paragraph = true;
+            }
+        }
+
+        if paragraph {
+            writeln!(self.output)?;
+        }
+
+        writeln!(self.output, "```text")?;
+        writeln!(self.output, "{}", usage.trim())?;
+        writeln!(self.output, "```")?;
+
+        Ok(command_list)
+    }
+
+    /// The sections of sub-commands are written one heading level below the sub-command. Titles are
+    /// written without the colon ending help headers.
+    fn section(&mut self, title: &str) -> std::io::Result<()> {
+        self.heading(
+            if self.sub_command { 3 } else { 2 },
+            title.trim_end_matches(':'),
+        )
+    }
+
+    /// Positionals are written as list items
+    fn positional<T: Positional>(&mut self, info: &PositionalInfo<T>) -> std::io::Result<()> {
+        if info.hidden {
+            return Ok(());
+        }
+
+        write!(
+            self.output,
+            "- `{}` ({}{})",
+            info.value,
+            if T::is_required(info) {
+                "required"
+            } else {
+                "optional"
+            },
+            if T::multiple(info) { ", multiple" } else { "" }
+        )?;
+
+        let mut description = description_lines(info.description)?;
+        description.append(&mut description_lines(info.long_description)?);
+        if !description.is_empty() {
+            write!(self.output, ": {}", description.join(" "))?;
+        }
+
+        writeln!(self.output)
+    }
+
+    /// Flags are written as rows of a table, starting the table on the first flag in a section
+    fn flag<T: Flag>(&mut self, info: &FlagInfo<T>) -> std::io::Result<()> {
+        if info.hidden {
+            return Ok(());
+        }
+
+        if !self.in_table {
+            writeln!(
+                self.output,
+                "| Short | Long | Value | Default | Description |"
+            )?;
+            writeln!(self.output, "| --- | --- | --- | --- | --- |")?;
+            self.in_table = true;
+        }
+
+        let code = |text: Option<&str>| match text {
+            Some(text) => format!("`{}`", escape_cell(text)),
+            None => String::new(),
+        };
+
+        let value = match info.value {
+            Some(value) => Some(value),
+            None if T::takes_value(info) => Some(messages().value()),
+            None => None,
+        };
+
+        let default = info
+            .default
+            .map(|default| default().as_display().to_string());
+
+        let mut description = description_lines(info.description)?;
+        description.append(&mut description_lines(info.long_description)?);
+
+        writeln!(
+            self.output,
+            "| {} | {} | {} | {} | {} |",
+            code(info.short_name),
+            code(info.long_name),
+            code(value),
+            code(default.as_deref()),
+            escape_cell(&description.join("\n"))
+        )
+    }
+
+    /// Arg groups are written as a paragraph following the table of flags, ending the table
+    fn arg_group(&mut self, arg_group: &ArgGroup) -> std::io::Result<()> {
+        self.in_table = false;
+        writeln!(self.output)?;
+        writeln!(
+            self.output,
+            "{}",
+            DisplayWith(|f| messages().arg_group(f, arg_group.rule, arg_group.members))
+        )
+    }
+}
@@ -0,0 +1,9 @@
+/// Escapes `text` so it can be placed in a table cell, joining its lines with line breaks
+pub(super) fn escape_cell(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.replace('|', "\\|"))
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
@@ -0,0 +1,16 @@
+use crate::MarkdownWriter;
+
+impl<'a> MarkdownWriter<'a> {
+    /// Writes a heading at `level` containing `title`, separated from any previous content by a
+    /// blank line
+    pub(super) fn heading(&mut self, level: usize, title: &str) -> std::io::Result<()> {
+        if self.started {
+            writeln!(self.output)?;
+        }
+
+        self.started = true;
+        self.in_table = false;
+        writeln!(self.output, "{} {}", "#".repeat(level), title)?;
+        writeln!(self.output)
+    }
+}
@@ -0,0 +1,24 @@
+use description_lines::description_lines;
+use escape_cell::escape_cell;
+use std::io::Write;
+
+mod description_lines;
+mod document_writer;
+mod escape_cell;
+mod heading;
+mod new;
+
+/// Writes Markdown reference documentation describing commands
+pub struct MarkdownWriter<'a> {
+    /// The output to write the document to
+    output: &'a mut dyn Write,
+
+    /// Is the command currently being written a sub-command?
+    sub_command: bool,
+
+    /// Has anything been written to the output yet?
+    started: bool,
+
+    /// Has the header of a flag table been written in the current section?
+    in_table: bool,
+}
@@ -0,0 +1,14 @@
+use crate::MarkdownWriter;
+use std::io::Write;
+
+impl<'a> MarkdownWriter<'a> {
+    /// Creates a new [`MarkdownWriter`] writing a document to `output`
+    pub fn new(output: &'a mut dyn Write) -> Self {
+        MarkdownWriter {
+            output,
+            sub_command: false,
+            started: false,
+            in_table: false,
+        }
+    }
+}
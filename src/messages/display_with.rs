@@ -0,0 +1,11 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// Displays the text written by a function, for using messages written to a [`Formatter`] where a
+/// [`Display`] value is needed
+pub(crate) struct DisplayWith<F: Fn(&mut Formatter) -> Result>(pub(crate) F);
+
+impl<F: Fn(&mut Formatter) -> Result> Display for DisplayWith<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (self.0)(f)
+    }
+}
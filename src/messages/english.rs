@@ -0,0 +1,7 @@
+use crate::Messages;
+
+/// The default messages, in English
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct English;
+
+impl Messages for English {}
@@ -0,0 +1,17 @@
+use crate::{English, Messages};
+use std::sync::{PoisonError, RwLock};
+
+/// The messages for the process, set by [`set_messages`]
+static GLOBAL: RwLock<&'static dyn Messages> = RwLock::new(&English);
+
+/// Gets the messages for the process
+///
+/// This is [`English`] unless it has been changed by [`set_messages`]
+pub fn messages() -> &'static dyn Messages {
+    *GLOBAL.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sets `messages` as the messages for the process, used for all help and errors written after
+pub fn set_messages(messages: &'static dyn Messages) {
+    *GLOBAL.write().unwrap_or_else(PoisonError::into_inner) = messages;
+}
@@ -0,0 +1,292 @@
+use crate::ArgGroupRule;
+use std::fmt::{Display, Formatter, Result};
+
+mod display_with;
+mod english;
+mod global;
+
+pub use english::English;
+pub use global::{messages, set_messages};
+
+pub(crate) use display_with::DisplayWith;
+
+/// The built-in text written in help and errors, allowing it to be translated
+///
+/// Every method defaults to the English text, so translations only override the messages they
+/// change. The messages for the process are set with [`set_messages`].
+pub trait Messages: Sync {
+    /// The header of the usage section in help
+    fn usage_header(&self) -> &str {
+        "USAGE:"
+    }
+
+    /// The header of the positionals section in help
+    fn arguments_header(&self) -> &str {
+        "ARGUMENTS:"
+    }
+
+    /// The header of the flags section in help
+    fn options_header(&self) -> &str {
+        "OPTIONS:"
+    }
+
+    /// The header of the examples section in long help
+    fn examples_header(&self) -> &str {
+        "EXAMPLES:"
+    }
+
+    /// The header of the list written by the built-in "help" sub-command
+    fn sub_commands_header(&self) -> &str {
+        "SUB-COMMANDS:"
+    }
+
+    /// The description of the built-in "help" sub-command
+    fn help_sub_command(&self) -> &str {
+        "Print the help for a sub-command"
+    }
+
+    /// The description of the built-in "--help" flag
+    fn help_flag(&self) -> &str {
+        "Print help"
+    }
+
+    /// The description of the built-in "--help-all" flag
+    fn help_all_flag(&self) -> &str {
+        "Print help, including hidden arguments"
+    }
+
+    /// The description of the built-in "--version" flag
+    fn version_flag(&self) -> &str {
+        "Print version"
+    }
+
+    /// The description of the built-in "--color" flag
+    fn color_flag(&self) -> &str {
+        "When to color output"
+    }
+
+    /// The description of the built-in "version" sub-command
+    fn version_sub_command(&self) -> &str {
+        "Print version and build details"
+    }
+
+    /// Written in usage in place of the optional flags
+    fn options_usage(&self) -> &str {
+        "[OPTIONS..]"
+    }
+
+    /// The name of the value of a flag which doesn't provide one
+    fn value(&self) -> &str {
+        "VALUE"
+    }
+
+    /// The prefix of errors reported to the user
+    fn error_prefix(&self) -> &str {
+        "error:"
+    }
+
+    /// Writes `error`, which occurred while parsing `command` at the argument with `index` if known
+    fn in_command(
+        &self,
+        f: &mut Formatter,
+        command: &str,
+        index: Option<usize>,
+        error: &dyn Display,
+    ) -> Result {
+        if !command.is_empty() {
+            write!(f, "{command}: ")?;
+        }
+
+        error.fmt(f)?;
+
+        match index {
+            Some(index) => write!(f, " (argument {index})"),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes the hint following the usage in errors reported by
+    /// [`Command::parse_or_exit`](crate::Command::parse_or_exit), pointing to `help_flag`
+    ///
+    /// `help_flag` is the command line showing the help of the sub-command the error occurred in,
+    /// like "tool build --help".
+    fn help_hint(&self, f: &mut Formatter, help_flag: &str) -> Result {
+        write!(f, "For more information, try \"{help_flag}\"")
+    }
+
+    /// Writes the note following a flag description with its default value
+    fn default_value(&self, f: &mut Formatter, default: &dyn Display) -> Result {
+        write!(f, "[Default: {default}]")
+    }
+
+    /// Writes the note following a description with the values an argument accepts
+    fn possible_values(&self, f: &mut Formatter, values: &[&str]) -> Result {
+        write!(f, "[possible values: {}]", values.join(", "))
+    }
+
+    /// Writes the note following a flag description with the flags it can't be passed with
+    fn conflicts_with(&self, f: &mut Formatter, flags: &[&str]) -> Result {
+        write!(f, "[conflicts with: {}]", flags.join(", "))
+    }
+
+    /// Writes the note following a flag description with the flags it must be passed with
+    fn requires(&self, f: &mut Formatter, flags: &[&str]) -> Result {
+        write!(f, "[requires: {}]", flags.join(", "))
+    }
+
+    /// Writes the note following the flags of an arg group with the rule on how many of its
+    /// `members` can be passed
+    fn arg_group(&self, f: &mut Formatter, rule: ArgGroupRule, members: &[&str]) -> Result {
+        let rule = match rule {
+            ArgGroupRule::ExactlyOne => "requires exactly one of",
+            ArgGroupRule::AtLeastOne => "requires at least one of",
+            ArgGroupRule::AtMostOne => "accepts at most one of",
+        };
+        write!(f, "[{rule}: {}]", members.join(", "))
+    }
+
+    /// Writes the error for an argument which isn't valid UTF-8
+    fn invalid_utf8(&self, f: &mut Formatter, string: &str) -> Result {
+        write!(f, "invalid UTF-8 \"{string}\"")
+    }
+
+    /// Writes the error for a missing required flag
+    fn missing_argument(&self, f: &mut Formatter, argument: &str) -> Result {
+        write!(f, "missing argument \"{argument}\"")
+    }
+
+    /// Writes the error for a missing required positional
+    fn missing_positional_value(&self, f: &mut Formatter, value: &str) -> Result {
+        write!(f, "missing \"{value}\"")
+    }
+
+    /// Writes the error for an invalid positional value, caused by `error`
+    fn invalid_positional_value(
+        &self,
+        f: &mut Formatter,
+        value: &str,
+        error: &dyn std::error::Error,
+    ) -> Result {
+        write!(f, "invalid \"{value}\" - {error}")
+    }
+
+    /// Writes the error for a flag missing its value
+    fn missing_flag_value(&self, f: &mut Formatter, argument: &str, value: &str) -> Result {
+        write!(f, "missing \"{value}\" for \"{argument}\"")
+    }
+
+    /// Writes the error for an invalid flag value, caused by `error`
+    fn invalid_flag_value(
+        &self,
+        f: &mut Formatter,
+        argument: &str,
+        value: &str,
+        error: &dyn std::error::Error,
+    ) -> Result {
+        write!(f, "invalid \"{value}\" for \"{argument}\" - {error}")
+    }
+
+    /// Writes the error for a flag passed more times than it can be
+    fn repeated_flag(&self, f: &mut Formatter, argument: &str) -> Result {
+        write!(f, "repeated flag \"{argument}\"")
+    }
+
+    /// Writes the error for a flag passed with another flag it conflicts with
+    fn conflicting_flags(&self, f: &mut Formatter, flag: &str, other: &str) -> Result {
+        write!(f, "\"{flag}\" cannot be used with \"{other}\"")
+    }
+
+    /// Writes the error for a flag passed without another flag it requires
+    fn missing_required_flag(&self, f: &mut Formatter, flag: &str, required: &str) -> Result {
+        write!(f, "\"{flag}\" requires \"{required}\"")
+    }
+
+    /// Writes the error for a group of flags where one must be passed but none were
+    fn missing_arg_group(&self, f: &mut Formatter, members: &[&str]) -> Result {
+        write!(f, "one of ")?;
+        for (i, member) in members.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "\"{member}\"")?;
+        }
+        write!(f, " is required")
+    }
+
+    /// Writes the error for a group of flags where only one can be passed but several were
+    fn conflicting_arg_group(&self, f: &mut Formatter, members: &[&str]) -> Result {
+        write!(f, "only one of ")?;
+        for (i, member) in members.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "\"{member}\"")?;
+        }
+        write!(f, " can be used")
+    }
+
+    /// Writes the error for an unknown argument
+    fn unknown_argument(&self, f: &mut Formatter, argument: &str) -> Result {
+        write!(f, "unknown argument \"{argument}\"")
+    }
+
+    /// Writes the note following an error with the closest known value
+    fn did_you_mean(&self, f: &mut Formatter, suggestion: &str) -> Result {
+        write!(f, " (did you mean \"{suggestion}\"?)")
+    }
+
+    /// Writes the error for failing to write output
+    fn io_error(&self, f: &mut Formatter, error: &std::io::Error) -> Result {
+        write!(f, "unable to write output - {error}")
+    }
+
+    /// Writes the error for a value not among the `expected` values
+    fn unexpected_value(&self, f: &mut Formatter, unexpected: &str, expected: &str) -> Result {
+        write!(f, "unexpected \"{unexpected}\", expected {expected}")
+    }
+
+    /// Writes the error for an invalid IP or socket address
+    fn invalid_address(&self, f: &mut Formatter) -> Result {
+        "invalid address provided".fmt(f)
+    }
+
+    /// Writes the error for a character value which isn't exactly one character
+    fn invalid_char(&self, f: &mut Formatter) -> Result {
+        "expected exactly 1 character".fmt(f)
+    }
+
+    /// Writes the error for an invalid duration
+    fn invalid_duration(&self, f: &mut Formatter, duration: f64) -> Result {
+        write!(f, "invalid duration \"{duration}\"")
+    }
+
+    /// Writes the error for a value shorter than its minimum length
+    fn value_too_short(&self, f: &mut Formatter) -> Result {
+        "value is too short".fmt(f)
+    }
+
+    /// Writes the error for a value longer than its maximum length
+    fn value_too_long(&self, f: &mut Formatter) -> Result {
+        "value is too long".fmt(f)
+    }
+
+    /// Writes the error for a value which isn't a number
+    fn invalid_number(&self, f: &mut Formatter) -> Result {
+        "invalid number provided".fmt(f)
+    }
+
+    /// Writes the error for a number above its maximum
+    fn number_too_large(&self, f: &mut Formatter) -> Result {
+        "provided number is too large".fmt(f)
+    }
+
+    /// Writes the error for a number below its minimum
+    fn number_too_small(&self, f: &mut Formatter) -> Result {
+        "provided number is too small".fmt(f)
+    }
+
+    /// Writes the error for a zero passed for a non-zero number
+    fn zero_value(&self, f: &mut Formatter) -> Result {
+        "value cannot be zero".fmt(f)
+    }
+}
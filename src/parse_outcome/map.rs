@@ -0,0 +1,12 @@
+use crate::ParseOutcome;
+
+impl<T> ParseOutcome<T> {
+    /// Maps the parsed value with `f`, leaving help and version outcomes unchanged
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> ParseOutcome<U> {
+        match self {
+            ParseOutcome::Parsed(value) => ParseOutcome::Parsed(f(value)),
+            ParseOutcome::Help(help) => ParseOutcome::Help(help),
+            ParseOutcome::Version(version) => ParseOutcome::Version(version),
+        }
+    }
+}
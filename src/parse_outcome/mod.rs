@@ -0,0 +1,14 @@
+mod map;
+
+/// The outcome of successfully parsing a command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseOutcome<T> {
+    /// The command was parsed into a value
+    Parsed(T),
+
+    /// A help flag was passed, containing the help text for the command
+    Help(String),
+
+    /// A version flag was passed, containing the version text for the command
+    Version(String),
+}
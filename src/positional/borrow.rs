@@ -1,9 +1,12 @@
 use crate::{
-    Argument, ArgumentSource, DefaultDisplay, Positional, PositionalInfo, PositionalResult, Result,
+    Argument, ArgumentSource, CommandList, DefaultDisplay, DocumentWriter, ParseOutcome,
+    Positional, PositionalInfo, PositionalResult, Result,
 };
 use std::borrow::Cow;
 
 impl<'a, T: Positional, B: DefaultDisplay + ToOwned<Owned = T>> Positional for Cow<'a, B> {
+    type Value = T::Value;
+
     fn parse<'b>(
         this: &mut Option<Self>,
         argument: Argument<'b>,
@@ -15,7 +18,7 @@ impl<'a, T: Positional, B: DefaultDisplay + ToOwned<Owned = T>> Positional for C
                 panic!("Cow should never be filled with `Borrowed` before parsing")
             }
         });
-        let result = T::parse(&mut inner, argument, &info.drop_default())?;
+        let result = T::parse(&mut inner, argument, &info.inner_info())?;
         *this = Some(Cow::Owned(inner.unwrap()));
         result
     }
@@ -24,8 +27,8 @@ impl<'a, T: Positional, B: DefaultDisplay + ToOwned<Owned = T>> Positional for C
         this: &mut Option<Self>,
         command: Argument,
         source: &mut dyn ArgumentSource,
-        command_list: String,
-    ) -> Result<bool> {
+        command_list: CommandList,
+    ) -> Result<ParseOutcome<()>> {
         let mut inner = this.take().map(|inner| match inner {
             Cow::Owned(inner) => inner,
             Cow::Borrowed(_) => {
@@ -35,4 +38,11 @@ impl<'a, T: Positional, B: DefaultDisplay + ToOwned<Owned = T>> Positional for C
 
         T::sub(&mut inner, command, source, command_list)
     }
+
+    fn write_document_sub_commands<W: DocumentWriter>(
+        writer: &mut W,
+        command_list: &CommandList,
+    ) -> std::io::Result<()> {
+        T::write_document_sub_commands(writer, command_list)
+    }
 }
@@ -1,13 +1,18 @@
-use crate::{Argument, ArgumentSource, Positional, PositionalInfo, PositionalResult, Result};
+use crate::{
+    Argument, ArgumentSource, CommandList, DocumentWriter, ParseOutcome, Positional,
+    PositionalInfo, PositionalResult, Result,
+};
 
 impl<T: Positional> Positional for Box<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
         info: &PositionalInfo<Self>,
     ) -> PositionalResult<'a> {
         let mut inner = this.take().map(Box::into_inner);
-        let result = T::parse(&mut inner, argument, &info.drop_default())?;
+        let result = T::parse(&mut inner, argument, &info.inner_info())?;
         *this = Some(Box::new(inner.unwrap()));
         result
     }
@@ -16,9 +21,16 @@ impl<T: Positional> Positional for Box<T> {
         this: &mut Option<Self>,
         command: Argument,
         source: &mut dyn ArgumentSource,
-        command_list: String,
-    ) -> Result<bool> {
+        command_list: CommandList,
+    ) -> Result<ParseOutcome<()>> {
         let mut inner = this.take().map(Box::into_inner);
         T::sub(&mut inner, command, source, command_list)
     }
+
+    fn write_document_sub_commands<W: DocumentWriter>(
+        writer: &mut W,
+        command_list: &CommandList,
+    ) -> std::io::Result<()> {
+        T::write_document_sub_commands(writer, command_list)
+    }
 }
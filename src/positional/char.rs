@@ -1,13 +1,18 @@
 use crate::{Argument, Error, InvalidCharError, Positional, PositionalInfo, PositionalResult};
 
 impl Positional for char {
+    type Value = Self;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
         info: &PositionalInfo<Self>,
     ) -> PositionalResult<'a> {
         match argument.as_str()?.parse() {
-            Ok(value) => *this = Some(value),
+            Ok(value) => {
+                info.validate_value(&value)?;
+                *this = Some(value)
+            }
             Err(error) => {
                 return PositionalResult::Error(Error::invalid_positional_value(
                     info.value,
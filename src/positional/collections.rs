@@ -1,5 +1,6 @@
 use crate::{
     Argument, Error, InvalidLengthError, Positional, PositionalInfo, PositionalResult, Result,
+    ValueHint,
 };
 use std::{
     collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque},
@@ -7,6 +8,8 @@ use std::{
 };
 
 impl<T: Positional> Positional for Vec<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -29,7 +32,11 @@ impl<T: Positional> Positional for Vec<T> {
                 min: None,
                 max: None,
                 default: None,
+                validate: info.validate,
                 description: None,
+                long_description: None,
+                complete: None,
+                hidden: false,
             },
         )?;
 
@@ -63,9 +70,15 @@ impl<T: Positional> Positional for Vec<T> {
         info.max.is_none()
             || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
     }
+
+    fn value_hint(info: &PositionalInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Positional> Positional for VecDeque<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -88,7 +101,11 @@ impl<T: Positional> Positional for VecDeque<T> {
                 min: None,
                 max: None,
                 default: None,
+                validate: info.validate,
                 description: None,
+                long_description: None,
+                complete: None,
+                hidden: false,
             },
         )?;
 
@@ -122,9 +139,15 @@ impl<T: Positional> Positional for VecDeque<T> {
         info.max.is_none()
             || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
     }
+
+    fn value_hint(info: &PositionalInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Positional> Positional for LinkedList<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -147,7 +170,11 @@ impl<T: Positional> Positional for LinkedList<T> {
                 min: None,
                 max: None,
                 default: None,
+                validate: info.validate,
                 description: None,
+                long_description: None,
+                complete: None,
+                hidden: false,
             },
         )?;
 
@@ -181,9 +208,15 @@ impl<T: Positional> Positional for LinkedList<T> {
         info.max.is_none()
             || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
     }
+
+    fn value_hint(info: &PositionalInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Positional + Eq + Hash> Positional for HashSet<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -206,7 +239,11 @@ impl<T: Positional + Eq + Hash> Positional for HashSet<T> {
                 min: None,
                 max: None,
                 default: None,
+                validate: info.validate,
                 description: None,
+                long_description: None,
+                complete: None,
+                hidden: false,
             },
         )?;
 
@@ -240,9 +277,15 @@ impl<T: Positional + Eq + Hash> Positional for HashSet<T> {
         info.max.is_none()
             || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
     }
+
+    fn value_hint(info: &PositionalInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Positional + Ord> Positional for BTreeSet<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -265,7 +308,11 @@ impl<T: Positional + Ord> Positional for BTreeSet<T> {
                 min: None,
                 max: None,
                 default: None,
+                validate: info.validate,
                 description: None,
+                long_description: None,
+                complete: None,
+                hidden: false,
             },
         )?;
 
@@ -299,9 +346,15 @@ impl<T: Positional + Ord> Positional for BTreeSet<T> {
         info.max.is_none()
             || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
     }
+
+    fn value_hint(info: &PositionalInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
 
 impl<T: Positional + Ord> Positional for BinaryHeap<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -324,7 +377,11 @@ impl<T: Positional + Ord> Positional for BinaryHeap<T> {
                 min: None,
                 max: None,
                 default: None,
+                validate: info.validate,
                 description: None,
+                long_description: None,
+                complete: None,
+                hidden: false,
             },
         )?;
 
@@ -358,4 +415,8 @@ impl<T: Positional + Ord> Positional for BinaryHeap<T> {
         info.max.is_none()
             || (info.max.map(|f| f as usize) != Some(0) && info.max.map(|f| f as usize) != Some(1))
     }
+
+    fn value_hint(info: &PositionalInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
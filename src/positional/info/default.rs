@@ -7,7 +7,11 @@ impl<T: Positional> Default for PositionalInfo<T> {
             min: None,
             max: None,
             default: None,
+            validate: None,
             description: None,
+            long_description: None,
+            complete: None,
+            hidden: false,
         }
     }
 }
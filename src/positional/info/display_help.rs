@@ -1,18 +1,14 @@
-use crate::{Positional, PositionalInfo};
+use crate::{HelpMode, Positional, PositionalInfo, TextWrap};
 
 impl<T: Positional> PositionalInfo<T> {
-    /// Displays the help for this positional on stdout
+    /// Displays the help for this positional on stdout, wrapped to the width of the terminal
     pub fn display_help(&self, description_offset: usize) {
-        print!("  {}", self.value);
-
-        if let Some(description) = self.description {
-            for _ in 0..description_offset - self.value.len() - 2 {
-                print!(" ");
-            }
-
-            description(description_offset);
-        }
-
-        println!();
+        self.write_help(
+            &mut std::io::stdout(),
+            description_offset,
+            TextWrap::detect(),
+            HelpMode::Long,
+        )
+        .expect("failed printing to stdout")
     }
 }
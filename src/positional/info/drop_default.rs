@@ -8,7 +8,11 @@ impl<T: Positional> PositionalInfo<T> {
             min: self.min,
             max: self.max,
             default: None,
+            validate: None,
             description: self.description,
+            long_description: self.long_description,
+            complete: self.complete,
+            hidden: self.hidden,
         }
     }
 }
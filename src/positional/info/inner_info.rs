@@ -0,0 +1,19 @@
+use crate::{Positional, PositionalInfo};
+
+impl<T: Positional> PositionalInfo<T> {
+    /// Copies this info into a new struct for the type wrapped by `T` without copying the default
+    /// value, keeping the validator as both types parse the same values
+    pub fn inner_info<T2: Positional<Value = T::Value>>(&self) -> PositionalInfo<T2> {
+        PositionalInfo {
+            value: self.value,
+            min: self.min,
+            max: self.max,
+            default: None,
+            validate: self.validate,
+            description: self.description,
+            long_description: self.long_description,
+            complete: self.complete,
+            hidden: self.hidden,
+        }
+    }
+}
@@ -1,9 +1,15 @@
 use crate::Positional;
+use std::io::Write;
 
 mod default;
 mod display_help;
 mod display_usage;
 mod drop_default;
+mod inner_info;
+mod validate_value;
+mod write_document_sub_commands;
+mod write_help;
+mod write_usage;
 
 /// Information about a positional
 ///
@@ -22,6 +28,22 @@ pub struct PositionalInfo<T: Positional> {
     /// The default value if none is provided by the user
     pub default: Option<fn() -> T>,
 
-    /// A function which writes the description of this positional to stdout, taking a margin for printing new lines
-    pub description: Option<fn(usize)>,
+    /// A function which checks each value parsed for this positional, rejecting it with an error
+    pub validate:
+        Option<fn(&T::Value) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>>>,
+
+    /// A function which writes the description of this positional to an output, taking a margin
+    /// for printing new lines
+    pub description: Option<fn(&mut dyn Write, usize) -> std::io::Result<()>>,
+
+    /// A function which writes the extended description of this positional to an output, taking a
+    /// margin for printing new lines, only included in long help
+    pub long_description: Option<fn(&mut dyn Write, usize) -> std::io::Result<()>>,
+
+    /// A function which lists the candidates for completing the value of this positional, given
+    /// the partial value being completed
+    pub complete: Option<fn(&str) -> Vec<String>>,
+
+    /// Is this positional left out of usage, help and completions unless hidden items are requested?
+    pub hidden: bool,
 }
@@ -0,0 +1,13 @@
+use crate::{Error, Positional, PositionalInfo, Result};
+
+impl<T: Positional> PositionalInfo<T> {
+    /// Checks `value` using the validator of this positional, if it has one
+    pub fn validate_value(&self, value: &T::Value) -> Result<()> {
+        let validate = match self.validate {
+            Some(validate) => validate,
+            None => return Ok(()),
+        };
+
+        validate(value).map_err(|error| Error::InvalidPositionalValue(self.value, error))
+    }
+}
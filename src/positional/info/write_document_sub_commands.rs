@@ -0,0 +1,12 @@
+use crate::{CommandList, DocumentWriter, Positional, PositionalInfo};
+
+impl<T: Positional> PositionalInfo<T> {
+    /// Writes the documentation for the sub-commands this positional can parse to `writer`
+    pub fn write_document_sub_commands<W: DocumentWriter>(
+        &self,
+        writer: &mut W,
+        command_list: &CommandList,
+    ) -> std::io::Result<()> {
+        T::write_document_sub_commands(writer, command_list)
+    }
+}
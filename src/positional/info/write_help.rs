@@ -0,0 +1,70 @@
+use crate::{
+    messages::{messages, DisplayWith},
+    text_wrap::{display_width, first_paragraph},
+    HelpMode, Positional, PositionalInfo, TextWrap, ValueHint,
+};
+use std::io::Write;
+
+impl<T: Positional> PositionalInfo<T> {
+    /// Writes the help for this positional to `output`, wrapping the description with `wrap`
+    ///
+    /// Only the first paragraph of the description is written unless `mode` is long, in which case
+    /// the extended description follows it. Positionals accepting a fixed set of values list them
+    /// after the description. Nothing is written for hidden positionals unless `mode` shows them.
+    pub fn write_help(
+        &self,
+        output: &mut dyn Write,
+        description_offset: usize,
+        wrap: TextWrap,
+        mode: HelpMode,
+    ) -> std::io::Result<()> {
+        if self.hidden && !mode.shows_hidden() {
+            return Ok(());
+        }
+
+        write!(output, "  {}", self.value)?;
+
+        let possible_values = match T::value_hint(self) {
+            ValueHint::Values(values) => Some(values),
+            _ => None,
+        };
+
+        let long_description = self.long_description.filter(|_| mode.shows_long());
+        if self.description.is_some() || long_description.is_some() || possible_values.is_some() {
+            for _ in display_width(self.value) + 2..description_offset {
+                write!(output, " ")?;
+            }
+
+            let mut buffer = Vec::new();
+            if let Some(description) = self.description {
+                description(&mut buffer, description_offset)?;
+            }
+
+            let mut text = String::from_utf8_lossy(&buffer).into_owned();
+            if !mode.shows_long() {
+                text.truncate(first_paragraph(&text).trim_end().len());
+            }
+
+            if let Some(values) = possible_values {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(&DisplayWith(|f| messages().possible_values(f, values)).to_string());
+            }
+
+            if let Some(long_description) = long_description {
+                let mut buffer = Vec::new();
+                long_description(&mut buffer, description_offset)?;
+
+                if !text.is_empty() {
+                    text.push_str("\n\n");
+                }
+                text.push_str(&String::from_utf8_lossy(&buffer));
+            }
+
+            wrap.write(output, &text, description_offset)?;
+        }
+
+        writeln!(output)
+    }
+}
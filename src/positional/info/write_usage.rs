@@ -0,0 +1,29 @@
+use crate::{Positional, PositionalInfo};
+use std::io::Write;
+
+impl<T: Positional> PositionalInfo<T> {
+    /// Writes the usage value for this positional to `output`, unless it is hidden
+    pub fn write_usage(&self, output: &mut dyn Write) -> std::io::Result<()> {
+        if self.hidden {
+            return Ok(());
+        }
+
+        let required = T::is_required(self);
+
+        if !required {
+            write!(output, "[")?;
+        }
+
+        write!(output, "{}", self.value)?;
+
+        if T::multiple(self) {
+            write!(output, "..")?;
+        }
+
+        if !required {
+            write!(output, "]")?;
+        }
+
+        write!(output, " ")
+    }
+}
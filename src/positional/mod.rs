@@ -1,7 +1,12 @@
-use crate::{Argument, ArgumentSource, DefaultDisplay, Error, Result};
+use crate::{
+    messages, text_wrap::display_width, Argument, ArgumentSource, CommandList, DefaultDisplay,
+    DocumentWriter, Error, ParseOutcome, Result, Style, TextWrap, ValueHint,
+};
+use std::io::Write;
 
 mod info;
 mod result;
+mod sub_command_info;
 
 mod borrow;
 mod r#box;
@@ -19,9 +24,22 @@ mod time;
 
 pub use info::PositionalInfo;
 pub use result::PositionalResult;
+pub use sub_command_info::SubCommandInfo;
+
+/// The entry listed for the built-in "help" sub-command
+const HELP_SUB_COMMAND: SubCommandInfo = SubCommandInfo {
+    name: "help",
+    description: Some(|output, _| write!(output, "{}", messages().help_sub_command())),
+};
 
 /// A type which can be a positional argument
 pub trait Positional: Sized + DefaultDisplay {
+    /// The type of each value parsed for this positional, which is passed to the validator of the
+    /// positional
+    ///
+    /// Defaults to the positional type itself, as for positionals taking a single value.
+    type Value = Self;
+
     /// Parse `argument` into this value
     fn parse<'a>(
         this: &mut Option<Self>,
@@ -38,17 +56,80 @@ pub trait Positional: Sized + DefaultDisplay {
         }
     }
 
-    /// Continue parsing as a sub-command, returning true if the parse should return [`Some`]
+    /// Continue parsing as a sub-command, returning [`ParseOutcome::Parsed`] if the parse should
+    /// produce a value
     #[allow(unused_variables)]
     fn sub(
         this: &mut Option<Self>,
         command: Argument,
         source: &mut dyn ArgumentSource,
-        command_list: String,
-    ) -> Result<bool> {
+        command_list: CommandList,
+    ) -> Result<ParseOutcome<()>> {
         unimplemented!()
     }
 
+    /// Write the documentation for the sub-commands this positional can parse to `writer`
+    ///
+    /// `command_list` contains the names leading to the command containing this positional
+    #[allow(unused_variables)]
+    fn write_document_sub_commands<W: DocumentWriter>(
+        writer: &mut W,
+        command_list: &CommandList,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Get the sub-commands this positional can parse, leaving out hidden ones
+    fn sub_commands() -> &'static [SubCommandInfo] {
+        &[]
+    }
+
+    /// Parse the built-in "help" sub-command, which gets the help for the sub-command named by
+    /// the rest of `source` or lists the sub-commands if `source` is empty
+    ///
+    /// `command_list` ends with the name of the "help" sub-command, which is replaced by the name
+    /// of the sub-command the help is for
+    fn help_sub_command(
+        this: &mut Option<Self>,
+        source: &mut dyn ArgumentSource,
+        command_list: CommandList,
+    ) -> Result<ParseOutcome<()>> {
+        let mut arguments = Vec::new();
+        while let Some(argument) = source.next() {
+            arguments.push(argument);
+        }
+
+        if arguments.is_empty() {
+            let sub_commands = Self::sub_commands();
+            let description_offset = sub_commands
+                .iter()
+                .chain([&HELP_SUB_COMMAND])
+                .map(|sub_command| display_width(sub_command.name))
+                .max()
+                .unwrap_or(0)
+                + 4;
+
+            let wrap = TextWrap::detect();
+            let style = Style::stdout();
+
+            let mut output = Vec::new();
+            writeln!(output, "{}", style.header(messages().sub_commands_header()))?;
+            for sub_command in sub_commands.iter().chain([&HELP_SUB_COMMAND]) {
+                sub_command.write_help(&mut output, description_offset, wrap, style)?;
+            }
+
+            return Ok(ParseOutcome::Help(
+                String::from_utf8_lossy(&output).into_owned(),
+            ));
+        }
+
+        let command = arguments.remove(0);
+        arguments.push(Argument::from("--help"));
+        let command_list = command_list.parent().sub_command(command.to_string());
+
+        Self::sub(this, command, &mut arguments.into_iter(), command_list)
+    }
+
     /// Is this positional required?
     fn is_required(info: &PositionalInfo<Self>) -> bool {
         info.default.is_none()
@@ -59,4 +140,10 @@ pub trait Positional: Sized + DefaultDisplay {
     fn multiple(info: &PositionalInfo<Self>) -> bool {
         false
     }
+
+    /// Get the hint describing the values this positional accepts, used for shell completion
+    #[allow(unused_variables)]
+    fn value_hint(info: &PositionalInfo<Self>) -> ValueHint {
+        ValueHint::Any
+    }
 }
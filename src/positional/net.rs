@@ -3,13 +3,18 @@ use crate::{Argument, Error, InvalidAddressError, Positional, PositionalInfo, Po
 macro_rules! impl_net {
     ($($t: ty),*) => {$(
         impl Positional for $t {
+            type Value = Self;
+
             fn parse<'a>(
                 this: &mut Option<Self>,
                 argument: Argument<'a>,
                 info: &PositionalInfo<Self>,
             ) -> PositionalResult<'a> {
                 match argument.as_str()?.parse() {
-                    Ok(value) => *this = Some(value),
+                    Ok(value) => {
+                        info.validate_value(&value)?;
+                        *this = Some(value)
+                    }
                     Err(_) => {
                         return PositionalResult::Error(Error::invalid_positional_value(
                             info.value,
@@ -1,8 +1,12 @@
-use crate::{Argument, Error, InvalidNumberError, Positional, PositionalInfo, PositionalResult};
+use crate::{
+    Argument, Error, InvalidNumberError, Positional, PositionalInfo, PositionalResult, ValueHint,
+};
 
 macro_rules! impl_number {
     ($($t: ty),*) => {$(
         impl Positional for $t {
+            type Value = Self;
+
             fn parse<'a>(
                 this: &mut Option<Self>,
                 argument: Argument<'a>,
@@ -22,6 +26,8 @@ macro_rules! impl_number {
                             }
                         }
 
+                        info.validate_value(&value)?;
+
                         *this = Some(value)
                     },
                     Err(error) => {
@@ -33,9 +39,15 @@ macro_rules! impl_number {
                 }
                 PositionalResult::Next
             }
+
+            fn value_hint(_: &PositionalInfo<Self>) -> ValueHint {
+                ValueHint::Integer
+            }
         }
 
         impl Positional for std::num::NonZero<$t> {
+            type Value = Self;
+
             fn parse<'a>(
                 this: &mut Option<Self>,
                 argument: Argument<'a>,
@@ -55,6 +67,8 @@ macro_rules! impl_number {
                             }
                         }
 
+                        info.validate_value(&value)?;
+
                         *this = Some(value)
                     },
                     Err(error) => {
@@ -66,6 +80,10 @@ macro_rules! impl_number {
                 }
                 PositionalResult::Next
             }
+
+            fn value_hint(_: &PositionalInfo<Self>) -> ValueHint {
+                ValueHint::Integer
+            }
         }
     )*};
 }
@@ -73,6 +91,8 @@ macro_rules! impl_number {
 macro_rules! impl_atomic {
     ($($t: ty),*) => {$(
         impl Positional for $t {
+            type Value = Self;
+
             fn parse<'a>(
                 this: &mut Option<Self>,
                 argument: Argument<'a>,
@@ -92,7 +112,11 @@ macro_rules! impl_atomic {
                             }
                         }
 
-                        *this = Some(<$t>::new(value))
+                        let value = <$t>::new(value);
+
+                        info.validate_value(&value)?;
+
+                        *this = Some(value)
                     },
                     Err(error) => {
                         return PositionalResult::Error(Error::invalid_positional_value(
@@ -103,6 +127,10 @@ macro_rules! impl_atomic {
                 }
                 PositionalResult::Next
             }
+
+            fn value_hint(_: &PositionalInfo<Self>) -> ValueHint {
+                ValueHint::Integer
+            }
         }
     )*};
 }
@@ -123,6 +151,8 @@ impl_atomic!(
 );
 
 impl Positional for f32 {
+    type Value = Self;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -148,6 +178,8 @@ impl Positional for f32 {
                     }
                 }
 
+                info.validate_value(&value)?;
+
                 *this = Some(value)
             }
             Err(error) => {
@@ -159,9 +191,15 @@ impl Positional for f32 {
         }
         PositionalResult::Next
     }
+
+    fn value_hint(_: &PositionalInfo<Self>) -> ValueHint {
+        ValueHint::Float
+    }
 }
 
 impl Positional for f64 {
+    type Value = Self;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -187,6 +225,8 @@ impl Positional for f64 {
                     }
                 }
 
+                info.validate_value(&value)?;
+
                 *this = Some(value)
             }
             Err(error) => {
@@ -198,4 +238,8 @@ impl Positional for f64 {
         }
         PositionalResult::Next
     }
+
+    fn value_hint(_: &PositionalInfo<Self>) -> ValueHint {
+        ValueHint::Float
+    }
 }
@@ -1,6 +1,8 @@
-use crate::{Argument, Positional, PositionalInfo, PositionalResult, Result};
+use crate::{Argument, Positional, PositionalInfo, PositionalResult, Result, ValueHint};
 
 impl<T: Positional> Positional for Option<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -10,7 +12,7 @@ impl<T: Positional> Positional for Option<T> {
             Some(inner) => inner,
             None => None,
         };
-        let result = T::parse(&mut inner, argument, &info.drop_default())?;
+        let result = T::parse(&mut inner, argument, &info.inner_info())?;
         *this = Some(inner);
         result
     }
@@ -29,4 +31,8 @@ impl<T: Positional> Positional for Option<T> {
     fn multiple(info: &PositionalInfo<Self>) -> bool {
         T::multiple(&info.drop_default())
     }
+
+    fn value_hint(info: &PositionalInfo<Self>) -> ValueHint {
+        T::value_hint(&info.drop_default())
+    }
 }
@@ -1,7 +1,11 @@
-use crate::{Argument, Error, InvalidLengthError, Positional, PositionalInfo, PositionalResult};
+use crate::{
+    Argument, Error, InvalidLengthError, Positional, PositionalInfo, PositionalResult, ValueHint,
+};
 use std::path::PathBuf;
 
 impl Positional for PathBuf {
+    type Value = Self;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -30,7 +34,11 @@ impl Positional for PathBuf {
             }
         }
 
+        info.validate_value(&path)?;
         *this = Some(path);
         PositionalResult::Next
     }
+    fn value_hint(_: &PositionalInfo<Self>) -> ValueHint {
+        ValueHint::Path
+    }
 }
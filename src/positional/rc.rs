@@ -1,14 +1,19 @@
-use crate::{Argument, ArgumentSource, Positional, PositionalInfo, PositionalResult, Result};
+use crate::{
+    Argument, ArgumentSource, CommandList, DocumentWriter, ParseOutcome, Positional,
+    PositionalInfo, PositionalResult, Result,
+};
 use std::{rc::Rc, sync::Arc};
 
 impl<T: Positional> Positional for Rc<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
         info: &PositionalInfo<Self>,
     ) -> PositionalResult<'a> {
         let mut inner = this.take().map(|inner| Rc::into_inner(inner).unwrap());
-        let result = T::parse(&mut inner, argument, &info.drop_default())?;
+        let result = T::parse(&mut inner, argument, &info.inner_info())?;
         *this = Some(Rc::new(inner.unwrap()));
         result
     }
@@ -17,21 +22,30 @@ impl<T: Positional> Positional for Rc<T> {
         this: &mut Option<Self>,
         command: Argument,
         source: &mut dyn ArgumentSource,
-        command_list: String,
-    ) -> Result<bool> {
+        command_list: CommandList,
+    ) -> Result<ParseOutcome<()>> {
         let mut inner = this.take().map(|inner| Rc::into_inner(inner).unwrap());
         T::sub(&mut inner, command, source, command_list)
     }
+
+    fn write_document_sub_commands<W: DocumentWriter>(
+        writer: &mut W,
+        command_list: &CommandList,
+    ) -> std::io::Result<()> {
+        T::write_document_sub_commands(writer, command_list)
+    }
 }
 
 impl<T: Positional> Positional for Arc<T> {
+    type Value = T::Value;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
         info: &PositionalInfo<Self>,
     ) -> PositionalResult<'a> {
         let mut inner = this.take().map(|inner| Arc::into_inner(inner).unwrap());
-        let result = T::parse(&mut inner, argument, &info.drop_default())?;
+        let result = T::parse(&mut inner, argument, &info.inner_info())?;
         *this = Some(Arc::new(inner.unwrap()));
         result
     }
@@ -40,9 +54,16 @@ impl<T: Positional> Positional for Arc<T> {
         this: &mut Option<Self>,
         command: Argument,
         source: &mut dyn ArgumentSource,
-        command_list: String,
-    ) -> Result<bool> {
+        command_list: CommandList,
+    ) -> Result<ParseOutcome<()>> {
         let mut inner = this.take().map(|inner| Arc::into_inner(inner).unwrap());
         T::sub(&mut inner, command, source, command_list)
     }
+
+    fn write_document_sub_commands<W: DocumentWriter>(
+        writer: &mut W,
+        command_list: &CommandList,
+    ) -> std::io::Result<()> {
+        T::write_document_sub_commands(writer, command_list)
+    }
 }
@@ -2,6 +2,8 @@ use crate::{Argument, Error, InvalidLengthError, Positional, PositionalInfo, Pos
 use std::ffi::OsString;
 
 impl Positional for String {
+    type Value = Self;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -33,12 +35,15 @@ impl Positional for String {
             }
         }
 
+        info.validate_value(&str)?;
         *this = Some(str);
         PositionalResult::Next
     }
 }
 
 impl Positional for OsString {
+    type Value = Self;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -67,6 +72,7 @@ impl Positional for OsString {
             }
         }
 
+        info.validate_value(&str)?;
         *this = Some(str);
         PositionalResult::Next
     }
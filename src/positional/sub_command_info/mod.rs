@@ -0,0 +1,14 @@
+use std::io::Write;
+
+mod write_help;
+
+/// Information about a sub-command a positional can parse
+#[derive(Debug, Clone, Copy)]
+pub struct SubCommandInfo {
+    /// The name the user types to select this sub-command
+    pub name: &'static str,
+
+    /// A function which writes the description of this sub-command to an output, taking a margin
+    /// for printing new lines
+    pub description: Option<fn(&mut dyn Write, usize) -> std::io::Result<()>>,
+}
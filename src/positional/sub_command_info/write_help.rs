@@ -0,0 +1,37 @@
+use crate::{
+    text_wrap::{display_width, first_paragraph},
+    Style, SubCommandInfo, TextWrap,
+};
+use std::io::Write;
+
+impl SubCommandInfo {
+    /// Writes the name of this sub-command and the first paragraph of its description to
+    /// `output`, starting the description at `description_offset`
+    pub fn write_help(
+        &self,
+        output: &mut dyn Write,
+        description_offset: usize,
+        wrap: TextWrap,
+        style: Style,
+    ) -> std::io::Result<()> {
+        write!(output, "  {}", style.literal(self.name))?;
+
+        if let Some(description) = self.description {
+            for _ in display_width(self.name) + 2..description_offset {
+                write!(output, " ")?;
+            }
+
+            let mut buffer = Vec::new();
+            description(&mut buffer, description_offset)?;
+
+            let text = String::from_utf8_lossy(&buffer);
+            wrap.write(
+                output,
+                first_paragraph(text.trim_start()).trim_end(),
+                description_offset,
+            )?;
+        }
+
+        writeln!(output)
+    }
+}
@@ -2,6 +2,8 @@ use crate::{Argument, Error, InvalidDurationError, Positional, PositionalInfo, P
 use std::time::Duration;
 
 impl Positional for Duration {
+    type Value = Self;
+
     fn parse<'a>(
         this: &mut Option<Self>,
         argument: Argument<'a>,
@@ -18,7 +20,9 @@ impl Positional for Duration {
             ));
         }
 
-        *this = Some(Duration::from_secs_f64(duration));
+        let duration = Duration::from_secs_f64(duration);
+        info.validate_value(&duration)?;
+        *this = Some(duration);
         PositionalResult::Next
     }
 }
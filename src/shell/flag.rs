@@ -0,0 +1,31 @@
+use crate::{ArgumentSource, Error, Flag, FlagInfo, Result, Shell, ValueHint};
+
+impl Flag for Shell {
+    type Value = Self;
+
+    fn parse(
+        this: &mut Option<Self>,
+        source: &mut dyn ArgumentSource,
+        info: &FlagInfo<Self>,
+        long: bool,
+    ) -> Result<()> {
+        if this.is_some() {
+            return Err(Error::repeated_flag(info, long));
+        }
+
+        let value = source.next().ok_or(Error::missing_flag_value(info, long))?;
+
+        let value = value
+            .as_str()?
+            .parse()
+            .map_err(|error| Error::invalid_flag_value(info, long, error))?;
+
+        info.validate_value(&value, long)?;
+        *this = Some(value);
+        Ok(())
+    }
+
+    fn value_hint(_: &FlagInfo<Self>) -> ValueHint {
+        ValueHint::Values(&["bash", "zsh", "fish"])
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{Shell, UnexpectedError};
+
+impl std::str::FromStr for Shell {
+    type Err = UnexpectedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Shell::Bash),
+            "zsh" => Ok(Shell::Zsh),
+            "fish" => Ok(Shell::Fish),
+            _ => Err(UnexpectedError::among(
+                s,
+                "\"bash\", \"zsh\" or \"fish\"",
+                &["bash", "zsh", "fish"],
+            )),
+        }
+    }
+}
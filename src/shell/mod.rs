@@ -0,0 +1,17 @@
+mod display;
+mod flag;
+mod from_str;
+mod positional;
+
+/// A shell which completion scripts can be generated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    /// The Bourne Again SHell
+    Bash,
+
+    /// The Z shell
+    Zsh,
+
+    /// The friendly interactive shell
+    Fish,
+}
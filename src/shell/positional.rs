@@ -0,0 +1,26 @@
+use crate::{Argument, Error, Positional, PositionalInfo, PositionalResult, Shell, ValueHint};
+
+impl Positional for Shell {
+    type Value = Self;
+
+    fn parse<'a>(
+        this: &mut Option<Self>,
+        argument: Argument<'a>,
+        info: &PositionalInfo<Self>,
+    ) -> PositionalResult<'a> {
+        match argument.as_str()?.parse() {
+            Ok(value) => {
+                info.validate_value(&value)?;
+                *this = Some(value)
+            }
+            Err(error) => {
+                return PositionalResult::Error(Error::invalid_positional_value(info.value, error))
+            }
+        }
+        PositionalResult::Next
+    }
+
+    fn value_hint(_: &PositionalInfo<Self>) -> ValueHint {
+        ValueHint::Values(&["bash", "zsh", "fish"])
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{ArgGroup, FlagGroupSpec, FlagSpec, PositionalSpec};
+
+/// A description of a command and its sub-commands as data
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CommandSpec {
+    /// The name of the program for the root command, or the name of the sub-command
+    pub name: String,
+
+    /// The description of the command
+    pub description: Option<String>,
+
+    /// The extended description of the command
+    pub long_description: Option<String>,
+
+    /// The version of the command, if it has a version flag
+    pub version: Option<String>,
+
+    /// The positionals the command accepts, in order
+    pub positionals: Vec<PositionalSpec>,
+
+    /// The flags of the command outside of any flag group
+    pub flags: Vec<FlagSpec>,
+
+    /// The arg groups of the flags outside of any flag group
+    pub arg_groups: Vec<ArgGroup>,
+
+    /// The flag groups of the command
+    pub flag_groups: Vec<FlagGroupSpec>,
+
+    /// The sub-commands of the command
+    pub sub_commands: Vec<CommandSpec>,
+}
@@ -0,0 +1,7 @@
+use crate::SpecWriter;
+
+impl Default for SpecWriter {
+    fn default() -> Self {
+        SpecWriter::new()
+    }
+}
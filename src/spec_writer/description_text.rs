@@ -0,0 +1,19 @@
+/// Gets the text written by `description` with each line trimmed of whitespace, or [`None`] if
+/// there is no text
+pub(super) fn description_text<F: FnOnce(&mut dyn std::io::Write, usize) -> std::io::Result<()>>(
+    description: Option<F>,
+) -> std::io::Result<Option<String>> {
+    let mut buffer = Vec::new();
+    if let Some(description) = description {
+        description(&mut buffer, 0)?;
+    }
+
+    let text = String::from_utf8_lossy(&buffer)
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let text = text.trim();
+
+    Ok((!text.is_empty()).then(|| text.to_owned()))
+}
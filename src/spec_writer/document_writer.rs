@@ -0,0 +1,144 @@
+use super::description_text;
+use crate::{
+    ArgGroup, Command, CommandList, CommandSpec, DocumentWriter, Flag, FlagGroup, FlagGroupSpec,
+    FlagInfo, FlagSpec, Positional, PositionalInfo, PositionalSpec, SpecWriter,
+};
+
+impl DocumentWriter for SpecWriter {
+    /// Sub-commands are nested in the command leading to them when the spec is finished
+    fn command<C: Command>(
+        &mut self,
+        name: &str,
+        command_list: &CommandList,
+    ) -> std::io::Result<CommandList> {
+        let command_list = match command_list.program() {
+            None if command_list.is_root() => CommandList::with_program(name),
+            _ => command_list.clone(),
+        };
+
+        let names: Vec<String> = command_list
+            .program()
+            .map(str::to_owned)
+            .into_iter()
+            .chain(command_list.sub_commands().iter().cloned())
+            .collect();
+
+        let version = C::version();
+
+        self.flag_groups.clear();
+        self.commands.push((
+            names.clone(),
+            CommandSpec {
+                name: names.last().cloned().unwrap_or_default(),
+                description: description_text(Some(C::write_description))?,
+                long_description: description_text(Some(C::write_long_description))?,
+                version: (!version.is_empty()).then(|| version.trim_end().to_owned()),
+                ..CommandSpec::default()
+            },
+        ));
+
+        Ok(command_list)
+    }
+
+    /// Sections aren't part of specs, other than the titles of flag groups
+    fn section(&mut self, _: &str) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn flag_group<G: FlagGroup>(&mut self, title: &str) -> std::io::Result<()> {
+        self.flag_groups.push(FlagGroupSpec {
+            title: title.to_owned(),
+            ..FlagGroupSpec::default()
+        });
+
+        Ok(())
+    }
+
+    fn end_flag_group(&mut self) -> std::io::Result<()> {
+        if let Some(flag_group) = self.flag_groups.pop() {
+            match self.flag_groups.last_mut() {
+                Some(parent) => parent.flag_groups.push(flag_group),
+                None => {
+                    if let Some((_, command)) = self.commands.last_mut() {
+                        command.flag_groups.push(flag_group);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn positional<T: Positional>(&mut self, info: &PositionalInfo<T>) -> std::io::Result<()> {
+        let positional = PositionalSpec {
+            value: info.value,
+            min: info.min,
+            max: info.max,
+            default: info
+                .default
+                .map(|default| default().as_display().to_string()),
+            description: description_text(info.description)?,
+            long_description: description_text(info.long_description)?,
+            value_hint: T::value_hint(info),
+            required: T::is_required(info),
+            multiple: T::multiple(info),
+            hidden: info.hidden,
+        };
+
+        if let Some((_, command)) = self.commands.last_mut() {
+            command.positionals.push(positional);
+        }
+
+        Ok(())
+    }
+
+    fn flag<T: Flag>(&mut self, info: &FlagInfo<T>) -> std::io::Result<()> {
+        let flag = FlagSpec {
+            long_name: info.long_name,
+            short_name: info.short_name,
+            value: match info.value {
+                Some(value) => Some(value),
+                // A fixed name keeps the spec the same in every locale
+                None if T::takes_value(info) => Some("VALUE"),
+                None => None,
+            },
+            min: info.min,
+            max: info.max,
+            default: info
+                .default
+                .map(|default| default().as_display().to_string()),
+            description: description_text(info.description)?,
+            long_description: description_text(info.long_description)?,
+            value_hint: T::value_hint(info),
+            multiple: T::multiple(info),
+            required: T::is_required(info),
+            conflicts_with: info.conflicts_with,
+            requires: info.requires,
+            hidden: info.hidden,
+        };
+
+        match self.flag_groups.last_mut() {
+            Some(flag_group) => flag_group.flags.push(flag),
+            None => {
+                if let Some((_, command)) = self.commands.last_mut() {
+                    command.flags.push(flag);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn arg_group(&mut self, arg_group: &ArgGroup) -> std::io::Result<()> {
+        match self.flag_groups.last_mut() {
+            Some(flag_group) => flag_group.arg_groups.push(*arg_group),
+            None => {
+                if let Some((_, command)) = self.commands.last_mut() {
+                    command.arg_groups.push(*arg_group);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
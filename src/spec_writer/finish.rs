@@ -0,0 +1,41 @@
+use crate::{CommandSpec, DocumentWriter, FlagGroupSpec, SpecWriter};
+
+impl SpecWriter {
+    /// Gets the spec of the first command written, with the commands written after it nested as
+    /// its sub-commands
+    ///
+    /// The spec is empty if no command was written.
+    pub fn finish(mut self) -> CommandSpec {
+        while self.commands.len() > 1 {
+            let (names, command) = self.commands.pop().unwrap();
+            let parent_names = &names[..names.len().saturating_sub(1)];
+
+            match self
+                .commands
+                .iter_mut()
+                .rev()
+                .find(|(names, _)| names == parent_names)
+            {
+                Some((_, parent)) => parent.sub_commands.insert(0, command),
+                None => self.commands[0].1.sub_commands.insert(0, command),
+            }
+        }
+
+        self.commands
+            .pop()
+            .map(|(_, command)| command)
+            .unwrap_or_default()
+    }
+
+    /// Gets the spec of the first flag group started, with any flag groups still open nested in it
+    ///
+    /// This is used to describe a flag group on its own, without a command containing it. The spec
+    /// is empty if no flag group was started.
+    pub fn finish_flag_group(mut self) -> FlagGroupSpec {
+        while self.flag_groups.len() > 1 {
+            self.end_flag_group().unwrap();
+        }
+
+        self.flag_groups.pop().unwrap_or_default()
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{ArgGroup, FlagSpec};
+
+/// A description of a flag group as data
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FlagGroupSpec {
+    /// The title of the flag group in help, empty for a flag group described on its own
+    pub title: String,
+
+    /// The flags of the flag group outside of any nested flag group
+    pub flags: Vec<FlagSpec>,
+
+    /// The arg groups of the flags outside of any nested flag group
+    pub arg_groups: Vec<ArgGroup>,
+
+    /// The flag groups nested in the flag group
+    pub flag_groups: Vec<FlagGroupSpec>,
+}
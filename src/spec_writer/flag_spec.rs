@@ -0,0 +1,47 @@
+use crate::ValueHint;
+
+/// A description of a flag as data
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FlagSpec {
+    /// The long name of the flag, including the leading dashes
+    pub long_name: Option<&'static str>,
+
+    /// The short name of the flag, including the leading dash
+    pub short_name: Option<&'static str>,
+
+    /// The name of the value of the flag, if it takes one, or "VALUE" if it isn't named
+    pub value: Option<&'static str>,
+
+    /// The requested minimum length/quantity/value of arguments
+    pub min: Option<f64>,
+
+    /// The requested maximum length/quantity/value of arguments
+    pub max: Option<f64>,
+
+    /// The default value of the flag, as displayed in help
+    pub default: Option<String>,
+
+    /// The description of the flag
+    pub description: Option<String>,
+
+    /// The extended description of the flag
+    pub long_description: Option<String>,
+
+    /// The hint describing the values the flag accepts
+    pub value_hint: ValueHint,
+
+    /// Can the flag be given multiple times, collecting each value?
+    pub multiple: bool,
+
+    /// Must the flag be passed?
+    pub required: bool,
+
+    /// The long names of the flags which can't be passed with this flag
+    pub conflicts_with: &'static [&'static str],
+
+    /// The long names of the flags which must be passed with this flag
+    pub requires: &'static [&'static str],
+
+    /// Is the flag left out of help, documentation and completions?
+    pub hidden: bool,
+}
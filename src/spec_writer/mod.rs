@@ -0,0 +1,30 @@
+use description_text::description_text;
+
+mod command_spec;
+mod flag_group_spec;
+mod flag_spec;
+mod positional_spec;
+
+mod default;
+mod description_text;
+mod document_writer;
+mod finish;
+mod new;
+
+pub use command_spec::CommandSpec;
+pub use flag_group_spec::FlagGroupSpec;
+pub use flag_spec::FlagSpec;
+pub use positional_spec::PositionalSpec;
+
+/// Collects the commands written to it into [`CommandSpec`]s describing them as data
+///
+/// Commands are described to this writer through [`DocumentWriter`](crate::DocumentWriter), after
+/// which [`SpecWriter::finish`] produces the spec of the root command.
+pub struct SpecWriter {
+    /// The commands described so far with the names leading to them, starting with the root
+    /// command
+    commands: Vec<(Vec<String>, CommandSpec)>,
+
+    /// The flag groups started in the current command which haven't ended yet, innermost last
+    flag_groups: Vec<FlagGroupSpec>,
+}
@@ -0,0 +1,11 @@
+use crate::SpecWriter;
+
+impl SpecWriter {
+    /// Creates a new [`SpecWriter`] with no commands
+    pub fn new() -> Self {
+        SpecWriter {
+            commands: Vec::new(),
+            flag_groups: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::ValueHint;
+
+/// A description of a positional as data
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PositionalSpec {
+    /// The name of the value of the positional
+    pub value: &'static str,
+
+    /// The requested minimum length/quantity/value of arguments
+    pub min: Option<f64>,
+
+    /// The requested maximum length/quantity/value of arguments
+    pub max: Option<f64>,
+
+    /// The default value of the positional, as displayed in help
+    pub default: Option<String>,
+
+    /// The description of the positional
+    pub description: Option<String>,
+
+    /// The extended description of the positional
+    pub long_description: Option<String>,
+
+    /// The hint describing the values the positional accepts
+    pub value_hint: ValueHint,
+
+    /// Must the positional be passed?
+    pub required: bool,
+
+    /// Can the positional take multiple values?
+    pub multiple: bool,
+
+    /// Is the positional left out of help, documentation and completions?
+    pub hidden: bool,
+}
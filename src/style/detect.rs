@@ -0,0 +1,47 @@
+use crate::{ColorChoice, Style};
+use std::io::IsTerminal;
+
+impl Style {
+    /// Detects the [`Style`] to use for `stream`, as [`Style::choose`] with [`ColorChoice::Auto`]
+    pub fn detect<S: IsTerminal>(stream: &S) -> Self {
+        Style::choose(ColorChoice::Auto, stream)
+    }
+
+    /// Chooses the [`Style`] to use for `stream` following `choice`
+    ///
+    /// When `choice` is [`ColorChoice::Auto`], styling is disabled if `NO_COLOR` is set, enabled if
+    /// `CLICOLOR_FORCE` is set to anything other than "0", and otherwise enabled only if `stream`
+    /// is a terminal.
+    pub fn choose<S: IsTerminal>(choice: ColorChoice, stream: &S) -> Self {
+        Style::new(match choice {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                if is_set("NO_COLOR") {
+                    false
+                } else if is_set("CLICOLOR_FORCE") {
+                    std::env::var_os("CLICOLOR_FORCE").unwrap() != "0"
+                } else {
+                    stream.is_terminal()
+                }
+            }
+        })
+    }
+
+    /// Detects the [`Style`] to use for stdout
+    pub fn stdout() -> Self {
+        Style::detect(&std::io::stdout())
+    }
+
+    /// Detects the [`Style`] to use for stderr
+    pub fn stderr() -> Self {
+        Style::detect(&std::io::stderr())
+    }
+}
+
+/// Is the environment variable `name` set to a non-empty value?
+fn is_set(name: &str) -> bool {
+    std::env::var_os(name)
+        .map(|value| !value.is_empty())
+        .unwrap_or(false)
+}
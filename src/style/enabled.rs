@@ -0,0 +1,8 @@
+use crate::Style;
+
+impl Style {
+    /// Are ANSI escape codes written?
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{Style, Styled};
+
+impl Style {
+    /// Styles `value` as an error
+    pub fn error<T>(&self, value: T) -> Styled<T> {
+        Styled::new(value, self.enabled.then_some("1;31"))
+    }
+}
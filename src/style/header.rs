@@ -0,0 +1,8 @@
+use crate::{Style, Styled};
+
+impl Style {
+    /// Styles `value` as a section header
+    pub fn header<T>(&self, value: T) -> Styled<T> {
+        Styled::new(value, self.enabled.then_some("1;4"))
+    }
+}
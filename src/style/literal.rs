@@ -0,0 +1,8 @@
+use crate::{Style, Styled};
+
+impl Style {
+    /// Styles `value` as a literal the user can type, like a flag name
+    pub fn literal<T>(&self, value: T) -> Styled<T> {
+        Styled::new(value, self.enabled.then_some("1"))
+    }
+}
@@ -0,0 +1,18 @@
+mod detect;
+mod enabled;
+mod error;
+mod header;
+mod literal;
+mod new;
+mod styled;
+
+pub use styled::Styled;
+
+/// Applies ANSI styling to output
+///
+/// A disabled style writes values unchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    /// Are ANSI escape codes written?
+    enabled: bool,
+}
@@ -0,0 +1,8 @@
+use crate::Style;
+
+impl Style {
+    /// Creates a new [`Style`] which writes ANSI escape codes if `enabled`
+    pub const fn new(enabled: bool) -> Self {
+        Style { enabled }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::Styled;
+
+impl<T: std::fmt::Display> std::fmt::Display for Styled<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "\x1B[{}m{}\x1B[0m", code, self.value),
+            None => self.value.fmt(f),
+        }
+    }
+}
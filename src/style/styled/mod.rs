@@ -0,0 +1,12 @@
+mod display;
+mod new;
+
+/// A value displayed with an ANSI style
+#[derive(Debug, Clone, Copy)]
+pub struct Styled<T> {
+    /// The value to display
+    value: T,
+
+    /// The parameters of the ANSI escape code, if any
+    code: Option<&'static str>,
+}
@@ -0,0 +1,8 @@
+use crate::Styled;
+
+impl<T> Styled<T> {
+    /// Creates a new [`Styled`] displaying `value` with the ANSI escape code parameters `code`
+    pub(crate) fn new(value: T, code: Option<&'static str>) -> Self {
+        Styled { value, code }
+    }
+}
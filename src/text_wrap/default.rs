@@ -0,0 +1,10 @@
+use crate::TextWrap;
+
+/// The width used when the terminal width cannot be detected
+const DEFAULT_WIDTH: usize = 80;
+
+impl Default for TextWrap {
+    fn default() -> Self {
+        TextWrap::new(DEFAULT_WIDTH)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::TextWrap;
+use std::io::IsTerminal;
+
+impl TextWrap {
+    /// Creates a new [`TextWrap`] for the width of the terminal
+    ///
+    /// The width is taken from the `COLUMNS` environment variable if it is set, otherwise it is
+    /// queried from the terminal attached to stdout. If stdout is not a terminal, the default
+    /// width of 80 columns is used.
+    pub fn detect() -> Self {
+        if let Some(width) = std::env::var("COLUMNS")
+            .ok()
+            .and_then(|columns| columns.trim().parse().ok())
+            .filter(|width| *width > 0)
+        {
+            return TextWrap::new(width);
+        }
+
+        if std::io::stdout().is_terminal() {
+            if let Some(width) = terminal_width() {
+                return TextWrap::new(width);
+            }
+        }
+
+        TextWrap::default()
+    }
+}
+
+/// Queries the width of the terminal attached to stdout
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+fn terminal_width() -> Option<usize> {
+    use std::ffi::c_int;
+
+    // The request type of `ioctl` is `int` on musl and `unsigned long` elsewhere
+    #[cfg(all(target_os = "linux", target_env = "musl"))]
+    type Request = c_int;
+    #[cfg(not(all(target_os = "linux", target_env = "musl")))]
+    type Request = std::ffi::c_ulong;
+
+    // Linux uses the generic ioctl numbers except on the architectures which kept the numbers of
+    // older systems, which match the BSD encoding of `_IOR('t', 104, struct winsize)`
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        not(any(
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "sparc",
+            target_arch = "sparc64"
+        ))
+    ))]
+    const TIOCGWINSZ: Request = 0x5413;
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        any(
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "sparc",
+            target_arch = "sparc64"
+        )
+    ))]
+    const TIOCGWINSZ: Request = 0x40087468;
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    const TIOCGWINSZ: Request = 0x40087468;
+
+    const STDOUT_FILENO: c_int = 1;
+
+    // `struct winsize` has the same layout on every supported system
+    #[repr(C)]
+    #[derive(Default)]
+    struct WinSize {
+        rows: u16,
+        columns: u16,
+        x_pixels: u16,
+        y_pixels: u16,
+    }
+
+    extern "C" {
+        fn ioctl(fd: c_int, request: Request, ...) -> c_int;
+    }
+
+    let mut size = WinSize::default();
+    if unsafe { ioctl(STDOUT_FILENO, TIOCGWINSZ, &mut size as *mut WinSize) } != 0 {
+        return None;
+    }
+
+    if size.columns == 0 {
+        None
+    } else {
+        Some(size.columns as usize)
+    }
+}
+
+/// Queries the width of the terminal attached to stdout
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+)))]
+fn terminal_width() -> Option<usize> {
+    None
+}
@@ -0,0 +1,59 @@
+/// Gets the number of columns `text` occupies when displayed on a terminal
+///
+/// ANSI escape sequences, like those added by [`Style`](crate::Style), take no columns.
+pub(crate) fn display_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1B' {
+            width += char_width(c);
+            continue;
+        }
+
+        // Control sequences run until a final byte in '@'..='~', other escapes are two characters
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+    width
+}
+
+/// Gets the number of columns `c` occupies when displayed on a terminal
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        // Control characters
+        0x00..=0x1F | 0x7F..=0x9F => 0,
+
+        // Combining marks, zero-width characters and variation selectors
+        0x0300..=0x036F
+        | 0x1AB0..=0x1AFF
+        | 0x1DC0..=0x1DFF
+        | 0x200B..=0x200F
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F
+        | 0xFE20..=0xFE2F => 0,
+
+        // East Asian wide and fullwidth characters
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x2FFFD
+        | 0x30000..=0x3FFFD => 2,
+
+        _ => 1,
+    }
+}
@@ -0,0 +1,15 @@
+/// Gets the first paragraph of `text`, ending before the first line which is blank
+///
+/// The new line before the blank line is kept, so text which ends with a new line still does.
+pub(crate) fn first_paragraph(text: &str) -> &str {
+    let mut start = 0;
+    for line in text.split_inclusive('\n') {
+        if start > 0 && line.trim().is_empty() {
+            return &text[..start];
+        }
+
+        start += line.len();
+    }
+
+    text
+}
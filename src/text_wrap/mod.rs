@@ -0,0 +1,21 @@
+mod default;
+mod detect;
+mod display_width;
+mod first_paragraph;
+mod new;
+mod width;
+mod write;
+mod write_description;
+mod write_summary;
+
+pub(crate) use display_width::display_width;
+pub(crate) use first_paragraph::first_paragraph;
+
+/// Word-wraps help text to a maximum line width
+///
+/// Lines which wrap are continued at the column they started at, creating a hanging indent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextWrap {
+    /// The maximum width of a line, in columns
+    width: usize,
+}
@@ -0,0 +1,8 @@
+use crate::TextWrap;
+
+impl TextWrap {
+    /// Creates a new [`TextWrap`] which wraps lines at `width` columns
+    pub const fn new(width: usize) -> Self {
+        TextWrap { width }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::TextWrap;
+
+impl TextWrap {
+    /// Gets the maximum width of a line, in columns
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{text_wrap::display_width, TextWrap};
+use std::io::Write;
+
+/// The fewest columns text will be wrapped to, regardless of the indent
+const MINIMUM_WIDTH: usize = 20;
+
+impl TextWrap {
+    /// Writes `text` to `output`, wrapping lines which exceed the width
+    ///
+    /// `indent` is the column the text starts at. Wrapped lines and lines after a new line are
+    /// indented to this column. Leading spaces up to `indent` on lines after a new line are
+    /// replaced by this indent, and lines of only spaces are left empty. Runs of spaces between
+    /// words are kept, except where a line is wrapped.
+    pub fn write(&self, output: &mut dyn Write, text: &str, indent: usize) -> std::io::Result<()> {
+        let available = self.width.saturating_sub(indent).max(MINIMUM_WIDTH);
+
+        for (i, line) in text.split('\n').enumerate() {
+            let mut line = line;
+            if i > 0 {
+                writeln!(output)?;
+                if line.trim_start_matches(' ').is_empty() {
+                    continue;
+                }
+
+                let margin = line.len() - line.trim_start_matches(' ').len();
+                line = &line[margin.min(indent)..];
+                write_spaces(output, indent)?;
+            }
+
+            let content = line.trim_start_matches(' ');
+            let leading = line.len() - content.len();
+            write_spaces(output, leading)?;
+
+            let mut column = leading;
+            let mut spaces = None;
+            for word in content.split(' ') {
+                if word.is_empty() {
+                    if let Some(spaces) = &mut spaces {
+                        *spaces += 1;
+                    }
+                    continue;
+                }
+
+                let width = display_width(word);
+
+                if let Some(spaces) = spaces {
+                    if column + spaces + width > available {
+                        writeln!(output)?;
+                        write_spaces(output, indent)?;
+                        column = 0;
+                    } else {
+                        write_spaces(output, spaces)?;
+                        column += spaces;
+                    }
+                }
+
+                write!(output, "{}", word)?;
+                column += width;
+                spaces = Some(1);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes `count` spaces to `output`
+fn write_spaces(output: &mut dyn Write, count: usize) -> std::io::Result<()> {
+    for _ in 0..count {
+        write!(output, " ")?;
+    }
+
+    Ok(())
+}
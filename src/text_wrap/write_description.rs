@@ -0,0 +1,19 @@
+use crate::TextWrap;
+use std::io::Write;
+
+impl TextWrap {
+    /// Writes the output of `description` to `output`, wrapping lines which exceed the width
+    ///
+    /// `indent` is the column the description starts at and is passed to `description` as its
+    /// margin.
+    pub fn write_description(
+        &self,
+        output: &mut dyn Write,
+        description: fn(&mut dyn Write, usize) -> std::io::Result<()>,
+        indent: usize,
+    ) -> std::io::Result<()> {
+        let mut buffer = Vec::new();
+        description(&mut buffer, indent)?;
+        self.write(output, &String::from_utf8_lossy(&buffer), indent)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{text_wrap::first_paragraph, TextWrap};
+use std::io::Write;
+
+impl TextWrap {
+    /// Writes the first paragraph of the output of `description` to `output`, wrapping lines which
+    /// exceed the width
+    ///
+    /// `indent` is the column the description starts at and is passed to `description` as its
+    /// margin.
+    pub fn write_summary(
+        &self,
+        output: &mut dyn Write,
+        description: fn(&mut dyn Write, usize) -> std::io::Result<()>,
+        indent: usize,
+    ) -> std::io::Result<()> {
+        let mut buffer = Vec::new();
+        description(&mut buffer, indent)?;
+        self.write(
+            output,
+            first_paragraph(&String::from_utf8_lossy(&buffer)),
+            indent,
+        )
+    }
+}
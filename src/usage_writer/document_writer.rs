@@ -0,0 +1,53 @@
+use super::UsageWriter;
+use crate::{
+    Command, CommandList, DocumentWriter, Flag, FlagInfo, HelpMode, Positional, PositionalInfo,
+    Style, TextWrap,
+};
+
+impl<'a> DocumentWriter for UsageWriter<'a> {
+    /// Commands are matched by their sub-command names, as the program name written may differ
+    /// from the one the program was run with. Only commands which write help have a help flag.
+    fn command<C: Command>(
+        &mut self,
+        name: &str,
+        command_list: &CommandList,
+    ) -> std::io::Result<CommandList> {
+        let command_list = match command_list.program() {
+            None if command_list.is_root() => CommandList::with_program(name),
+            _ => command_list.clone(),
+        };
+
+        if command_list.sub_commands() == self.command_list.sub_commands() {
+            C::write_usage(self.output, self.command_list, self.style)?;
+
+            let mut help = Vec::new();
+            C::write_help(
+                &mut help,
+                self.command_list,
+                TextWrap::new(usize::MAX),
+                Style::new(false),
+                HelpMode::Short,
+            )?;
+            self.has_help = !help.is_empty();
+        }
+
+        Ok(command_list)
+    }
+
+    /// Errors in hidden sub-commands are written with their usage too
+    fn hidden_commands(&self) -> bool {
+        true
+    }
+
+    fn section(&mut self, _: &str) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn positional<T: Positional>(&mut self, _: &PositionalInfo<T>) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn flag<T: Flag>(&mut self, _: &FlagInfo<T>) -> std::io::Result<()> {
+        Ok(())
+    }
+}
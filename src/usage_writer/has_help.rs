@@ -0,0 +1,8 @@
+use super::UsageWriter;
+
+impl<'a> UsageWriter<'a> {
+    /// Was the command found among those described to this writer, and does it have a help flag
+    pub(crate) fn has_help(&self) -> bool {
+        self.has_help
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{CommandList, Style};
+use std::io::Write;
+
+mod document_writer;
+mod has_help;
+mod new;
+
+/// Writes the usage of the sub-command reached through a list of names, found among the commands
+/// described to it
+pub(crate) struct UsageWriter<'a> {
+    /// The output to write the usage to
+    output: &'a mut dyn Write,
+
+    /// The names leading to the command to write the usage of, which is also written in the usage
+    command_list: &'a CommandList,
+
+    /// The style to write the usage with
+    style: Style,
+
+    /// Was the command found and does it have a help flag
+    has_help: bool,
+}
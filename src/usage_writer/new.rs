@@ -0,0 +1,20 @@
+use super::UsageWriter;
+use crate::{CommandList, Style};
+use std::io::Write;
+
+impl<'a> UsageWriter<'a> {
+    /// Creates a new [`UsageWriter`] writing the usage of the command `command_list` leads to into
+    /// `output` with `style`
+    pub(crate) fn new(
+        output: &'a mut dyn Write,
+        command_list: &'a CommandList,
+        style: Style,
+    ) -> Self {
+        UsageWriter {
+            output,
+            command_list,
+            style,
+            has_help: false,
+        }
+    }
+}
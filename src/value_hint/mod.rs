@@ -0,0 +1,20 @@
+/// A hint describing the values an argument accepts, used for shell completion and to list the
+/// possible values in help
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValueHint {
+    /// Any value, with nothing to complete
+    #[default]
+    Any,
+
+    /// A whole number, with nothing to complete
+    Integer,
+
+    /// A number which may have a fractional part, with nothing to complete
+    Float,
+
+    /// A path to a file or directory
+    Path,
+
+    /// One of a fixed set of values
+    Values(&'static [&'static str]),
+}
@@ -0,0 +1,32 @@
+use crate::{
+    messages, ArgumentSource, Command, CommandList, DocumentWriter, ParseOutcome, Result, Style,
+    VersionSubCommand,
+};
+use std::io::Write;
+
+impl Command for VersionSubCommand {
+    /// Arguments after the sub-command are left in `source`, as derived commands ignore them
+    fn parse(_: &mut dyn ArgumentSource, _: CommandList) -> Result<ParseOutcome<Self>> {
+        Ok(ParseOutcome::Parsed(VersionSubCommand))
+    }
+
+    fn write_description(output: &mut dyn Write, _: usize) -> std::io::Result<()> {
+        writeln!(output, "{}", messages().version_sub_command())
+    }
+
+    fn write_usage(
+        output: &mut dyn Write,
+        command_list: &CommandList,
+        _: Style,
+    ) -> std::io::Result<()> {
+        write!(output, "{}", command_list)
+    }
+
+    fn write_document<W: DocumentWriter>(
+        writer: &mut W,
+        command_list: &CommandList,
+    ) -> std::io::Result<()> {
+        writer.command::<Self>("version", command_list)?;
+        Ok(())
+    }
+}
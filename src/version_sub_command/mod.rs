@@ -0,0 +1,9 @@
+mod command;
+
+/// The "version" sub-command built into commands with `command(version_command)`
+///
+/// Derived commands handle the sub-command themselves, writing their long version. This type
+/// describes it to a [`DocumentWriter`](crate::DocumentWriter) so it appears in documentation and
+/// completions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionSubCommand;
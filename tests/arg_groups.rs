@@ -0,0 +1,49 @@
+use argparse::{ArgGroup, ArgGroupRule, Command, CommandList, HelpMode};
+
+/// Downloads a file
+#[derive(Debug, Command)]
+#[command(help, description)]
+#[arg_group(exactly_one = [file, url])]
+struct Fetch {
+    /// Read the address from a file
+    #[flag]
+    file: Option<String>,
+
+    /// The address to download
+    #[flag]
+    url: Option<String>,
+}
+
+const NOTE: &str = "[requires exactly one of: --file, --url]";
+
+#[test]
+fn spec() {
+    assert_eq!(
+        Fetch::spec().arg_groups,
+        [ArgGroup {
+            rule: ArgGroupRule::ExactlyOne,
+            members: &["--file", "--url"],
+        }]
+    );
+}
+
+#[test]
+fn json() {
+    let json = Fetch::json();
+
+    assert!(json.contains("\"rule\": \"exactly_one\""));
+    assert!(json.contains("\"--file\""));
+}
+
+#[test]
+fn help() {
+    let help = Fetch::help(&CommandList::with_program("fetch"), HelpMode::Short);
+
+    assert!(help.contains(NOTE));
+}
+
+#[test]
+fn documents() {
+    assert!(Fetch::man().contains(NOTE));
+    assert!(Fetch::markdown().contains(NOTE));
+}
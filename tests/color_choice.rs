@@ -0,0 +1,52 @@
+use argparse::{Argument, Command, CommandList, ParseOutcome, Positional};
+
+/// Runs a test tool
+#[derive(Debug, Command)]
+#[command(help, description)]
+struct Tool {
+    /// The action to run
+    action: Action,
+}
+
+/// The actions of the test tool
+#[derive(Debug, Positional)]
+enum Action {
+    /// Build the project
+    Build(Build),
+}
+
+/// Builds the project
+#[derive(Debug, Command)]
+#[command(help, description)]
+struct Build {
+    /// Build with optimizations
+    #[flag]
+    release: bool,
+}
+
+fn help(arguments: &[&'static str]) -> String {
+    let mut source = arguments
+        .iter()
+        .map(|argument| Argument::from(*argument))
+        .collect::<Vec<_>>()
+        .into_iter();
+    match Tool::parse(&mut source, CommandList::with_program("tool")) {
+        Ok(ParseOutcome::Help(help)) => help,
+        _ => panic!("expected help for {:?}", arguments),
+    }
+}
+
+#[test]
+fn never_color() {
+    assert!(!help(&["--color", "never", "--help"]).contains('\x1B'));
+}
+
+#[test]
+fn always_color() {
+    assert!(help(&["--color", "always", "--help"]).contains('\x1B'));
+}
+
+#[test]
+fn sub_commands_keep_the_choice() {
+    assert!(help(&["--color", "always", "build", "--help"]).contains('\x1B'));
+}
@@ -0,0 +1,109 @@
+use argparse::{Command, Positional};
+
+/// Deploys a service
+#[derive(Debug, Command)]
+#[command(help, description, dynamic_completion)]
+struct Deploy {
+    /// The region to deploy to
+    #[flag(short_name, complete = regions)]
+    region: Option<String>,
+
+    /// Skip the confirmation
+    #[flag]
+    yes: bool,
+
+    /// The target to deploy
+    target: Target,
+}
+
+/// The targets which can be deployed
+#[derive(Debug, Positional)]
+enum Target {
+    /// Deploy the web service
+    Web,
+
+    /// Deploy the workers
+    Workers(Workers),
+}
+
+/// Deploys the workers
+#[derive(Debug, Command)]
+#[command(help, description)]
+struct Workers {
+    /// The number of workers to start
+    #[flag]
+    count: Option<u32>,
+}
+
+/// Deploys a service, without answering completion requests
+#[derive(Debug, Command)]
+struct Static {
+    /// Skip the confirmation
+    #[flag]
+    yes: bool,
+}
+
+fn regions(current: &str) -> Vec<String> {
+    ["eu-west", "eu-north", "us-east"]
+        .into_iter()
+        .filter(|region| region.starts_with(current))
+        .map(str::to_owned)
+        .collect()
+}
+
+fn request(arguments: &[&str]) -> Vec<String> {
+    Deploy::complete_request(arguments).expect("expected a completion request")
+}
+
+#[test]
+fn flags() {
+    assert_eq!(request(&["__complete", "1", "deploy", "--y"]), ["--yes"]);
+}
+
+#[test]
+fn sub_commands() {
+    assert_eq!(
+        request(&["__complete", "1", "deploy", "w"]),
+        ["web", "workers"]
+    );
+    assert_eq!(
+        request(&["__complete", "2", "deploy", "workers", "--c"]),
+        ["--count"]
+    );
+}
+
+#[test]
+fn completer_function() {
+    assert_eq!(
+        request(&["__complete", "2", "deploy", "--region", "eu"]),
+        ["eu-west", "eu-north"]
+    );
+    assert_eq!(
+        request(&["__complete", "2", "deploy", "-r", "us"]),
+        ["us-east"]
+    );
+}
+
+#[test]
+fn new_argument() {
+    // The index may be past the end of the command line, completing an empty argument
+    assert_eq!(
+        request(&["__complete", "2", "deploy", "--yes"]),
+        ["web", "workers"]
+    );
+}
+
+#[test]
+fn not_a_request() {
+    assert_eq!(Deploy::complete_request(&["--yes", "web"]), None);
+    assert_eq!(Deploy::complete_request(&[]), None);
+}
+
+#[test]
+fn disabled() {
+    assert!(!Static::dynamic_completion_enabled());
+    assert_eq!(
+        Static::complete_request(&["__complete", "1", "static", "--"]),
+        None
+    );
+}
@@ -0,0 +1,46 @@
+use argparse::{Argument, ArgumentSource, DefaultDisplay, Error, Flag, FlagInfo};
+
+/// A custom flag type relying on the default value type
+#[derive(Debug, PartialEq)]
+struct Level(u8);
+
+impl DefaultDisplay for Level {
+    type Display<'a> = &'a u8;
+
+    fn as_display<'a>(&'a self) -> Self::Display<'a> {
+        &self.0
+    }
+}
+
+impl Flag for Level {
+    fn parse(
+        this: &mut Option<Self>,
+        source: &mut dyn ArgumentSource,
+        info: &FlagInfo<Self>,
+        long: bool,
+    ) -> argparse::Result<()> {
+        let argument = source
+            .next()
+            .ok_or_else(|| Error::missing_flag_value(info, long))?;
+        let level = argument
+            .as_str()?
+            .parse()
+            .map_err(|error| Error::invalid_flag_value(info, long, error))?;
+        *this = Some(Level(level));
+        Ok(())
+    }
+}
+
+#[test]
+fn default_value_type() {
+    let info: FlagInfo<Level> = FlagInfo {
+        long_name: Some("--level"),
+        ..FlagInfo::default()
+    };
+
+    let mut source = vec![Argument::from("3")].into_iter();
+    let mut level = None;
+    Level::parse(&mut level, &mut source, &info, true).unwrap();
+
+    assert_eq!(Level::unwrap(level, &info).unwrap(), Level(3));
+}
@@ -0,0 +1,90 @@
+use argparse::Command;
+
+/// Copies a file without a help flag
+#[derive(Debug, Command)]
+#[command(description)]
+struct Plain {
+    /// The number of copies to make
+    #[flag(short_name)]
+    count: Option<u32>,
+
+    /// The file to copy
+    source: String,
+}
+
+#[test]
+fn spec_without_help() {
+    let spec = Plain::spec();
+
+    assert!(spec
+        .description
+        .as_deref()
+        .is_some_and(|description| description.contains("Copies a file")));
+    assert_eq!(spec.positionals.len(), 1);
+    assert!(spec
+        .flags
+        .iter()
+        .any(|flag| flag.long_name == Some("--count")));
+}
+
+#[test]
+fn spec_value_placeholder() {
+    let spec = Plain::spec();
+    let count = spec
+        .flags
+        .iter()
+        .find(|flag| flag.long_name == Some("--count"))
+        .unwrap();
+
+    assert_eq!(count.value, Some("VALUE"));
+}
+
+#[test]
+fn completion_without_help() {
+    assert!(Plain::complete(&["plain", "--"], 1).contains(&"--count".to_owned()));
+}
+
+#[test]
+fn man_without_help() {
+    let man = Plain::man();
+
+    assert!(man.contains("Copies a file"));
+    assert!(man.contains("count"));
+}
+
+/// Copies a file with the built-in flags
+#[derive(Debug, Command)]
+#[command(help, description, version = "copy 1.0", version_command)]
+struct Builtin {
+    /// The number of copies to make
+    #[flag(short_name)]
+    count: Option<u32>,
+}
+
+#[test]
+fn builtin_flag_candidates() {
+    let candidates = Builtin::complete(&["copy", "--"], 1);
+
+    for flag in ["--count", "--help", "--help-all", "--version", "--color"] {
+        assert!(candidates.contains(&flag.to_owned()), "missing {flag}");
+    }
+    assert!(Builtin::complete(&["copy", "-"], 1).contains(&"-h".to_owned()));
+    assert_eq!(
+        Builtin::complete(&["copy", "--color", ""], 2),
+        ["auto", "always", "never"]
+    );
+}
+
+#[test]
+fn version_command_candidate() {
+    assert_eq!(Builtin::complete(&["copy", "ver"], 1), ["version"]);
+    assert!(Builtin::spec()
+        .sub_commands
+        .iter()
+        .any(|sub_command| sub_command.name == "version"));
+}
+
+#[test]
+fn only_color_flag_without_help() {
+    assert_eq!(Plain::complete(&["plain", "--"], 1), ["--count", "--color"]);
+}
@@ -0,0 +1,47 @@
+use argparse::{Argument, Command, CommandList, Error, ParseOutcome, Positional};
+
+/// Writes a report
+#[derive(Debug, PartialEq, Command)]
+struct Report {
+    /// The format of the report
+    #[flag]
+    format: Format,
+}
+
+/// The formats a report can be written in
+#[derive(Debug, PartialEq, Positional)]
+#[positional(flag)]
+enum Format {
+    /// Plain text
+    Text,
+
+    /// JSON lines
+    JsonLines,
+}
+
+fn parse(arguments: &[&'static str]) -> argparse::Result<ParseOutcome<Report>> {
+    let mut source = arguments
+        .iter()
+        .map(|argument| Argument::from(*argument))
+        .collect::<Vec<_>>()
+        .into_iter();
+    Report::parse(&mut source, CommandList::with_program("report"))
+}
+
+#[test]
+fn parses_value() {
+    assert_eq!(
+        parse(&["--format", "json-lines"]).unwrap(),
+        ParseOutcome::Parsed(Report {
+            format: Format::JsonLines,
+        })
+    );
+}
+
+#[test]
+fn unknown_value() {
+    assert!(matches!(
+        parse(&["--format", "xml"]).unwrap_err().without_context(),
+        Error::InvalidFlagValue("--format", _, _)
+    ));
+}
@@ -0,0 +1,106 @@
+use argparse::{Argument, ArgumentSource, Command, CommandList, Error, ParseOutcome, Positional};
+
+/// Runs a test tool
+#[derive(Debug, Command)]
+#[command(help, description)]
+struct Tool {
+    /// Print more output
+    #[flag(short_name)]
+    verbose: bool,
+
+    /// The action to run
+    action: Action,
+}
+
+/// The actions of the test tool
+#[derive(Debug, Positional)]
+enum Action {
+    /// Build the project
+    Build(Build),
+}
+
+/// Builds the project
+#[derive(Debug, Command)]
+#[command(help, description)]
+struct Build {
+    /// The number of jobs to run at once
+    #[flag]
+    jobs: u32,
+}
+
+/// A source which counts the arguments taken, starting from 1 as the program name isn't included
+struct IndexedSource {
+    arguments: Vec<&'static str>,
+    taken: usize,
+}
+
+impl<'a> ArgumentSource<'a> for IndexedSource {
+    fn next(&mut self) -> Option<Argument<'a>> {
+        let argument = self.arguments.get(self.taken)?;
+        self.taken += 1;
+        Some(Argument::from(*argument))
+    }
+
+    fn empty(&self) -> bool {
+        self.taken == self.arguments.len()
+    }
+
+    fn index(&self) -> Option<usize> {
+        (self.taken > 0).then_some(self.taken)
+    }
+}
+
+fn parse(arguments: &[&'static str]) -> argparse::Result<ParseOutcome<Tool>> {
+    let mut source = IndexedSource {
+        arguments: arguments.to_vec(),
+        taken: 0,
+    };
+    Tool::parse(&mut source, CommandList::with_program("tool"))
+}
+
+#[test]
+fn root_errors_name_the_program() {
+    let error = parse(&["-v", "--unknown"]).unwrap_err();
+
+    assert_eq!(error.command_list().unwrap().to_string(), "tool");
+    assert_eq!(error.argument_index(), Some(2));
+    assert!(matches!(
+        error.without_context(),
+        Error::UnknownArgument(..)
+    ));
+}
+
+#[test]
+fn sub_command_errors_name_the_sub_command() {
+    let error = parse(&["build", "--unknown"]).unwrap_err();
+
+    assert_eq!(error.command_list().unwrap().to_string(), "tool build");
+    assert_eq!(error.argument_index(), Some(2));
+    assert!(matches!(
+        error.without_context(),
+        Error::UnknownArgument(..)
+    ));
+}
+
+#[test]
+fn flag_value_errors_point_at_the_value() {
+    let error = parse(&["-v", "build", "--jobs", "many"]).unwrap_err();
+
+    assert_eq!(error.argument_index(), Some(4));
+    assert!(matches!(
+        error.without_context(),
+        Error::InvalidFlagValue("--jobs", _, _)
+    ));
+}
+
+#[test]
+fn missing_values_have_no_argument() {
+    let error = parse(&["build"]).unwrap_err();
+
+    assert_eq!(error.command_list().unwrap().to_string(), "tool build");
+    assert_eq!(error.argument_index(), None);
+    assert!(matches!(
+        error.without_context(),
+        Error::MissingArgument("--jobs")
+    ));
+}
@@ -0,0 +1,51 @@
+use argparse::{CommandList, Error, InvalidNumberError};
+use std::error::Error as _;
+
+fn invalid_number() -> Error {
+    Error::InvalidPositionalValue("COUNT", Box::new(InvalidNumberError::PosOverflow))
+        .in_command(&CommandList::with_program("tool"), Some(1))
+}
+
+#[test]
+fn value_error_downcasts() {
+    let error = invalid_number();
+
+    let value_error = error.value_error().unwrap();
+    assert!(matches!(
+        value_error.downcast_ref::<InvalidNumberError>(),
+        Some(InvalidNumberError::PosOverflow)
+    ));
+}
+
+#[test]
+fn displayed_errors_are_not_sources() {
+    let error = invalid_number();
+    let message = InvalidNumberError::PosOverflow.to_string();
+
+    assert!(error.to_string().contains(&message));
+    assert!(error.source().is_none());
+}
+
+/// A custom error caused by an I/O error
+#[derive(Debug)]
+struct LoadError(std::io::Error);
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unable to load configuration")
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn sources_of_wrapped_errors() {
+    let error = Error::custom(LoadError(std::io::Error::other("disk full")));
+
+    assert_eq!(error.to_string(), "unable to load configuration");
+    assert_eq!(error.source().unwrap().to_string(), "disk full");
+}
@@ -0,0 +1,112 @@
+use argparse::{
+    Argument, Command, CommandList, Error, FlagGroup, FlagInfo, HelpMode, ParseOutcome, Style,
+    TextWrap,
+};
+
+#[derive(Debug, Command)]
+struct Report {
+    /// Print nothing but errors
+    #[flag(conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Print every step
+    #[flag(conflicts_with = ["quiet"])]
+    verbose: bool,
+
+    /// Where to write the report
+    #[flag]
+    output: Option<String>,
+
+    /// The format of the report
+    #[flag(requires = "output")]
+    format: Option<String>,
+}
+
+fn parse(arguments: &[&'static str]) -> argparse::Result<ParseOutcome<Report>> {
+    let mut source = arguments
+        .iter()
+        .map(|argument| Argument::from(*argument))
+        .collect::<Vec<_>>()
+        .into_iter();
+    Report::parse(&mut source, CommandList::with_program("report"))
+}
+
+#[test]
+fn conflicting_flags() {
+    assert!(matches!(
+        parse(&["--quiet", "--verbose"])
+            .unwrap_err()
+            .without_context(),
+        Error::ConflictingFlags("--quiet", "--verbose")
+    ));
+}
+
+#[test]
+fn missing_required_flag() {
+    assert!(matches!(
+        parse(&["--format", "json"]).unwrap_err().without_context(),
+        Error::MissingRequiredFlag("--format", "--output")
+    ));
+}
+
+#[test]
+fn notes_without_description() {
+    let info: FlagInfo<String> = FlagInfo {
+        long_name: Some("--format"),
+        value: Some("FORMAT"),
+        default: Some(|| "text".to_owned()),
+        requires: &["--output"],
+        ..FlagInfo::default()
+    };
+
+    let mut output = Vec::new();
+    info.write_help(
+        &mut output,
+        false,
+        17,
+        TextWrap::new(80),
+        Style::new(false),
+        HelpMode::Long,
+    )
+    .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "--format FORMAT  [Default: text] [requires: --output]\n"
+    );
+}
+
+#[test]
+fn satisfied_relationships() {
+    assert!(parse(&["--quiet", "--format", "json", "--output", "report.json"]).is_ok());
+}
+
+/// The flags shared by commands writing output
+#[derive(Debug, FlagGroup)]
+struct OutputFlags {
+    /// Where to write the output
+    #[flag]
+    path: Option<String>,
+}
+
+#[derive(Debug, Command)]
+struct Export {
+    /// Compress the output file
+    #[flag(requires = "path")]
+    compress: bool,
+
+    #[flag_group]
+    output: OutputFlags,
+}
+
+#[test]
+fn relationships_with_flag_group_members() {
+    let mut source = vec![Argument::from("--compress")].into_iter();
+
+    assert!(matches!(
+        Export::parse(&mut source, CommandList::with_program("export"))
+            .unwrap_err()
+            .without_context(),
+        Error::MissingRequiredFlag("--compress", "--path")
+    ));
+}
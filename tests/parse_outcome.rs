@@ -0,0 +1,145 @@
+use argparse::{Argument, Command, CommandList, ParseOutcome, Positional};
+
+/// Runs a test tool
+#[derive(Debug, PartialEq, Command)]
+#[command(help, description, version = "tool 1.2.3")]
+struct Tool {
+    /// Print more output
+    #[flag(short_name)]
+    verbose: bool,
+
+    /// The action to run
+    action: Action,
+}
+
+/// The actions of the test tool
+#[derive(Debug, PartialEq, Positional)]
+enum Action {
+    /// Build the project
+    Build(Build),
+
+    /// Remove the build output
+    Clean,
+}
+
+/// Builds the project
+#[derive(Debug, PartialEq, Command)]
+#[command(help, description)]
+struct Build {
+    /// Build with optimizations
+    #[flag]
+    release: bool,
+}
+
+fn parse(arguments: &[&'static str]) -> argparse::Result<ParseOutcome<Tool>> {
+    let mut source = arguments
+        .iter()
+        .map(|argument| Argument::from(*argument))
+        .collect::<Vec<_>>()
+        .into_iter();
+    Tool::parse(&mut source, CommandList::with_program("tool"))
+}
+
+#[test]
+fn parsed() {
+    assert_eq!(
+        parse(&["-v", "build", "--release"]).unwrap(),
+        ParseOutcome::Parsed(Tool {
+            verbose: true,
+            action: Action::Build(Build { release: true }),
+        })
+    );
+
+    assert_eq!(
+        parse(&["clean"]).unwrap(),
+        ParseOutcome::Parsed(Tool {
+            verbose: false,
+            action: Action::Clean,
+        })
+    );
+}
+
+#[test]
+fn help() {
+    let help = match parse(&["--help"]).unwrap() {
+        ParseOutcome::Help(help) => help,
+        outcome => panic!("expected help, got {outcome:?}"),
+    };
+
+    assert!(help.contains("Runs a test tool"));
+    assert!(help.contains("--verbose"));
+    assert!(help.contains("Print more output"));
+}
+
+#[test]
+fn short_help() {
+    assert!(matches!(parse(&["-h"]).unwrap(), ParseOutcome::Help(_)));
+}
+
+#[test]
+fn help_stops_parsing() {
+    // Arguments after the help flag, even invalid ones, are never parsed
+    assert!(matches!(
+        parse(&["--help", "--unknown"]).unwrap(),
+        ParseOutcome::Help(_)
+    ));
+}
+
+#[test]
+fn sub_command_help() {
+    let help = match parse(&["build", "--help"]).unwrap() {
+        ParseOutcome::Help(help) => help,
+        outcome => panic!("expected help, got {outcome:?}"),
+    };
+
+    assert!(help.contains("Builds the project"));
+    assert!(help.contains("--release"));
+    assert!(!help.contains("--verbose"));
+}
+
+#[test]
+fn version() {
+    assert_eq!(
+        parse(&["--version"]).unwrap(),
+        ParseOutcome::Version("tool 1.2.3\n".to_owned())
+    );
+    assert_eq!(
+        parse(&["-V"]).unwrap(),
+        ParseOutcome::Version("tool 1.2.3\n".to_owned())
+    );
+}
+
+/// Lists files
+#[derive(Debug, PartialEq, Command)]
+#[command(version = "list 1.0", version_command)]
+struct List {
+    /// The files to list
+    files: Vec<String>,
+}
+
+fn parse_list(arguments: &[&'static str]) -> argparse::Result<ParseOutcome<List>> {
+    let mut source = arguments
+        .iter()
+        .map(|argument| Argument::from(*argument))
+        .collect::<Vec<_>>()
+        .into_iter();
+    List::parse(&mut source, CommandList::with_program("list"))
+}
+
+#[test]
+fn version_command() {
+    assert!(matches!(
+        parse_list(&["version"]).unwrap(),
+        ParseOutcome::Version(_)
+    ));
+}
+
+#[test]
+fn version_command_only_comes_first() {
+    assert_eq!(
+        parse_list(&["notes.txt", "version"]).unwrap(),
+        ParseOutcome::Parsed(List {
+            files: vec!["notes.txt".to_owned(), "version".to_owned()],
+        })
+    );
+}
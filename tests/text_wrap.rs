@@ -0,0 +1,36 @@
+use argparse::TextWrap;
+
+fn wrap(width: usize, text: &str, indent: usize) -> String {
+    let mut output = Vec::new();
+    TextWrap::new(width)
+        .write(&mut output, text, indent)
+        .unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn keeps_inner_spaces() {
+    assert_eq!(wrap(80, "name:   the  name", 0), "name:   the  name");
+    assert_eq!(
+        wrap(80, "example:\n    tool  --flag", 0),
+        "example:\n    tool  --flag"
+    );
+}
+
+#[test]
+fn breaks_at_spaces() {
+    // Spaces at a wrap point are replaced by the new line and indent
+    assert_eq!(
+        wrap(24, "one two three four   five six", 2),
+        "one two three four\n  five six"
+    );
+}
+
+#[test]
+fn ignores_escape_sequences() {
+    // The styled words take as many columns as the plain ones
+    assert_eq!(
+        wrap(24, "one two \x1B[1mthree\x1B[0m four five", 0),
+        "one two \x1B[1mthree\x1B[0m four five"
+    );
+}
@@ -0,0 +1,99 @@
+use argparse::{Argument, Command, CommandList, Error, ParseOutcome, UnwrapErrors};
+
+#[derive(Debug, Command)]
+struct Transfer {
+    /// The number of copies to make
+    #[flag]
+    count: u32,
+
+    /// The file to copy
+    source: String,
+
+    /// Where to copy the file
+    destination: String,
+}
+
+fn parse(arguments: &[&'static str]) -> argparse::Result<ParseOutcome<Transfer>> {
+    let mut source = arguments
+        .iter()
+        .map(|argument| Argument::from(*argument))
+        .collect::<Vec<_>>()
+        .into_iter();
+    Transfer::parse(&mut source, CommandList::with_program("transfer"))
+}
+
+#[test]
+fn empty() {
+    assert!(UnwrapErrors::new().finish().is_ok());
+}
+
+#[test]
+fn single_error() {
+    let mut errors = UnwrapErrors::new();
+    assert_eq!(errors.take(Ok(5)), Some(5));
+    assert_eq!(
+        errors.take::<u32>(Err(Error::missing_argument("--count"))),
+        None
+    );
+
+    assert!(matches!(
+        errors.finish(),
+        Err(Error::MissingArgument("--count"))
+    ));
+}
+
+#[test]
+fn multiple_errors() {
+    let mut errors = UnwrapErrors::new();
+    errors.take::<()>(Err(Error::missing_argument("--count")));
+    errors.take::<()>(Err(Error::Multiple(vec![
+        Error::missing_positional_value("SOURCE"),
+        Error::missing_positional_value("DESTINATION"),
+    ])));
+
+    // Nested multiple errors are flattened
+    match errors.finish() {
+        Err(Error::Multiple(errors)) => {
+            assert_eq!(errors.len(), 3);
+            assert!(matches!(errors[0], Error::MissingArgument("--count")));
+            assert!(matches!(errors[1], Error::MissingPositionalValue("SOURCE")));
+            assert!(matches!(
+                errors[2],
+                Error::MissingPositionalValue("DESTINATION")
+            ));
+        }
+        result => panic!("expected multiple errors, got {result:?}"),
+    }
+}
+
+#[test]
+fn parse_reports_every_missing_value() {
+    let errors = match parse(&["a.txt"]) {
+        Err(Error::Multiple(errors)) => errors,
+        result => panic!("expected multiple errors, got {result:?}"),
+    };
+
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        errors[0].without_context(),
+        Error::MissingPositionalValue("DESTINATION")
+    ));
+    assert!(matches!(
+        errors[1].without_context(),
+        Error::MissingArgument("--count")
+    ));
+}
+
+#[test]
+fn parse_stops_at_invalid_value() {
+    let error = match parse(&["--count", "many"]) {
+        Err(error) => error,
+        result => panic!("expected an error, got {result:?}"),
+    };
+
+    // Values which fail to parse stop parsing, so the missing positionals aren't reported
+    assert!(matches!(
+        error.without_context(),
+        Error::InvalidFlagValue("--count", _, _)
+    ));
+}